@@ -22,11 +22,14 @@
 //! ```
 
 use std::{
+    borrow::Cow,
     cmp::{self, max},
-    collections::{BTreeMap, HashMap, HashSet},
-    fmt::{self, Display, Write},
+    collections::{BTreeMap, HashMap, HashSet, VecDeque},
+    fmt::{self, Write},
     hash::Hash,
+    io,
     ops::{Bound, RangeBounds},
+    sync::Arc,
 };
 
 pub const DEFAULT_BORDERS: Borders = Borders {
@@ -70,6 +73,10 @@ pub struct Grid {
     theme: Theme,
     override_split_lines: HashMap<usize, String>,
     spans: BTreeMap<(usize, usize), HashSet<usize>>,
+    column_widths: HashMap<usize, usize>,
+    row_spacing: HashMap<usize, usize>,
+    trim_trailing_whitespace: bool,
+    captions: HashMap<CaptionPosition, Caption>,
 }
 
 impl Grid {
@@ -92,7 +99,31 @@ impl Grid {
     ///     )
     /// ```
     pub fn new(rows: usize, columns: usize) -> Self {
-        let mut styles = HashMap::with_capacity(1);
+        Self::new_with_styles_capacity(rows, columns, 1)
+    }
+
+    /// Creates a new [Grid] like [Grid::new] does,
+    /// but preallocates a place for `styles` different styles.
+    ///
+    /// It's useful when a lot of cells are known to be styled individually,
+    /// in which case the style storage doesn't have to be reallocated on each [Grid::set] call.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings, AlignmentHorizontal};
+    ///     let mut grid = Grid::new_with_styles_capacity(1, 2, 3);
+    ///     grid.set(Entity::Cell(0, 0), Settings::new().text("0-0"));
+    ///     grid.set(Entity::Cell(0, 1), Settings::new().text("0-1").alignment(AlignmentHorizontal::Right));
+    ///     assert_eq!(
+    ///          grid.to_string(),
+    ///          "+---+---+\n\
+    ///           |0-0|0-1|\n\
+    ///           +---+---+\n"
+    ///     )
+    /// ```
+    pub fn new_with_styles_capacity(rows: usize, columns: usize, styles: usize) -> Self {
+        let mut styles = HashMap::with_capacity(cmp::max(styles, 1));
         styles.insert(Entity::Global, Style::default());
 
         Grid {
@@ -103,7 +134,47 @@ impl Grid {
             theme: Theme::new(),
             override_split_lines: HashMap::new(),
             spans: BTreeMap::new(),
+            column_widths: HashMap::new(),
+            row_spacing: HashMap::new(),
+            trim_trailing_whitespace: false,
+            captions: HashMap::new(),
+        }
+    }
+
+    /// Creates a grid from a list of rows.
+    ///
+    /// The number of columns is defined by the longest row,
+    /// shorter rows are filled with empty cells.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::Grid;
+    ///     let grid = Grid::from_rows(vec![
+    ///         vec![String::from("0-0"), String::from("0-1")],
+    ///         vec![String::from("1-0")],
+    ///     ]);
+    ///     assert_eq!(
+    ///          grid.to_string(),
+    ///          "+---+---+\n\
+    ///           |0-0|0-1|\n\
+    ///           +---+---+\n\
+    ///           |1-0|   |\n\
+    ///           +---+---+\n"
+    ///     )
+    /// ```
+    pub fn from_rows(mut rows: Vec<Vec<String>>) -> Self {
+        let count_rows = rows.len();
+        let count_columns = rows.iter().map(Vec::len).max().unwrap_or(0);
+        for row in &mut rows {
+            row.resize(count_columns, String::new());
         }
+
+        let mut grid = Self::new(0, 0);
+        grid.size = (count_rows, count_columns);
+        grid.cells = rows;
+
+        grid
     }
 
     /// Set method is responsible for modification of cell/row/column.
@@ -130,8 +201,12 @@ impl Grid {
         let is_style_changes = settings.padding.is_some()
             || settings.alignment_h.is_some()
             || settings.alignment_v.is_some()
-            || settings.span.is_some()
-            || settings.formatting.is_some();
+            || settings.formatting.is_some()
+            || settings.max_height.is_some();
+
+        #[cfg(feature = "color")]
+        let is_style_changes =
+            is_style_changes || settings.background.is_some() || settings.color.is_some();
 
         if is_style_changes {
             self.remove_inherited_styles(entity);
@@ -152,6 +227,20 @@ impl Grid {
             if let Some(formatting) = settings.formatting {
                 style.formatting = formatting;
             }
+
+            if let Some(max_height) = settings.max_height {
+                style.max_height = Some(max_height);
+            }
+
+            #[cfg(feature = "color")]
+            if let Some(background) = settings.background {
+                style.background = Some(background);
+            }
+
+            #[cfg(feature = "color")]
+            if let Some(color) = settings.color {
+                style.color = Some(color);
+            }
         }
 
         if let Some(text) = settings.text {
@@ -182,6 +271,189 @@ impl Grid {
         &self.margin
     }
 
+    /// Sets a [Caption] printed outside of the frame,
+    /// replacing a caption set for the same position.
+    ///
+    /// A caption is aligned within the width of the table, not including margins.
+    /// A caption line which is wider then the table is cut to its width,
+    /// so a caption never makes the table wider.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{AlignmentHorizontal, Caption, CaptionPosition, Grid, Entity, Settings};
+    ///     let mut grid = Grid::new(1, 2);
+    ///     grid.set(Entity::Global, Settings::new().text("abc"));
+    ///     grid.set_caption(Caption {
+    ///         text: String::from("Title"),
+    ///         position: CaptionPosition::Above,
+    ///         alignment: AlignmentHorizontal::Center,
+    ///     });
+    ///     assert_eq!(
+    ///          grid.to_string(),
+    ///          concat!(
+    ///              "  Title  \n",
+    ///              "+---+---+\n",
+    ///              "|abc|abc|\n",
+    ///              "+---+---+\n",
+    ///          )
+    ///     )
+    /// ```
+    pub fn set_caption(&mut self, caption: Caption) {
+        self.captions.insert(caption.position, caption);
+    }
+
+    /// Returns a [Caption] set for a given position.
+    pub fn get_caption(&self, position: CaptionPosition) -> Option<&Caption> {
+        self.captions.get(&position)
+    }
+
+    /// Sets the left and right [Margin] so the table is aligned within `total_width` columns.
+    ///
+    /// [AlignmentHorizontal::Center] puts an extra column on the right
+    /// when the free space can't be divided evenly,
+    /// and [AlignmentHorizontal::CenterLeftBias] puts it on the left.
+    ///
+    /// The fill characters of the margin are kept.
+    /// If the table is wider then `total_width` the margins are set to 0.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings, AlignmentHorizontal};
+    ///     let mut grid = Grid::new(1, 1);
+    ///     grid.set(Entity::Global, Settings::new().text("a"));
+    ///     grid.set_table_alignment(AlignmentHorizontal::Right, 6);
+    ///     assert_eq!(
+    ///          grid.to_string(),
+    ///          concat!(
+    ///              "   +-+\n",
+    ///              "   |a|\n",
+    ///              "   +-+\n",
+    ///          )
+    ///     )
+    /// ```
+    pub fn set_table_alignment(&mut self, alignment: AlignmentHorizontal, total_width: usize) {
+        let breakdown = self.width_breakdown();
+        let width = breakdown.content + breakdown.borders;
+        let rest = total_width.saturating_sub(width);
+
+        let left = match alignment {
+            AlignmentHorizontal::Left => 0,
+            AlignmentHorizontal::Right => rest,
+            AlignmentHorizontal::Center => rest / 2,
+            AlignmentHorizontal::CenterLeftBias => (rest + 1) / 2,
+        };
+
+        self.margin.left.size = left;
+        self.margin.right.size = rest - left;
+    }
+
+    /// Sets whether trailing spaces are stripped from each rendered line.
+    ///
+    /// It matters only when there's no right border,
+    /// in which case the last column is rendered without its right padding and alignment spaces.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Borders, Entity, Grid, Settings};
+    ///     let mut grid = Grid::new(2, 2);
+    ///     grid.set_borders(Borders {
+    ///         vertical_intersection: Some(' '.into()),
+    ///         ..Default::default()
+    ///     });
+    ///     grid.set(Entity::Cell(0, 0), Settings::new().text("a long"));
+    ///     grid.set(Entity::Cell(0, 1), Settings::new().text("b"));
+    ///     grid.set(Entity::Cell(1, 0), Settings::new().text("c"));
+    ///     grid.set(Entity::Cell(1, 1), Settings::new().text("long d"));
+    ///     grid.set_trim_trailing_whitespace(true);
+    ///     assert_eq!(
+    ///          grid.to_string(),
+    ///          "a long b\n\
+    ///           c      long d\n"
+    ///     )
+    /// ```
+    pub fn set_trim_trailing_whitespace(&mut self, on: bool) {
+        self.trim_trailing_whitespace = on;
+    }
+
+    /// Verifies whether trailing spaces are stripped from rendered lines.
+    ///
+    /// See [Grid::set_trim_trailing_whitespace].
+    pub fn is_trim_trailing_whitespace(&self) -> bool {
+        self.trim_trailing_whitespace
+    }
+
+    /// Sets a content of each cell to an empty string.
+    ///
+    /// The dimensions, styles, theme and margin are left intact.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings};
+    ///     let mut grid = Grid::new(1, 2);
+    ///     grid.set(Entity::Global, Settings::new().text("Hello"));
+    ///     grid.clear_contents();
+    ///     assert_eq!(
+    ///          grid.to_string(),
+    ///          "+++\n\
+    ///           |||\n\
+    ///           +++\n"
+    ///     )
+    /// ```
+    pub fn clear_contents(&mut self) {
+        for row in &mut self.cells {
+            for cell in row {
+                cell.clear();
+            }
+        }
+    }
+
+    /// Replaces a content of each cell by a value returned by a given function.
+    ///
+    /// The function gets a row, a column and a current content of a cell.
+    /// Styles are left intact.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings};
+    ///     let mut grid = Grid::new(1, 2);
+    ///     grid.set(Entity::Global, Settings::new().text("Hello"));
+    ///     grid.map_cells(|_, col, text| format!("{}{}", text, col));
+    ///     assert_eq!(
+    ///          grid.to_string(),
+    ///          "+------+------+\n\
+    ///           |Hello0|Hello1|\n\
+    ///           +------+------+\n"
+    ///     )
+    /// ```
+    pub fn map_cells<F>(&mut self, mut f: F)
+    where
+        F: FnMut(usize, usize, &str) -> String,
+    {
+        for (row, cells) in self.cells.iter_mut().enumerate() {
+            for (col, cell) in cells.iter_mut().enumerate() {
+                let text = f(row, col, cell);
+                *cell = strip_lone_carriage_returns(text);
+            }
+        }
+    }
+
+    /// Sets a content of each cell to an empty string
+    /// and resets cell styles and spans to default.
+    ///
+    /// The dimensions, theme and margin are left intact.
+    pub fn clear_all(&mut self) {
+        self.clear_contents();
+
+        self.styles.clear();
+        self.styles.insert(Entity::Global, Style::default());
+        self.spans.clear();
+    }
+
     /// Clears all theme changes.
     /// And sets it to default.
     pub fn clear_theme(&mut self) {
@@ -199,6 +471,119 @@ impl Grid {
         &self.theme.borders
     }
 
+    /// Changes each border symbol which is set,
+    /// including the ones set by [Grid::set_border] and [Grid::set_split_line].
+    ///
+    /// It's handy to recolor all borders at once.
+    /// Symbols made by [Symbol::empty] are left intact,
+    /// as well as split lines set by [Grid::override_split_line]
+    /// and symbols produced by an intersection resolver.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Symbol};
+    ///     let mut grid = Grid::new(1, 1);
+    ///     grid.map_borders(|symbol| {
+    ///         if symbol.same_glyph(&Symbol::from('+')) { Symbol::from('*') } else { symbol.clone() }
+    ///     });
+    ///     assert_eq!(
+    ///          grid.to_string(),
+    ///          "**\n\
+    ///           ||\n\
+    ///           **\n"
+    ///     )
+    /// ```
+    pub fn map_borders<F>(&mut self, f: F)
+    where
+        F: Fn(&Symbol) -> Symbol,
+    {
+        let map = |symbol: &mut Symbol| {
+            if !symbol.is_empty() {
+                *symbol = f(symbol);
+            }
+        };
+
+        let borders = &mut self.theme.borders;
+        for symbol in [
+            &mut borders.top,
+            &mut borders.top_left,
+            &mut borders.top_right,
+            &mut borders.top_intersection,
+            &mut borders.bottom,
+            &mut borders.bottom_left,
+            &mut borders.bottom_right,
+            &mut borders.bottom_intersection,
+            &mut borders.horizontal,
+            &mut borders.horizontal_left,
+            &mut borders.horizontal_right,
+            &mut borders.vertical_left,
+            &mut borders.vertical_intersection,
+            &mut borders.vertical_right,
+            &mut borders.intersection,
+        ]
+        .iter_mut()
+        .filter_map(|symbol| symbol.as_mut())
+        {
+            map(symbol);
+        }
+
+        for line in self.theme.override_lines.values_mut() {
+            for symbol in [
+                &mut line.horizontal,
+                &mut line.intersection,
+                &mut line.left,
+                &mut line.right,
+            ]
+            .iter_mut()
+            .filter_map(|symbol| symbol.as_mut())
+            {
+                map(symbol);
+            }
+        }
+
+        let overrides = &mut self.theme.override_borders;
+        overrides
+            .vertical
+            .values_mut()
+            .chain(overrides.horizontal.values_mut())
+            .chain(overrides.intersection.values_mut())
+            .for_each(|c| map(&mut c.symbol));
+    }
+
+    /// Sets a function which picks a symbol of an intersection
+    /// based on the border segments meeting at it.
+    ///
+    /// It's used for all intersections, except the ones set explicitly
+    /// by [Grid::set_border] and [Grid::set_split_line].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Symbol};
+    ///     let mut grid = Grid::new(1, 2);
+    ///     grid.set_intersection_resolver(|sides| {
+    ///         if sides.up.is_none() { Symbol::from('v') } else { Symbol::from('^') }
+    ///     });
+    ///     assert_eq!(
+    ///          grid.to_string(),
+    ///          "vvv\n\
+    ///           |||\n\
+    ///           ^^^\n"
+    ///     )
+    /// ```
+    pub fn set_intersection_resolver<F>(&mut self, resolver: F)
+    where
+        F: Fn(BorderSides) -> Symbol + Send + Sync + 'static,
+    {
+        self.theme.intersection_resolver = Some(IntersectionResolver(Arc::new(resolver)));
+    }
+
+    /// Removes a function set by [Grid::set_intersection_resolver].
+    pub fn remove_intersection_resolver(&mut self) {
+        self.theme.intersection_resolver = None;
+    }
+
     /// Set border set a border value to all cells in [Entity].
     pub fn set_border(&mut self, entity: Entity, border: Border) {
         match entity {
@@ -225,14 +610,117 @@ impl Grid {
         }
     }
 
+    /// Removes a side of a border set by [Grid::set_border] from all cells in [Entity].
+    ///
+    /// So the side is rendered according to the [Borders] again.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings, Border, Side};
+    ///     let mut grid = Grid::new(1, 1);
+    ///     grid.set(
+    ///         Entity::Global,
+    ///         Settings::new()
+    ///             .text("a")
+    ///             .border(Border::new('*', '*', '*', '*', '*', '*', '*', '*')),
+    ///     );
+    ///     grid.remove_border_side(Entity::Global, Side::Top);
+    ///     assert_eq!(
+    ///          grid.to_string(),
+    ///          "*-*\n\
+    ///           *a*\n\
+    ///           ***\n"
+    ///     )
+    /// ```
+    pub fn remove_border_side(&mut self, entity: Entity, side: Side) {
+        match entity {
+            Entity::Global => {
+                for col in 0..self.count_columns() {
+                    for row in 0..self.count_rows() {
+                        self.theme.remove_border_side((row, col), side);
+                    }
+                }
+            }
+            Entity::Column(col) => {
+                for row in 0..self.count_rows() {
+                    self.theme.remove_border_side((row, col), side);
+                }
+            }
+            Entity::Row(row) => {
+                for col in 0..self.count_columns() {
+                    self.theme.remove_border_side((row, col), side);
+                }
+            }
+            Entity::Cell(row, col) => {
+                self.theme.remove_border_side((row, col), side);
+            }
+        }
+    }
+
     /// Set the border line by row index.
     ///
     /// Row `0` means the top row.
     /// Row `grid.count_rows()` means the bottom row.
+    ///
+    /// A symbol of the line takes precedence over [Borders] set by [Grid::set_borders],
+    /// but a border set for a particular cell takes precedence over the line.
+    /// The precedence is applied per symbol, including the intersections.
     pub fn set_split_line(&mut self, row: usize, line: Line) {
         self.theme.override_line(row, line)
     }
 
+    /// Verifies if there's any vertical border on a column split line.
+    ///
+    /// Column `0` means the left border.
+    /// Column `grid.count_columns()` means the right border.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Borders};
+    ///     let mut grid = Grid::new(2, 2);
+    ///     grid.set_borders(Borders {
+    ///         vertical_intersection: Some('|'.into()),
+    ///         ..Default::default()
+    ///     });
+    ///
+    ///     assert!(!grid.has_vertical(0));
+    ///     assert!(grid.has_vertical(1));
+    ///     assert!(!grid.has_vertical(2));
+    /// ```
+    pub fn has_vertical(&self, col: usize) -> bool {
+        has_vertical(self, col)
+    }
+
+    /// Verifies if there's any horizontal border on a row split line.
+    ///
+    /// Row `0` means the top border.
+    /// Row `grid.count_rows()` means the bottom border.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Borders};
+    ///     let mut grid = Grid::new(2, 2);
+    ///     grid.set_borders(Borders {
+    ///         top: Some('-'.into()),
+    ///         ..Default::default()
+    ///     });
+    ///
+    ///     assert!(grid.has_horizontal(0));
+    ///     assert!(!grid.has_horizontal(1));
+    ///     assert!(!grid.has_horizontal(2));
+    /// ```
+    pub fn has_horizontal(&self, row: usize) -> bool {
+        has_horizontal(self, row)
+    }
+
+    /// Returns a border line set by [Grid::set_split_line], if any.
+    pub fn get_split_line(&self, row: usize) -> Option<&Line> {
+        self.theme.override_lines.get(&row)
+    }
+
     /// get_cell_settings returns a settings of a cell
     pub fn get_settings(&self, row: usize, col: usize) -> Settings {
         let style = self.style(Entity::Cell(row, col));
@@ -251,8 +739,13 @@ impl Grid {
             border: Some(border),
             alignment_h: Some(style.alignment_h),
             alignment_v: Some(style.alignment_v),
-            formatting: None,
+            formatting: Some(style.formatting),
+            max_height: style.max_height,
             span,
+            #[cfg(feature = "color")]
+            background: style.background.clone(),
+            #[cfg(feature = "color")]
+            color: style.color.clone(),
         }
     }
 
@@ -346,6 +839,13 @@ impl Grid {
         self.cells[row][column].as_str()
     }
 
+    /// Returns a mutable reference to a content of a cell.
+    ///
+    /// The method panics if incorrect cell index is given.
+    pub fn get_cell_content_mut(&mut self, row: usize, column: usize) -> &mut String {
+        &mut self.cells[row][column]
+    }
+
     /// get_cell_content_styled returns content with style changes
     pub fn get_cell_content_styled(&self, row: usize, column: usize) -> String {
         let style = self.style(Entity::Cell(row, column));
@@ -363,38 +863,172 @@ impl Grid {
         self.size.1
     }
 
+    /// Returns an amount of columns visible in a row,
+    /// meaning that a spanned cell is counted once.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings};
+    ///     let mut grid = Grid::new(2, 4);
+    ///     grid.set(Entity::Cell(0, 0), Settings::new().span(3));
+    ///
+    ///     assert_eq!(grid.count_visible_columns_in_row(0), 2);
+    ///     assert_eq!(grid.count_visible_columns_in_row(1), 4);
+    /// ```
+    pub fn count_visible_columns_in_row(&self, row: usize) -> usize {
+        (0..self.count_columns())
+            .filter(|&col| is_cell_visible(self, (row, col)))
+            .count()
+    }
+
+    /// Returns true if every visible cell on the grid has an empty content.
+    ///
+    /// Paddings and borders are not taken into account,
+    /// so a grid with no cells is blank as well.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings};
+    ///     let mut grid = Grid::new(2, 2);
+    ///     assert!(grid.is_blank());
+    ///
+    ///     grid.set(Entity::Cell(1, 1), Settings::new().text("1-1"));
+    ///     assert!(!grid.is_blank());
+    /// ```
+    pub fn is_blank(&self) -> bool {
+        (0..self.count_rows()).all(|row| {
+            (0..self.count_columns()).all(|col| {
+                !is_cell_visible(self, (row, col)) || self.get_cell_content(row, col).is_empty()
+            })
+        })
+    }
+
     /// Set text value to all cells in [Entity].
     pub fn set_text(&mut self, entity: Entity, text: String) {
         self._set_text(entity, text);
     }
 
-    /// Returns a new [Grid] that reflects a segment of the referenced [Grid]
+    /// Set text value to all cells in [Entity].
     ///
-    /// The segment is defined by [RangeBounds<usize>] for Rows and Columns
+    /// Unlike [Grid::set_text] it returns an error instead of panicking
+    /// if the entity is out of the grid.
+    pub fn try_set_text(&mut self, entity: Entity, text: String) -> Result<(), GridError> {
+        self.check_entity(entity)?;
+        self.set_text(entity, text);
+        Ok(())
+    }
+
+    /// Set a [Settings] value to a given [Entity].
+    ///
+    /// Unlike [Grid::set] it returns an error instead of panicking
+    /// if the entity is out of the grid.
     ///
     /// # Example
     ///
-    /// ```text,no_run
-    /// grid
-    /// +---+---+---+
-    /// |0-0|0-1|0-2|
-    /// +---+---+---+
-    /// |1-0|1-1|1-2|
-    /// +---+---+---+
-    /// |2-0|2-1|2-2|
-    /// +---+---+---+
-    /// let rows = ..;
-    /// let columns = ..1;
-    /// grid.extract(rows, columns)
-    /// +---+
-    /// |0-0|
-    /// +---+
-    /// |1-0|
-    /// +---+
-    /// |2-0|
-    /// +---+
+    /// ```rust
+    ///     use papergrid::{Grid, GridError, Entity, Settings};
+    ///     let mut grid = Grid::new(2, 2);
+    ///     assert_eq!(
+    ///         grid.try_set(Entity::Cell(2, 0), Settings::new().text("Hello")),
+    ///         Err(GridError::EntityOutOfBounds(Entity::Cell(2, 0))),
+    ///     );
     /// ```
-    pub fn extract<R, C>(&self, rows: R, columns: C) -> Self
+    pub fn try_set(&mut self, entity: Entity, settings: Settings) -> Result<(), GridError> {
+        self.check_entity(entity)?;
+        self.set(entity, settings);
+        Ok(())
+    }
+
+    fn check_entity(&self, entity: Entity) -> Result<(), GridError> {
+        let is_valid = match entity {
+            Entity::Global => true,
+            Entity::Column(col) => col < self.count_columns(),
+            Entity::Row(row) => row < self.count_rows(),
+            Entity::Cell(row, col) => row < self.count_rows() && col < self.count_columns(),
+        };
+
+        if is_valid {
+            Ok(())
+        } else {
+            Err(GridError::EntityOutOfBounds(entity))
+        }
+    }
+
+    /// Applies settings to each cell of a rectangle defined by ranges of rows and columns,
+    /// like [Grid::set] called with [Entity::Cell] for each of them.
+    ///
+    /// Only the cells in the rectangle get their own styles,
+    /// the rest keep inheriting row, column and global ones.
+    /// The part of the rectangle which is out of the grid is ignored.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings};
+    ///     let mut grid = Grid::new(2, 2);
+    ///     grid.set(Entity::Global, Settings::new().text("a"));
+    ///     grid.set_range(1.., ..1, Settings::new().text("b"));
+    ///     assert_eq!(
+    ///          grid.to_string(),
+    ///          "+-+-+\n\
+    ///           |a|a|\n\
+    ///           +-+-+\n\
+    ///           |b|a|\n\
+    ///           +-+-+\n"
+    ///     )
+    /// ```
+    pub fn set_range<R, C>(&mut self, rows: R, columns: C, settings: Settings)
+    where
+        R: RangeBounds<usize>,
+        C: RangeBounds<usize>,
+    {
+        let (start_row, end_row) =
+            bounds_to_usize(rows.start_bound(), rows.end_bound(), self.count_rows());
+        let (start_column, end_column) = bounds_to_usize(
+            columns.start_bound(),
+            columns.end_bound(),
+            self.count_columns(),
+        );
+
+        let end_row = cmp::min(end_row, self.count_rows());
+        let end_column = cmp::min(end_column, self.count_columns());
+
+        for row in start_row..end_row {
+            for column in start_column..end_column {
+                self.set(Entity::Cell(row, column), settings.clone());
+            }
+        }
+    }
+
+    /// Returns a new [Grid] that reflects a segment of the referenced [Grid]
+    ///
+    /// The segment is defined by [RangeBounds<usize>] for Rows and Columns
+    ///
+    /// # Example
+    ///
+    /// ```text,no_run
+    /// grid
+    /// +---+---+---+
+    /// |0-0|0-1|0-2|
+    /// +---+---+---+
+    /// |1-0|1-1|1-2|
+    /// +---+---+---+
+    /// |2-0|2-1|2-2|
+    /// +---+---+---+
+    /// let rows = ..;
+    /// let columns = ..1;
+    /// grid.extract(rows, columns)
+    /// +---+
+    /// |0-0|
+    /// +---+
+    /// |1-0|
+    /// +---+
+    /// |2-0|
+    /// +---+
+    /// ```
+    pub fn extract<R, C>(&self, rows: R, columns: C) -> Self
     where
         R: RangeBounds<usize>,
         C: RangeBounds<usize>,
@@ -411,6 +1045,28 @@ impl Grid {
         let new_count_columns = end_column - start_column;
         let mut new_grid = Grid::new(new_count_rows, new_count_columns);
         new_grid.theme = self.theme.clone();
+        new_grid.margin = self.margin;
+        new_grid.trim_trailing_whitespace = self.trim_trailing_whitespace;
+
+        for (&row, line) in &self.override_split_lines {
+            if row >= start_row && row <= end_row {
+                new_grid
+                    .override_split_lines
+                    .insert(row - start_row, line.clone());
+            }
+        }
+
+        for (&col, &width) in &self.column_widths {
+            if col >= start_column && col < end_column {
+                new_grid.column_widths.insert(col - start_column, width);
+            }
+        }
+
+        for (&row, &size) in &self.row_spacing {
+            if row >= start_row && row < end_row {
+                new_grid.row_spacing.insert(row - start_row, size);
+            }
+        }
 
         for (new_row, row) in (start_row..end_row).enumerate() {
             for (new_column, column) in (start_column..end_column).enumerate() {
@@ -419,33 +1075,712 @@ impl Grid {
             }
         }
 
-        new_grid
+        new_grid
+    }
+
+    /// Swaps 2 rows, including their contents, styles, spans and spacing.
+    ///
+    /// The borders set for the cells by [Grid::set_border] are moved with them,
+    /// while the split lines are left in place as they are located between rows.
+    ///
+    /// The method panics if incorrect row index is given.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings};
+    ///     let mut grid = Grid::new(2, 1);
+    ///     grid.set(Entity::Row(0), Settings::new().text("row 1"));
+    ///     grid.set(Entity::Row(1), Settings::new().text("row 2"));
+    ///     grid.swap_rows(0, 1);
+    ///     assert_eq!(
+    ///          grid.to_string(),
+    ///          "+-----+\n\
+    ///           |row 2|\n\
+    ///           +-----+\n\
+    ///           |row 1|\n\
+    ///           +-----+\n"
+    ///     )
+    /// ```
+    pub fn swap_rows(&mut self, a: usize, b: usize) {
+        assert!(
+            a < self.count_rows() && b < self.count_rows(),
+            "a row index is out of bounds"
+        );
+
+        if a == b {
+            return;
+        }
+
+        self.cells.swap(a, b);
+
+        let styles = std::mem::take(&mut self.styles);
+        self.styles = styles
+            .into_iter()
+            .map(|(entity, style)| {
+                let entity = match entity {
+                    Entity::Row(row) => Entity::Row(swap_index(row, a, b)),
+                    Entity::Cell(row, col) => Entity::Cell(swap_index(row, a, b), col),
+                    entity => entity,
+                };
+
+                (entity, style)
+            })
+            .collect();
+
+        for rows in self.spans.values_mut() {
+            let has_a = rows.remove(&a);
+            let has_b = rows.remove(&b);
+            if has_a {
+                rows.insert(b);
+            }
+
+            if has_b {
+                rows.insert(a);
+            }
+        }
+
+        swap_keys(&mut self.row_spacing, a, b);
+
+        self.theme.override_borders.swap_rows(a, b);
+    }
+
+    /// Swaps 2 columns, including their contents, styles, spans and widths.
+    ///
+    /// The borders set for the cells by [Grid::set_border] are moved with them.
+    /// A span is moved with its cell and it's cut if it doesn't fit the grid anymore.
+    ///
+    /// The method panics if incorrect column index is given.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings};
+    ///     let mut grid = Grid::new(1, 2);
+    ///     grid.set(Entity::Column(0), Settings::new().text("a"));
+    ///     grid.set(Entity::Column(1), Settings::new().text("bb"));
+    ///     grid.swap_columns(0, 1);
+    ///     assert_eq!(
+    ///          grid.to_string(),
+    ///          "+--+-+\n\
+    ///           |bb|a|\n\
+    ///           +--+-+\n"
+    ///     )
+    /// ```
+    pub fn swap_columns(&mut self, a: usize, b: usize) {
+        assert!(
+            a < self.count_columns() && b < self.count_columns(),
+            "a column index is out of bounds"
+        );
+
+        if a == b {
+            return;
+        }
+
+        for row in &mut self.cells {
+            row.swap(a, b);
+        }
+
+        let styles = std::mem::take(&mut self.styles);
+        self.styles = styles
+            .into_iter()
+            .map(|(entity, style)| {
+                let entity = match entity {
+                    Entity::Column(col) => Entity::Column(swap_index(col, a, b)),
+                    Entity::Cell(row, col) => Entity::Cell(row, swap_index(col, a, b)),
+                    entity => entity,
+                };
+
+                (entity, style)
+            })
+            .collect();
+
+        swap_keys(&mut self.column_widths, a, b);
+
+        let count_columns = self.count_columns();
+        let spans = std::mem::take(&mut self.spans);
+        for ((start, end), rows) in spans {
+            // a span is moved with its cell and cut if it goes out of the grid.
+            let span = end - start;
+            let start = swap_index(start, a, b);
+            let end = cmp::min(start + span, count_columns);
+            if end - start > 1 {
+                self.spans.entry((start, end)).or_default().extend(rows);
+            }
+        }
+
+        self.theme.override_borders.swap_columns(a, b);
+    }
+
+    /// Inserts an empty column at a given index, shifting all columns after it to the right.
+    ///
+    /// Styles, widths, spans and borders set for the shifted columns are moved with them.
+    /// The inserted column has no styles of its own, so it uses global and row styles.
+    /// A span which goes over the index is extended to cover the new column.
+    ///
+    /// The method panics if `at` is bigger then [Grid::count_columns],
+    /// `at` equal to the count of columns appends a column.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings};
+    ///     let mut grid = Grid::new(1, 2);
+    ///     grid.set(Entity::Cell(0, 0), Settings::new().text("a"));
+    ///     grid.set(Entity::Cell(0, 1), Settings::new().text("b"));
+    ///     grid.insert_column(1);
+    ///     grid.set(Entity::Cell(0, 1), Settings::new().text("new"));
+    ///     assert_eq!(
+    ///          grid.to_string(),
+    ///          "+-+---+-+\n\
+    ///           |a|new|b|\n\
+    ///           +-+---+-+\n"
+    ///     )
+    /// ```
+    pub fn insert_column(&mut self, at: usize) {
+        let count_columns = self.count_columns();
+        assert!(at <= count_columns, "a column index is out of bounds");
+
+        for row in &mut self.cells {
+            row.insert(at, String::new());
+        }
+
+        self.size.1 += 1;
+
+        let shift = |col: usize| if col >= at { col + 1 } else { col };
+        // the vertical line on the left of the inserted column stays in place,
+        // but the right border of the grid is moved to stay the last one.
+        let shift_line = |col: usize| {
+            if col > at || col == count_columns {
+                col + 1
+            } else {
+                col
+            }
+        };
+
+        self.map_columns(|col| Some(shift(col)), |col| Some(shift_line(col)));
+
+        let spans = std::mem::take(&mut self.spans);
+        for ((start, end), rows) in spans {
+            let span = if start >= at {
+                (start + 1, end + 1)
+            } else if end > at {
+                (start, end + 1)
+            } else {
+                (start, end)
+            };
+
+            self.spans.entry(span).or_default().extend(rows);
+        }
+    }
+
+    /// Removes a column at a given index, shifting all columns after it to the left.
+    ///
+    /// Styles, widths, spans and borders set for the shifted columns are moved with them,
+    /// and the ones set for the removed column are dropped.
+    /// A span which goes over the index is shrunk.
+    ///
+    /// The method panics if incorrect column index is given.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings};
+    ///     let mut grid = Grid::new(1, 3);
+    ///     grid.set(Entity::Cell(0, 0), Settings::new().text("a"));
+    ///     grid.set(Entity::Cell(0, 1), Settings::new().text("bb"));
+    ///     grid.set(Entity::Cell(0, 2), Settings::new().text("c"));
+    ///     grid.remove_column(1);
+    ///     assert_eq!(
+    ///          grid.to_string(),
+    ///          "+-+-+\n\
+    ///           |a|c|\n\
+    ///           +-+-+\n"
+    ///     )
+    /// ```
+    pub fn remove_column(&mut self, at: usize) {
+        let count_columns = self.count_columns();
+        assert!(at < count_columns, "a column index is out of bounds");
+
+        for row in &mut self.cells {
+            row.remove(at);
+        }
+
+        self.size.1 -= 1;
+
+        let shift = |col: usize| match col.cmp(&at) {
+            cmp::Ordering::Less => Some(col),
+            cmp::Ordering::Equal => None,
+            cmp::Ordering::Greater => Some(col - 1),
+        };
+        // the vertical line on the right of the removed column is dropped,
+        // unless it's the right border of the grid.
+        let removed_line = if at + 1 == count_columns { at } else { at + 1 };
+        let shift_line = |col: usize| match col.cmp(&removed_line) {
+            cmp::Ordering::Less => Some(col),
+            cmp::Ordering::Equal => None,
+            cmp::Ordering::Greater => Some(col - 1),
+        };
+
+        self.map_columns(shift, shift_line);
+
+        let spans = std::mem::take(&mut self.spans);
+        for ((start, end), rows) in spans {
+            let span = if start > at {
+                (start - 1, end - 1)
+            } else if end > at {
+                (start, end - 1)
+            } else {
+                (start, end)
+            };
+
+            if span.1 - span.0 > 1 {
+                self.spans.entry(span).or_default().extend(rows);
+            }
+        }
+    }
+
+    /// Inserts an empty row at a given index, shifting all rows after it down.
+    ///
+    /// Styles, spacing, spans and borders set for the shifted rows are moved with them,
+    /// as well as split lines below them.
+    /// The inserted row has no styles of its own, so it uses global and column styles.
+    ///
+    /// The method panics if `at` is bigger then [Grid::count_rows],
+    /// `at` equal to the count of rows appends a row.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings};
+    ///     let mut grid = Grid::new(2, 1);
+    ///     grid.set(Entity::Cell(0, 0), Settings::new().text("a"));
+    ///     grid.set(Entity::Cell(1, 0), Settings::new().text("b"));
+    ///     grid.insert_row(1);
+    ///     grid.set(Entity::Cell(1, 0), Settings::new().text("new"));
+    ///     assert_eq!(
+    ///          grid.to_string(),
+    ///          "+---+\n\
+    ///           |a  |\n\
+    ///           +---+\n\
+    ///           |new|\n\
+    ///           +---+\n\
+    ///           |b  |\n\
+    ///           +---+\n"
+    ///     )
+    /// ```
+    pub fn insert_row(&mut self, at: usize) {
+        let count_rows = self.count_rows();
+        assert!(at <= count_rows, "a row index is out of bounds");
+
+        self.cells
+            .insert(at, vec![String::new(); self.count_columns()]);
+        self.size.0 += 1;
+
+        let shift = |row: usize| if row >= at { row + 1 } else { row };
+        // the horizontal line above the inserted row stays in place,
+        // but the bottom border of the grid is moved to stay the last one.
+        let shift_line = |row: usize| {
+            if row > at || row == count_rows {
+                row + 1
+            } else {
+                row
+            }
+        };
+
+        self.map_rows(|row| Some(shift(row)), |row| Some(shift_line(row)));
+    }
+
+    /// Removes a row at a given index, shifting all rows after it up.
+    ///
+    /// Styles, spacing, spans and borders set for the shifted rows are moved with them,
+    /// and the ones set for the removed row are dropped.
+    ///
+    /// The method panics if incorrect row index is given.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings};
+    ///     let mut grid = Grid::new(3, 1);
+    ///     grid.set(Entity::Cell(0, 0), Settings::new().text("a"));
+    ///     grid.set(Entity::Cell(1, 0), Settings::new().text("b"));
+    ///     grid.set(Entity::Cell(2, 0), Settings::new().text("c"));
+    ///     grid.remove_row(1);
+    ///     assert_eq!(
+    ///          grid.to_string(),
+    ///          "+-+\n\
+    ///           |a|\n\
+    ///           +-+\n\
+    ///           |c|\n\
+    ///           +-+\n"
+    ///     )
+    /// ```
+    pub fn remove_row(&mut self, at: usize) {
+        let count_rows = self.count_rows();
+        assert!(at < count_rows, "a row index is out of bounds");
+
+        self.cells.remove(at);
+        self.size.0 -= 1;
+
+        let shift = |row: usize| match row.cmp(&at) {
+            cmp::Ordering::Less => Some(row),
+            cmp::Ordering::Equal => None,
+            cmp::Ordering::Greater => Some(row - 1),
+        };
+        // the horizontal line below the removed row is dropped,
+        // unless it's the bottom border of the grid.
+        let removed_line = if at + 1 == count_rows { at } else { at + 1 };
+        let shift_line = |row: usize| match row.cmp(&removed_line) {
+            cmp::Ordering::Less => Some(row),
+            cmp::Ordering::Equal => None,
+            cmp::Ordering::Greater => Some(row - 1),
+        };
+
+        self.map_rows(shift, shift_line);
+    }
+
+    /// Changes the size of the grid by adding or removing rows and columns at the end.
+    ///
+    /// Content of the cells which stay in the grid is preserved and new cells are empty.
+    /// Styles, spans and borders set for the removed rows and columns are dropped,
+    /// while the borders of the grid are moved to stay the last ones
+    /// as [Grid::insert_row] and [Grid::remove_row] do.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings};
+    ///     let mut grid = Grid::new(1, 1);
+    ///     grid.set(Entity::Cell(0, 0), Settings::new().text("a"));
+    ///     grid.resize(2, 2);
+    ///     grid.set(Entity::Cell(1, 1), Settings::new().text("b"));
+    ///     assert_eq!(
+    ///          grid.to_string(),
+    ///          "+-+-+\n\
+    ///           |a| |\n\
+    ///           +-+-+\n\
+    ///           | |b|\n\
+    ///           +-+-+\n"
+    ///     )
+    /// ```
+    pub fn resize(&mut self, rows: usize, columns: usize) {
+        while self.count_rows() > rows {
+            self.remove_row(self.count_rows() - 1);
+        }
+
+        while self.count_columns() > columns {
+            self.remove_column(self.count_columns() - 1);
+        }
+
+        while self.count_columns() < columns {
+            self.insert_column(self.count_columns());
+        }
+
+        while self.count_rows() < rows {
+            self.insert_row(self.count_rows());
+        }
+    }
+
+    /// Remaps row indexes of styles, spacing, spans and borders.
+    ///
+    /// `row` maps an index of a row and `line` an index of a horizontal line,
+    /// `None` means the entry must be dropped.
+    fn map_rows(
+        &mut self,
+        row: impl Fn(usize) -> Option<usize>,
+        line: impl Fn(usize) -> Option<usize>,
+    ) {
+        let styles = std::mem::take(&mut self.styles);
+        self.styles = styles
+            .into_iter()
+            .filter_map(|(entity, style)| {
+                let entity = match entity {
+                    Entity::Row(r) => Entity::Row(row(r)?),
+                    Entity::Cell(r, col) => Entity::Cell(row(r)?, col),
+                    entity => entity,
+                };
+
+                Some((entity, style))
+            })
+            .collect();
+
+        for rows in self.spans.values_mut() {
+            *rows = rows.iter().filter_map(|&r| row(r)).collect();
+        }
+
+        self.spans.retain(|_, rows| !rows.is_empty());
+
+        let spacing = std::mem::take(&mut self.row_spacing);
+        self.row_spacing = spacing
+            .into_iter()
+            .filter_map(|(r, spacing)| Some((row(r)?, spacing)))
+            .collect();
+
+        let split_lines = std::mem::take(&mut self.override_split_lines);
+        self.override_split_lines = split_lines
+            .into_iter()
+            .filter_map(|(l, text)| Some((line(l)?, text)))
+            .collect();
+
+        let lines = std::mem::take(&mut self.theme.override_lines);
+        self.theme.override_lines = lines
+            .into_iter()
+            .filter_map(|(l, split_line)| Some((line(l)?, split_line)))
+            .collect();
+
+        let borders = &mut self.theme.override_borders;
+
+        let horizontal = std::mem::take(&mut borders.horizontal);
+        borders.horizontal = horizontal
+            .into_iter()
+            .filter_map(|((l, col), c)| Some(((line(l)?, col), c)))
+            .collect();
+
+        let vertical = std::mem::take(&mut borders.vertical);
+        borders.vertical = vertical
+            .into_iter()
+            .filter_map(|((r, col), c)| Some(((row(r)?, col), c)))
+            .collect();
+
+        let intersection = std::mem::take(&mut borders.intersection);
+        borders.intersection = intersection
+            .into_iter()
+            .filter_map(|((l, col), c)| Some(((line(l)?, col), c)))
+            .collect();
+    }
+
+    /// Remaps column indexes of styles, widths and borders.
+    ///
+    /// `column` maps an index of a column and `line` an index of a vertical line,
+    /// `None` means the entry must be dropped.
+    fn map_columns(
+        &mut self,
+        column: impl Fn(usize) -> Option<usize>,
+        line: impl Fn(usize) -> Option<usize>,
+    ) {
+        let styles = std::mem::take(&mut self.styles);
+        self.styles = styles
+            .into_iter()
+            .filter_map(|(entity, style)| {
+                let entity = match entity {
+                    Entity::Column(col) => Entity::Column(column(col)?),
+                    Entity::Cell(row, col) => Entity::Cell(row, column(col)?),
+                    entity => entity,
+                };
+
+                Some((entity, style))
+            })
+            .collect();
+
+        let widths = std::mem::take(&mut self.column_widths);
+        self.column_widths = widths
+            .into_iter()
+            .filter_map(|(col, width)| Some((column(col)?, width)))
+            .collect();
+
+        let borders = &mut self.theme.override_borders;
+
+        let horizontal = std::mem::take(&mut borders.horizontal);
+        borders.horizontal = horizontal
+            .into_iter()
+            .filter_map(|((row, col), c)| Some(((row, column(col)?), c)))
+            .collect();
+
+        let vertical = std::mem::take(&mut borders.vertical);
+        borders.vertical = vertical
+            .into_iter()
+            .filter_map(|((row, col), c)| Some(((row, line(col)?), c)))
+            .collect();
+
+        let intersection = std::mem::take(&mut borders.intersection);
+        borders.intersection = intersection
+            .into_iter()
+            .filter_map(|((row, col), c)| Some(((row, line(col)?), c)))
+            .collect();
+    }
+
+    /// Returns a total width of table, including split lines.
+    pub fn total_width(&self) -> usize {
+        self.width_breakdown().total()
+    }
+
+    /// Returns a total width of table split into its components.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings, WidthBreakdown};
+    ///     let mut grid = Grid::new(1, 2);
+    ///     grid.set(Entity::Global, Settings::new().text("abc"));
+    ///     assert_eq!(
+    ///         grid.width_breakdown(),
+    ///         WidthBreakdown { content: 6, borders: 3, margin: 0 },
+    ///     );
+    ///     assert_eq!(grid.width_breakdown().total(), grid.total_width());
+    /// ```
+    pub fn width_breakdown(&self) -> WidthBreakdown {
+        let count_rows = self.count_rows();
+        let count_columns = self.count_columns();
+        if count_rows == 0 || count_columns == 0 {
+            return WidthBreakdown::default();
+        }
+
+        let widths = columns_width(self);
+
+        width_breakdown(self, &widths, &self.margin)
+    }
+
+    /// Sets an exact width of a column, regardless of its content.
+    ///
+    /// The width includes cell paddings.
+    /// A content which doesn't fit is cut and a narrower one is aligned within the width.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings};
+    ///     let mut grid = Grid::new(1, 2);
+    ///     grid.set(Entity::Cell(0, 0), Settings::new().text("Hello"));
+    ///     grid.set(Entity::Cell(0, 1), Settings::new().text("World"));
+    ///     grid.set_column_width(0, 2);
+    ///     assert_eq!(
+    ///          grid.to_string(),
+    ///          "+--+-----+\n\
+    ///           |He|World|\n\
+    ///           +--+-----+\n"
+    ///     )
+    /// ```
+    pub fn set_column_width(&mut self, col: usize, width: usize) {
+        self.column_widths.insert(col, width);
+    }
+
+    /// Sets an amount of blank lines printed after a row content,
+    /// before the next split line.
+    ///
+    /// The vertical borders are kept on the blank lines.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings};
+    ///     let mut grid = Grid::new(2, 1);
+    ///     grid.set(Entity::Global, Settings::new().text("a"));
+    ///     grid.set_row_spacing(0, 1);
+    ///     assert_eq!(
+    ///          grid.to_string(),
+    ///          "+-+\n\
+    ///           |a|\n\
+    ///           | |\n\
+    ///           +-+\n\
+    ///           |a|\n\
+    ///           +-+\n"
+    ///     )
+    /// ```
+    pub fn set_row_spacing(&mut self, row: usize, size: usize) {
+        self.row_spacing.insert(row, size);
+    }
+
+    /// Override the split line with a custom text.
+    ///
+    /// If borders are not set the string won't be rendered.
+    pub fn override_split_line(&mut self, row: usize, line: impl Into<String>) {
+        self.override_split_lines.insert(row, line.into());
+    }
+
+    /// Returns columns widths used in rendering.
+    ///
+    /// See [Grid::layout] to get all sizes at once.
+    pub fn build_widths(&self) -> Vec<usize> {
+        columns_width(self)
+    }
+
+    /// Returns sizes and styles the [Grid] is rendered with.
+    ///
+    /// It's meant for building a custom rendering on top of a [Grid].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings};
+    ///     let mut grid = Grid::new(1, 2);
+    ///     grid.set(Entity::Cell(0, 0), Settings::new().text("Hello\nWorld"));
+    ///     grid.set_column_width(1, 3);
+    ///
+    ///     let layout = grid.layout();
+    ///     assert_eq!(layout.widths, vec![5, 3]);
+    ///     assert_eq!(layout.normal_widths, vec![5, 0]);
+    ///     assert_eq!(layout.heights, vec![2]);
+    /// ```
+    pub fn layout(&self) -> GridLayout {
+        let styles = (0..self.count_rows())
+            .map(|row| {
+                (0..self.count_columns())
+                    .map(|col| self.style(Entity::Cell(row, col)).clone())
+                    .collect()
+            })
+            .collect();
+
+        GridLayout {
+            widths: columns_width(self),
+            normal_widths: self.natural_column_widths(),
+            heights: rows_height(self).collect(),
+            styles,
+        }
     }
 
-    /// Returns a total width of table, including split lines.
-    pub fn total_width(&self) -> usize {
-        let count_rows = self.count_rows();
-        let count_columns = self.count_columns();
-        if count_rows == 0 || count_columns == 0 {
-            return 0;
+    /// Returns lines of a cell as they are rendered, including padding and alignment.
+    ///
+    /// A cell hidden by a span has no lines.
+    ///
+    /// The method panics if incorrect cell index is given.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings, AlignmentHorizontal};
+    ///     let mut grid = Grid::new(2, 1);
+    ///     grid.set(Entity::Cell(0, 0), Settings::new().text("Hello World"));
+    ///     grid.set(
+    ///         Entity::Cell(1, 0),
+    ///         Settings::new().text("Hi").alignment(AlignmentHorizontal::Right),
+    ///     );
+    ///     assert_eq!(grid.render_cell(1, 0), vec!["         Hi"]);
+    /// ```
+    pub fn render_cell(&self, row: usize, col: usize) -> Vec<String> {
+        if !is_cell_visible(self, (row, col)) {
+            return Vec::new();
         }
 
         let widths = columns_width(self);
-
-        total_width(self, &widths, &self.margin)
+        let height = rows_height(self).nth(row).unwrap();
+        let columns_max_line_width = columns_max_line_width(self);
+
+        (0..height)
+            .map(|i| {
+                let mut line = String::new();
+                print_cell_line(
+                    &mut line,
+                    self,
+                    &widths,
+                    columns_max_line_width.as_deref(),
+                    (row, col),
+                    i,
+                    height,
+                )
+                .unwrap();
+
+                line
+            })
+            .collect()
     }
 
-    /// Override the split line with a custom text.
+    /// Returns columns widths driven only by the content of cells.
     ///
-    /// If borders are not set the string won't be rendered.
-    pub fn override_split_line(&mut self, row: usize, line: impl Into<String>) {
-        self.override_split_lines.insert(row, line.into());
-    }
-
-    // hide it by feature?
-    // 'private'
-    pub fn build_widths(&self) -> Vec<usize> {
-        columns_width(self)
+    /// Unlike [Grid::build_widths] it ignores widths set by [Grid::set_column_width].
+    /// A width includes padding.
+    pub fn natural_column_widths(&self) -> Vec<usize> {
+        columns_width_with(self, &HashMap::new())
     }
 
     /// This function returns a cells widths.
@@ -482,7 +1817,8 @@ impl Grid {
         (0..count_rows).for_each(|row| {
             (0..count_columns).for_each(|col| {
                 let style = self.style(Entity::Cell(row, col));
-                let content = replace_tab(&self.cells[row][col], style.formatting.tab_width);
+                let text = cut_height(&self.cells[row][col], style);
+                let content = replace_tab(&text, style.formatting.tab_width);
 
                 // fixme: I guess it can be done in a different place?
                 let lines: Vec<_> = content.lines().map(|l| l.to_owned()).collect();
@@ -556,8 +1892,11 @@ impl Grid {
     }
 
     fn _set_text(&mut self, entity: Entity, text: String) {
+        let text = strip_lone_carriage_returns(text);
         match entity {
             Entity::Cell(row, col) => {
+                // Only the content is replaced, the styles are left as they are,
+                // so filling a grid cell by cell doesn't allocate anything in the styles map.
                 self.cells[row][col] = text;
             }
             Entity::Column(col) => {
@@ -615,6 +1954,511 @@ impl Grid {
     pub fn has_column_spans(&self) -> bool {
         !self.spans.is_empty()
     }
+
+    /// Checks that the grid is in a consistent state and can be rendered correctly.
+    ///
+    /// It verifies that spans don't go beyond the grid
+    /// and that spans in the same row don't overlap.
+    pub fn validate(&self) -> Result<(), GridError> {
+        for (&(start, end), rows) in &self.spans {
+            if let Some(&row) = rows.iter().min() {
+                if end > self.count_columns() || row >= self.count_rows() {
+                    return Err(GridError::SpanOutOfBounds { row, col: start });
+                }
+            }
+        }
+
+        for row in 0..self.count_rows() {
+            let mut last_end = 0;
+            // spans are sorted by a start column
+            for &(start, end) in self
+                .spans
+                .iter()
+                .filter(|(_, rows)| rows.contains(&row))
+                .map(|(span, _)| span)
+            {
+                if start < last_end {
+                    return Err(GridError::OverlappingSpans { row, col: start });
+                }
+
+                last_end = end;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the same output as [ToString::to_string] but without the trailing new line.
+    pub fn to_string_trimmed(&self) -> String {
+        let mut s = self.to_string();
+        if s.ends_with('\n') {
+            s.pop();
+        }
+
+        s
+    }
+
+    /// Renders the grid with each visible character of a content replaced by a `fill` character
+    /// repeated to the character width.
+    ///
+    /// Whitespace is kept as it is, so it's trimmed the same way as in [ToString::to_string].
+    /// Widths, heights and borders stay the same as well,
+    /// so it shows a layout of the grid without its data.
+    /// The `fill` is expected to be 1 column wide.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings};
+    ///     let mut grid = Grid::new(1, 2);
+    ///     grid.set(Entity::Cell(0, 0), Settings::new().text("Hello"));
+    ///     grid.set(Entity::Cell(0, 1), Settings::new().text("a\nb c"));
+    ///
+    ///     assert_eq!(
+    ///         grid.to_string_skeleton('░'),
+    ///         "+-----+---+\n\
+    ///          |░░░░░|░  |\n\
+    ///          |     |░ ░|\n\
+    ///          +-----+---+\n"
+    ///     );
+    /// ```
+    pub fn to_string_skeleton(&self, fill: char) -> String {
+        let mut grid = self.clone();
+        for row in grid.cells.iter_mut() {
+            for cell in row.iter_mut() {
+                *cell = skeleton_text(cell, fill);
+            }
+        }
+
+        grid.to_string()
+    }
+
+    /// Returns the grid content as tab-separated values, one row per line.
+    ///
+    /// Borders, padding and alignment are ignored.
+    /// As TSV has no quoting, tabs and new lines in a content are replaced by spaces.
+    pub fn to_tsv(&self) -> String {
+        let mut buf = String::new();
+        if self.count_rows() == 0 || self.count_columns() == 0 {
+            return buf;
+        }
+
+        for row in &self.cells {
+            for (col, text) in row.iter().enumerate() {
+                if col > 0 {
+                    buf.push('\t');
+                }
+
+                let text = text.replace("\r\n", " ");
+                buf.extend(text.chars().map(|c| match c {
+                    '\t' | '\n' | '\r' => ' ',
+                    c => c,
+                }));
+            }
+
+            buf.push('\n');
+        }
+
+        buf
+    }
+
+    /// Writes the grid into a [io::Write] without building an intermediate [String].
+    ///
+    /// The output is the same as the one produced by [fmt::Display].
+    pub fn write_to<W: io::Write>(&self, w: &mut W) -> io::Result<()> {
+        self.write_to_with(w, |_| {})
+    }
+
+    /// Writes the grid into a [io::Write] calling `on_row` each time a row is completely written.
+    ///
+    /// The callback gets an index of the row.
+    /// It may be used to report a progress of rendering a big table.
+    pub fn write_to_with<W: io::Write>(
+        &self,
+        w: &mut W,
+        on_row: impl FnMut(usize),
+    ) -> io::Result<()> {
+        let count_rows = self.count_rows();
+        let count_columns = self.count_columns();
+
+        if count_rows == 0 || count_columns == 0 {
+            return Ok(());
+        }
+
+        let heights = rows_height(self);
+        let widths = columns_width(self);
+
+        let mut writer = IoWriter { w, err: None };
+        let result = print_grid(&mut writer, self, widths, heights, on_row);
+
+        match (result, writer.err) {
+            (_, Some(err)) => Err(err),
+            (Err(_), None) => Err(io::Error::new(io::ErrorKind::Other, "formatter error")),
+            (Ok(()), None) => Ok(()),
+        }
+    }
+
+    /// Renders the grid into a matrix of characters, a row per output line.
+    ///
+    /// Each element takes a column of the output,
+    /// so a wide character is followed by [WIDE_CHAR_PLACEHOLDER]s
+    /// and characters which have no width, like combining marks, are dropped.
+    /// Rows are padded by spaces to the same length.
+    /// It may be useful to find a difference between 2 renders.
+    ///
+    /// With the `color` feature ANSI escape sequences are not included,
+    /// see [Grid::to_styled_char_matrix].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings};
+    ///     let mut grid = Grid::new(1, 1);
+    ///     grid.set(Entity::Global, Settings::new().text("a"));
+    ///     assert_eq!(
+    ///          grid.to_char_matrix(),
+    ///          vec![
+    ///              vec!['+', '-', '+'],
+    ///              vec!['|', 'a', '|'],
+    ///              vec!['+', '-', '+'],
+    ///          ]
+    ///     )
+    /// ```
+    #[cfg(not(feature = "color"))]
+    pub fn to_char_matrix(&self) -> Vec<Vec<char>> {
+        let mut lines = self
+            .render_matrix()
+            .lines()
+            .map(|line| {
+                let mut cells = Vec::with_capacity(line.len());
+                for c in line.chars() {
+                    push_char_cells(&mut cells, c, |c| c);
+                }
+
+                cells
+            })
+            .collect::<Vec<_>>();
+
+        let width = lines.iter().map(Vec::len).max().unwrap_or(0);
+        for line in &mut lines {
+            line.resize(width, ' ');
+        }
+
+        lines
+    }
+
+    /// Renders the grid into a matrix of characters, a row per output line.
+    ///
+    /// Each element takes a column of the output,
+    /// so a wide character is followed by [WIDE_CHAR_PLACEHOLDER]s
+    /// and characters which have no width, like combining marks, are dropped.
+    /// Rows are padded by spaces to the same length.
+    /// It may be useful to find a difference between 2 renders.
+    ///
+    /// With the `color` feature ANSI escape sequences are not included,
+    /// see [Grid::to_styled_char_matrix].
+    #[cfg(feature = "color")]
+    pub fn to_char_matrix(&self) -> Vec<Vec<char>> {
+        self.to_styled_char_matrix()
+            .into_iter()
+            .map(|line| line.into_iter().map(|c| c.c).collect())
+            .collect()
+    }
+
+    /// Renders the grid into a matrix of characters with their ANSI styles,
+    /// a row per output line.
+    ///
+    /// Rows are padded by unstyled spaces to the same length.
+    /// A [WIDE_CHAR_PLACEHOLDER] following a wide character has its style.
+    #[cfg(feature = "color")]
+    pub fn to_styled_char_matrix(&self) -> Vec<Vec<StyledChar>> {
+        let mut lines = self
+            .render_matrix()
+            .lines()
+            .map(styled_chars)
+            .collect::<Vec<_>>();
+
+        let width = lines.iter().map(Vec::len).max().unwrap_or(0);
+        for line in &mut lines {
+            line.resize(width, StyledChar::new(' '));
+        }
+
+        lines
+    }
+
+    /// Returns an iterator over rendered lines of the grid.
+    ///
+    /// The grid is rendered lazily, a row at a time,
+    /// so it may be used to page a big grid without building the whole string.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings};
+    ///     let mut grid = Grid::new(2, 1);
+    ///     grid.set(Entity::Global, Settings::new().text("a"));
+    ///
+    ///     let mut lines = grid.lines();
+    ///     assert_eq!(lines.next().as_deref(), Some("+-+"));
+    ///     assert_eq!(lines.next().as_deref(), Some("|a|"));
+    /// ```
+    pub fn lines(&self) -> impl Iterator<Item = String> + '_ {
+        let is_empty = self.count_rows() == 0 || self.count_columns() == 0;
+        let (widths, heights) = if is_empty {
+            (Vec::new(), Vec::new())
+        } else {
+            (columns_width(self), rows_height(self).collect())
+        };
+
+        let table_width = row_width_grid(self, &widths);
+        let columns_max_line_width = columns_max_line_width(self);
+
+        LinesIter {
+            grid: self,
+            widths,
+            heights,
+            columns_max_line_width,
+            table_width,
+            stage: if is_empty { None } else { Some(0) },
+            buf: VecDeque::new(),
+        }
+    }
+
+    fn render_matrix(&self) -> String {
+        let mut buf = String::new();
+        if self.count_rows() == 0 || self.count_columns() == 0 {
+            return buf;
+        }
+
+        let heights = rows_height(self);
+        let widths = columns_width(self);
+
+        // writing to a string never fails
+        let _ = print_grid(&mut buf, self, widths, heights, |_| {});
+
+        buf
+    }
+}
+
+/// An iterator over rendered lines of a [Grid].
+struct LinesIter<'a> {
+    grid: &'a Grid,
+    widths: Vec<usize>,
+    heights: Vec<usize>,
+    columns_max_line_width: Option<Vec<usize>>,
+    table_width: usize,
+    // 0 is a top margin, then rows, then a bottom margin.
+    stage: Option<usize>,
+    buf: VecDeque<String>,
+}
+
+impl LinesIter<'_> {
+    fn render_stage(&self, stage: usize, f: &mut String) -> fmt::Result {
+        if self.grid.trim_trailing_whitespace {
+            let mut f = TrimTrailingWriter::new(f);
+            self.render_stage_lines(stage, &mut f)?;
+            f.finish()
+        } else {
+            self.render_stage_lines(stage, f)
+        }
+    }
+
+    fn render_stage_lines(&self, stage: usize, f: &mut impl Write) -> fmt::Result {
+        let count_rows = self.grid.count_rows();
+        if stage == 0 {
+            print_margin_top(f, &self.grid.margin, self.table_width)?;
+            print_caption(f, self.grid, self.table_width, CaptionPosition::Above)
+        } else if stage <= count_rows {
+            let row = stage - 1;
+            print_row(
+                f,
+                self.grid,
+                &self.widths,
+                self.columns_max_line_width.as_deref(),
+                self.table_width,
+                row,
+                self.heights[row],
+            )
+        } else {
+            print_caption(f, self.grid, self.table_width, CaptionPosition::Below)?;
+            print_margin_bottom(f, &self.grid.margin, self.table_width)
+        }
+    }
+}
+
+impl Iterator for LinesIter<'_> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.buf.is_empty() {
+            let stage = self.stage?;
+            self.stage = if stage > self.grid.count_rows() {
+                None
+            } else {
+                Some(stage + 1)
+            };
+
+            let mut text = String::new();
+            // writing to a string never fails
+            let _ = self.render_stage(stage, &mut text);
+
+            self.buf.extend(text.lines().map(ToOwned::to_owned));
+        }
+
+        self.buf.pop_front()
+    }
+}
+
+/// A character of a rendered grid with an ANSI style it's printed with.
+#[cfg(feature = "color")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StyledChar {
+    pub c: char,
+    /// ANSI escape sequences which set the style of the char.
+    ///
+    /// It's empty if the char has no style.
+    pub style: String,
+}
+
+#[cfg(feature = "color")]
+impl StyledChar {
+    fn new(c: char) -> Self {
+        Self {
+            c,
+            style: String::new(),
+        }
+    }
+}
+
+#[cfg(feature = "color")]
+impl fmt::Display for StyledChar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.style.is_empty() {
+            f.write_char(self.c)
+        } else {
+            write!(f, "{}{}\u{1b}[0m", self.style, self.c)
+        }
+    }
+}
+
+/// A character which fills the columns taken by a wide character
+/// in [Grid::to_char_matrix] and [Grid::to_styled_char_matrix].
+pub const WIDE_CHAR_PLACEHOLDER: char = '\0';
+
+/// Pushes a cell per column taken by the char, see [Grid::to_char_matrix].
+fn push_char_cells<T>(cells: &mut Vec<T>, c: char, cell: impl Fn(char) -> T) {
+    let width = char_width(c);
+    if width > 0 {
+        cells.push(cell(c));
+        cells.extend((1..width).map(|_| cell(WIDE_CHAR_PLACEHOLDER)));
+    }
+}
+
+/// Splits a line into chars with their ANSI styles.
+#[cfg(feature = "color")]
+fn styled_chars(line: &str) -> Vec<StyledChar> {
+    use ansi_str::AnsiStr;
+
+    let mut cells = Vec::with_capacity(line.len());
+    let mut rest = line.to_owned();
+    for c in line.ansi_strip().chars() {
+        // the part is the char surrounded by the sequences which set and reset its style
+        let (part, rhs) = rest.ansi_split_at(c.len_utf8());
+        rest = rhs;
+
+        // the sequences have no common bytes with the char so they are matched
+        // right up to it.
+        let sequences = part.ansi_cut(..0);
+        let style_len = part
+            .bytes()
+            .zip(sequences.bytes())
+            .take_while(|(a, b)| a == b)
+            .count();
+        let style = &part[..style_len];
+
+        push_char_cells(&mut cells, c, |c| StyledChar {
+            c,
+            style: style.to_owned(),
+        });
+    }
+
+    cells
+}
+
+/// An adapter which lets [fmt::Write] functions write into a [io::Write].
+struct IoWriter<'a, W> {
+    w: &'a mut W,
+    err: Option<io::Error>,
+}
+
+impl<W: io::Write> Write for IoWriter<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.w.write_all(s.as_bytes()).map_err(|err| {
+            self.err = Some(err);
+            fmt::Error
+        })
+    }
+}
+
+/// A writer which drops spaces at the end of each line.
+///
+/// A line is held back until it's finished,
+/// ANSI escape sequences among the trailing spaces are kept.
+struct TrimTrailingWriter<'a, W> {
+    f: &'a mut W,
+    line: String,
+}
+
+impl<'a, W: Write> TrimTrailingWriter<'a, W> {
+    fn new(f: &'a mut W) -> Self {
+        Self {
+            f,
+            line: String::new(),
+        }
+    }
+
+    /// Writes the held line without its trailing spaces.
+    fn finish(&mut self) -> fmt::Result {
+        let parts = ansi_parts(&self.line).collect::<Vec<_>>();
+        let last_text = parts.iter().rposition(|part| match part {
+            AnsiPart::Text(text) => !text.trim_end_matches(' ').is_empty(),
+            AnsiPart::Escape(_) => false,
+        });
+
+        for (i, part) in parts.into_iter().enumerate() {
+            match part {
+                AnsiPart::Escape(escape) => self.f.write_str(escape)?,
+                AnsiPart::Text(text) if Some(i) == last_text => {
+                    self.f.write_str(text.trim_end_matches(' '))?
+                }
+                AnsiPart::Text(text) if last_text.map_or(false, |last| i < last) => {
+                    self.f.write_str(text)?
+                }
+                AnsiPart::Text(_) => {}
+            }
+        }
+
+        self.line.clear();
+
+        Ok(())
+    }
+}
+
+impl<W: Write> Write for TrimTrailingWriter<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let mut lines = s.split('\n');
+        if let Some(line) = lines.next() {
+            self.line.push_str(line);
+        }
+
+        for line in lines {
+            self.finish()?;
+            self.f.write_char('\n')?;
+            self.line.push_str(line);
+        }
+
+        Ok(())
+    }
 }
 
 impl fmt::Display for Grid {
@@ -629,7 +2473,7 @@ impl fmt::Display for Grid {
         let heights = rows_height(self);
         let widths = columns_width(self);
 
-        print_grid(f, self, widths, heights)
+        print_grid(f, self, widths, heights, |_| {})
     }
 }
 
@@ -656,6 +2500,11 @@ pub struct Settings {
     alignment_h: Option<AlignmentHorizontal>,
     alignment_v: Option<AlignmentVertical>,
     formatting: Option<Formatting>,
+    max_height: Option<usize>,
+    #[cfg(feature = "color")]
+    background: Option<Color>,
+    #[cfg(feature = "color")]
+    color: Option<Color>,
 }
 
 impl Settings {
@@ -681,6 +2530,14 @@ impl Settings {
         self
     }
 
+    /// Sets the same amount of spaces on each side of a cell.
+    ///
+    /// See [Padding::uniform].
+    pub fn padding_uniform(mut self, size: usize) -> Self {
+        self.padding = Some(Padding::uniform(size));
+        self
+    }
+
     /// Alignment method sets horizontal alignment for a cell
     pub fn alignment(mut self, alignment: AlignmentHorizontal) -> Self {
         self.alignment_h = Some(alignment);
@@ -705,25 +2562,56 @@ impl Settings {
     /// So if there was no split line but border relies on it
     /// a error will be issued.
     ///
-    /// To fix it you can construct split lines before calling this function.
-    /// Or you can pass a `false` argument into [Self::border_restriction]
-    /// so if absent lines will be created.
-    pub fn border(mut self, border: Border) -> Self {
-        self.border = Some(border);
+    /// To fix it you can construct split lines before calling this function.
+    /// Or you can pass a `false` argument into [Self::border_restriction]
+    /// so if absent lines will be created.
+    pub fn border(mut self, border: Border) -> Self {
+        self.border = Some(border);
+        self
+    }
+
+    /// Set a formatting settings.
+    ///
+    /// It overades them even if any were not set.
+    pub fn formatting(mut self, formatting: Formatting) -> Self {
+        self.formatting = Some(formatting);
+        self
+    }
+
+    /// Set a maximum amount of content lines of a cell.
+    ///
+    /// The rest of the lines are not rendered,
+    /// and the last rendered line gets an `…` indicator.
+    pub fn max_height(mut self, height: usize) -> Self {
+        self.max_height = Some(height);
+        self
+    }
+
+    /// Set a background of a cell.
+    ///
+    /// ANSI sequences of the [Symbol] wrap each line of a cell, including padding,
+    /// so the whole cell is painted.
+    #[cfg(feature = "color")]
+    pub fn background(mut self, background: Symbol) -> Self {
+        let (prefix, suffix) = background.ansi_sequences();
+        self.background = Some(Color::new(prefix, suffix));
         self
     }
 
-    /// Set a formatting settings.
+    /// Set a color of a cell content.
     ///
-    /// It overades them even if any were not set.
-    pub fn formatting(mut self, formatting: Formatting) -> Self {
-        self.formatting = Some(formatting);
+    /// Each line of the content, without padding and alignment,
+    /// is wrapped by the [Color] sequences when the grid is printed.
+    #[cfg(feature = "color")]
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
         self
     }
 }
 
 /// Border is a representation of a cells's borders (left, right, top, bottom, and the corners)
 #[derive(Debug, Clone, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Border {
     pub top: Option<Symbol>,
     pub bottom: Option<Symbol>,
@@ -760,6 +2648,24 @@ impl Border {
         }
     }
 
+    /// This function constructs a cell borders with all sides set to [Symbol::empty].
+    ///
+    /// Unlike [Border::default] which doesn't change anything,
+    /// it removes the sides, so a border line which consists only of empty sides is not rendered at all.
+    /// If other cells have the line, the empty sides are rendered as a space.
+    pub fn empty() -> Self {
+        Self::new(
+            Symbol::empty(),
+            Symbol::empty(),
+            Symbol::empty(),
+            Symbol::empty(),
+            Symbol::empty(),
+            Symbol::empty(),
+            Symbol::empty(),
+            Symbol::empty(),
+        )
+    }
+
     /// This function constructs a cell borders with all sides's char set to a given character.
     /// It behaives like [Border::new] with the same character set to each side.
     pub fn filled(c: impl Into<Symbol>) -> Self {
@@ -832,6 +2738,14 @@ pub struct Style {
     pub alignment_h: AlignmentHorizontal,
     pub alignment_v: AlignmentVertical,
     pub formatting: Formatting,
+    /// A maximum amount of content lines.
+    pub max_height: Option<usize>,
+    /// A color which wraps each line of a cell, including its padding.
+    #[cfg(feature = "color")]
+    pub background: Option<Color>,
+    /// A color which wraps each line of a cell content.
+    #[cfg(feature = "color")]
+    pub color: Option<Color>,
 }
 
 impl Default for Style {
@@ -840,23 +2754,124 @@ impl Default for Style {
             padding: Padding::default(),
             alignment_h: AlignmentHorizontal::Left,
             alignment_v: AlignmentVertical::Top,
-            formatting: Formatting {
-                horizontal_trim: false,
-                vertical_trim: false,
-                allow_lines_alignement: false,
-                tab_width: 4,
-            },
+            formatting: Formatting::new(),
+            max_height: None,
+            #[cfg(feature = "color")]
+            background: None,
+            #[cfg(feature = "color")]
+            color: None,
         }
     }
 }
 
 /// Formatting represent a logic of formatting of a cell.
+///
+/// Notice that [Formatting::default] sets `tab_width` to 0, so tabs are removed,
+/// while [Formatting::new] is the formatting used by default by a [Grid] where a tab is 4 spaces.
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Formatting {
     pub horizontal_trim: bool,
     pub vertical_trim: bool,
+    /// Align each line of a cell content individually,
+    /// instead of aligning the content as a whole block against its widest line.
     pub allow_lines_alignement: bool,
+    /// Align a cell content as a whole against the widest line of the column,
+    /// so the lines of all cells in a column which have it set start at the same position.
+    ///
+    /// It's not used when `allow_lines_alignement` is set.
+    pub allow_column_alignment: bool,
     pub tab_width: usize,
+    /// Render a cell in a right-to-left mode.
+    ///
+    /// A horizontal alignment and left/right paddings are mirrored,
+    /// so a left aligned content is put on the right side.
+    /// The text itself is not reordered.
+    pub rtl: bool,
+}
+
+impl Formatting {
+    /// Creates a [Formatting] which is used by a [Grid] by default.
+    ///
+    /// All options are turned off and `tab_width` is set to 4.
+    pub fn new() -> Self {
+        Self {
+            tab_width: 4,
+            ..Default::default()
+        }
+    }
+}
+
+/// Side represents one of the 8 components of a cell [Border].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Side {
+    Top,
+    Bottom,
+    Left,
+    Right,
+    TopLeftCorner,
+    TopRightCorner,
+    BottomLeftCorner,
+    BottomRightCorner,
+}
+
+/// Caption is a text printed above or below the frame of a [Grid].
+///
+/// See [Grid::set_caption].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Caption {
+    /// A text of the caption, each line of which is aligned separately.
+    ///
+    /// A line wider than the table is cut to its width.
+    pub text: String,
+    /// A side of the table the caption is printed on.
+    pub position: CaptionPosition,
+    /// An alignment of the caption within the width of the table.
+    pub alignment: AlignmentHorizontal,
+}
+
+/// CaptionPosition is a side of the table a [Caption] is printed on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CaptionPosition {
+    Above,
+    Below,
+}
+
+/// GridLayout represents sizes and styles a [Grid] is rendered with.
+///
+/// It's returned by [Grid::layout].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GridLayout {
+    /// Widths of the columns, including padding.
+    ///
+    /// It respects widths set by [Grid::set_column_width].
+    pub widths: Vec<usize>,
+    /// Widths of the columns driven only by the content, including padding.
+    ///
+    /// See [Grid::natural_column_widths].
+    pub normal_widths: Vec<usize>,
+    /// Heights of the rows, including padding, but not the row spacing.
+    pub heights: Vec<usize>,
+    /// Styles of each cell row by row, with row, column and global styles resolved.
+    pub styles: Vec<Vec<Style>>,
+}
+
+/// WidthBreakdown represents components of a total width of a [Grid].
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WidthBreakdown {
+    /// A width of the cells, including their paddings.
+    pub content: usize,
+    /// A width of the vertical split lines.
+    pub borders: usize,
+    /// A width of the left and right margins.
+    pub margin: usize,
+}
+
+impl WidthBreakdown {
+    /// Returns a total width.
+    pub fn total(&self) -> usize {
+        self.content + self.borders + self.margin
+    }
 }
 
 /// Margin represent a 4 indents of table as a whole.
@@ -870,6 +2885,7 @@ pub struct Margin {
 
 /// Padding represent a 4 indents of cell.
 #[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Padding {
     pub top: Indent,
     pub bottom: Indent,
@@ -877,8 +2893,56 @@ pub struct Padding {
     pub right: Indent,
 }
 
+impl Padding {
+    /// Creates a [Padding] with the same amount of spaces on each side.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Indent, Padding};
+    ///
+    ///     assert_eq!(
+    ///         Padding::uniform(2),
+    ///         Padding {
+    ///             top: Indent::spaced(2),
+    ///             bottom: Indent::spaced(2),
+    ///             left: Indent::spaced(2),
+    ///             right: Indent::spaced(2),
+    ///         }
+    ///     );
+    /// ```
+    pub fn uniform(size: usize) -> Self {
+        let indent = Indent::spaced(size);
+        Self {
+            top: indent,
+            bottom: indent,
+            left: indent,
+            right: indent,
+        }
+    }
+
+    /// Creates a [Padding] with `size` spaces on the left and right sides only.
+    pub fn horizontal(size: usize) -> Self {
+        Self {
+            left: Indent::spaced(size),
+            right: Indent::spaced(size),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a [Padding] with `size` lines on the top and bottom sides only.
+    pub fn vertical(size: usize) -> Self {
+        Self {
+            top: Indent::spaced(size),
+            bottom: Indent::spaced(size),
+            ..Default::default()
+        }
+    }
+}
+
 /// Indent represent a filled space.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Indent {
     pub fill: char,
     pub size: usize,
@@ -910,8 +2974,12 @@ impl Default for Indent {
 
 /// AlignmentHorizontal represents an horizontal alignment of a cell content.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AlignmentHorizontal {
     Center,
+    /// Centers content, but puts an extra space on the left side
+    /// when it can't be divided evenly.
+    CenterLeftBias,
     Left,
     Right,
 }
@@ -919,7 +2987,7 @@ pub enum AlignmentHorizontal {
 impl AlignmentHorizontal {
     fn align_with_max_width(
         &self,
-        f: &mut fmt::Formatter<'_>,
+        f: &mut impl Write,
         text: &str,
         width: usize,
         text_width: usize,
@@ -941,11 +3009,17 @@ impl AlignmentHorizontal {
                 let rest = diff - left;
                 Self::align(f, text, left, rest, tab_width)
             }
+            AlignmentHorizontal::CenterLeftBias => {
+                let max_diff = width - max_text_width;
+                let left = (max_diff + 1) / 2;
+                let rest = diff - left;
+                Self::align(f, text, left, rest, tab_width)
+            }
         }
     }
 
     fn align(
-        f: &mut fmt::Formatter<'_>,
+        f: &mut impl Write,
         text: &str,
         left: usize,
         right: usize,
@@ -970,16 +3044,21 @@ impl AlignmentHorizontal {
 
 /// AlignmentVertical represents an vertical alignment of a cell content.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AlignmentVertical {
     Center,
     Top,
     Bottom,
+    /// Spreads lines evenly across a cell height by inserting empty lines between them.
+    ///
+    /// A single line is aligned as [AlignmentVertical::Top].
+    Justify,
 }
 
 impl AlignmentVertical {
     fn top_ident(&self, height: usize, real_height: usize) -> usize {
         match self {
-            AlignmentVertical::Top => 0,
+            AlignmentVertical::Top | AlignmentVertical::Justify => 0,
             AlignmentVertical::Bottom => height - real_height,
             AlignmentVertical::Center => (height - real_height) / 2,
         }
@@ -987,18 +3066,28 @@ impl AlignmentVertical {
 }
 
 fn build_line_cell(
-    f: &mut fmt::Formatter<'_>,
+    f: &mut impl Write,
     line_index: usize,
     cell: &str,
     style: &Style,
     width: usize,
     height: usize,
+    column_max_width: usize,
 ) -> fmt::Result {
     let cell_height = count_lines(cell);
     if style.formatting.vertical_trim {
         let cell = skip_empty_lines(cell, cell_height);
         let cell_height = cell.clone().count();
-        build_format_line(f, line_index, cell, style, width, height, cell_height)
+        build_format_line(
+            f,
+            line_index,
+            cell,
+            style,
+            width,
+            height,
+            cell_height,
+            column_max_width,
+        )
     } else {
         build_format_line(
             f,
@@ -1008,29 +3097,50 @@ fn build_line_cell(
             width,
             height,
             cell_height,
+            column_max_width,
         )
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn build_format_line<'a>(
-    f: &mut fmt::Formatter<'_>,
+    f: &mut impl Write,
     line_index: usize,
     mut cell: impl Iterator<Item = &'a str>,
     style: &Style,
     width: usize,
     height: usize,
     cell_height: usize,
+    column_max_width: usize,
 ) -> Result<(), fmt::Error> {
+    if line_index < style.padding.top.size {
+        return repeat_char(f, style.padding.top.fill, width);
+    }
+
+    if line_index >= height.saturating_sub(style.padding.bottom.size) {
+        return repeat_char(f, style.padding.bottom.fill, width);
+    }
+
+    // lines added by a vertical alignment belong to the content area,
+    // so they're filled the same way as the content lines.
     let top_indent = top_indent(cell_height, style, height);
     if top_indent > line_index {
-        return repeat_char(f, style.padding.top.fill, width);
+        return line_with_width(f, "", width, 0, 0, style);
+    }
+
+    let mut cell_line_index = line_index - top_indent;
+    if style.alignment_v == AlignmentVertical::Justify {
+        let height = height - style.padding.top.size - style.padding.bottom.size;
+        match justify_line_index(cell_line_index, cell_height, height) {
+            Some(index) => cell_line_index = index,
+            None => return line_with_width(f, "", width, 0, 0, style),
+        }
     }
 
-    let cell_line_index = line_index - top_indent;
     let cell_has_this_line = cell_height > cell_line_index;
     // happens when other cells have bigger height
     if !cell_has_this_line {
-        return repeat_char(f, style.padding.bottom.fill, width);
+        return line_with_width(f, "", width, 0, 0, style);
     }
 
     if style.formatting.allow_lines_alignement {
@@ -1076,6 +3186,12 @@ fn build_format_line<'a>(
                 acc
             });
 
+        let max_line_width = if style.formatting.allow_column_alignment {
+            cmp::max(max_line_width, column_max_width)
+        } else {
+            max_line_width
+        };
+
         line_with_width(f, text, width, line_width, max_line_width, style)
     }
 }
@@ -1087,61 +3203,143 @@ fn skip_empty_lines(s: &str, length: usize) -> impl Iterator<Item = &'_ str> + C
     s.lines().take(n).skip_while(is_empty)
 }
 
+/// Maps a line index within a justified area onto a content line index.
+///
+/// [None] is returned for an empty line inserted between content lines.
+fn justify_line_index(index: usize, cell_height: usize, height: usize) -> Option<usize> {
+    if cell_height < 2 || height <= cell_height || index >= height {
+        return Some(index);
+    }
+
+    let gaps = cell_height - 1;
+    let free = height - cell_height;
+    let gap = free / gaps;
+    let extra = free % gaps;
+
+    (0..cell_height).find(|&line| line + line * gap + cmp::min(line, extra) == index)
+}
+
 fn top_indent(cell_height: usize, style: &Style, height: usize) -> usize {
-    let height = height - style.padding.top.size;
+    // the content is aligned within the area between top and bottom paddings.
+    let height = height - style.padding.top.size - style.padding.bottom.size;
     let indent = style.alignment_v.top_ident(height, cell_height);
 
     indent + style.padding.top.size
 }
 
-fn repeat_symbol(f: &mut fmt::Formatter<'_>, c: &Symbol, n: usize) -> fmt::Result {
-    if n > 0 {
-        for _ in 0..n {
-            c.fmt(f)?;
-        }
+fn repeat_symbol(f: &mut impl Write, c: &Symbol, n: usize) -> fmt::Result {
+    repeat_with_width(f, c, c.width(), n)
+}
+
+fn repeat_char(f: &mut impl Write, c: char, n: usize) -> fmt::Result {
+    let width = char_width(c);
+    if width > 1 {
+        return repeat_with_width(f, c, width, n);
+    }
+
+    // it's used for padding each line of a cell, so it's kept fast for a common char.
+    for _ in 0..n {
+        f.write_char(c)?;
     }
+
     Ok(())
 }
 
-fn repeat_char(f: &mut fmt::Formatter<'_>, c: char, n: usize) -> fmt::Result {
-    if n > 0 {
-        for _ in 0..n {
-            f.write_char(c)?;
-        }
+/// Repeats `c` so it takes exactly `n` columns.
+///
+/// A symbol is expected to take 1 column,
+/// but a wider one is repeated fewer times so it never overflows `n`
+/// and the remaining columns are filled by spaces.
+fn repeat_with_width(
+    f: &mut impl Write,
+    c: impl fmt::Display,
+    width: usize,
+    n: usize,
+) -> fmt::Result {
+    let width = cmp::max(width, 1);
+    for _ in 0..n / width {
+        write!(f, "{}", c)?;
+    }
+
+    for _ in 0..n % width {
+        f.write_char(' ')?;
     }
+
     Ok(())
 }
 
 fn line_with_width(
-    f: &mut fmt::Formatter<'_>,
+    f: &mut impl Write,
     text: &str,
     width: usize,
     width_text: usize,
     width_text_max: usize,
     style: &Style,
 ) -> fmt::Result {
-    let left_indent = style.padding.left;
-    let right_indent = style.padding.right;
-    let alignment = style.alignment_h;
+    let (left_indent, right_indent, alignment) = if style.formatting.rtl {
+        let alignment = match style.alignment_h {
+            AlignmentHorizontal::Left => AlignmentHorizontal::Right,
+            AlignmentHorizontal::Right => AlignmentHorizontal::Left,
+            AlignmentHorizontal::Center => AlignmentHorizontal::CenterLeftBias,
+            AlignmentHorizontal::CenterLeftBias => AlignmentHorizontal::Center,
+        };
+
+        (style.padding.right, style.padding.left, alignment)
+    } else {
+        (style.padding.left, style.padding.right, style.alignment_h)
+    };
 
     repeat_char(f, left_indent.fill, left_indent.size)?;
-    let width = width - left_indent.size - right_indent.size;
-    alignment.align_with_max_width(
-        f,
-        text,
-        width,
-        width_text,
-        width_text_max,
-        style.formatting.tab_width,
-    )?;
+    let width = width.saturating_sub(left_indent.size + right_indent.size);
+
+    // it may happen when a width of a column was set explicitly
+    if width_text > width {
+        let text = cut_str(&replace_tab(text, style.formatting.tab_width), width);
+        let width_text = string_width(&text);
+        let text = colorize_line(&text, style);
+        alignment.align_with_max_width(f, &text, width, width_text, width_text, 0)?;
+    } else {
+        alignment.align_with_max_width(
+            f,
+            &colorize_line(text, style),
+            width,
+            width_text,
+            cmp::min(width_text_max, width),
+            style.formatting.tab_width,
+        )?;
+    }
+
     repeat_char(f, right_indent.fill, right_indent.size)?;
 
     Ok(())
 }
 
+#[cfg(feature = "color")]
+fn colorize_line<'a>(line: &'a str, style: &Style) -> Cow<'a, str> {
+    match &style.color {
+        Some(color) if !line.is_empty() => Cow::Owned(color.colorize(line)),
+        _ => Cow::Borrowed(line),
+    }
+}
+
+#[cfg(not(feature = "color"))]
+fn colorize_line<'a>(line: &'a str, _: &Style) -> Cow<'a, str> {
+    Cow::Borrowed(line)
+}
+
 fn columns_width(grid: &Grid) -> Vec<usize> {
+    columns_width_with(grid, &grid.column_widths)
+}
+
+/// Calculates columns widths where `forced_widths` are used instead of the content widths.
+fn columns_width_with(grid: &Grid, forced_widths: &HashMap<usize, usize>) -> Vec<usize> {
     let mut widths = Vec::with_capacity(grid.count_columns());
     for col in 0..grid.count_columns() {
+        if let Some(&width) = forced_widths.get(&col) {
+            widths.push(width);
+            continue;
+        }
+
         let mut max = 0;
 
         for row in 0..grid.count_rows() {
@@ -1156,18 +3354,25 @@ fn columns_width(grid: &Grid) -> Vec<usize> {
         widths.push(max);
     }
 
-    adjust_spans(grid, &mut widths);
+    adjust_spans(grid, &mut widths, forced_widths);
 
     widths
 }
 
-fn adjust_spans(grid: &Grid, widths: &mut [usize]) {
+fn adjust_spans(grid: &Grid, widths: &mut [usize], forced_widths: &HashMap<usize, usize>) {
     if grid.spans.is_empty() {
         return;
     }
 
     for (&(start, end), rows) in &grid.spans {
-        adjust_range(grid, rows.iter().copied(), widths, start, end);
+        adjust_range(
+            grid,
+            rows.iter().copied(),
+            widths,
+            forced_widths,
+            start,
+            end,
+        );
     }
 }
 
@@ -1175,6 +3380,7 @@ fn adjust_range(
     grid: &Grid,
     rows: impl ExactSizeIterator<Item = usize>,
     widths: &mut [usize],
+    forced_widths: &HashMap<usize, usize>,
     start: usize,
     end: usize,
 ) {
@@ -1192,13 +3398,19 @@ fn adjust_range(
         return;
     }
 
-    inc_range_width(widths, max_span_width - range_width, start, end);
+    inc_range_width(
+        widths,
+        forced_widths,
+        max_span_width - range_width,
+        start,
+        end,
+    );
 }
 
 fn get_cell_width(grid: &Grid, (row, col): Position) -> usize {
     let style = grid.style(Entity::Cell(row, col));
-    let text = &grid.cells[row][col];
-    let width = string_width_multiline_tab(text, style.formatting.tab_width);
+    let text = cut_height(&grid.cells[row][col], style);
+    let width = string_width_multiline_tab(&text, style.formatting.tab_width);
 
     width + style.padding.left.size + style.padding.right.size
 }
@@ -1220,6 +3432,69 @@ fn is_cell_overriden(grid: &Grid, pos: Position) -> bool {
         .any(|(&(start, end), rows)| pos.1 > start && pos.1 < end && rows.contains(&pos.0))
 }
 
+/// Checks whether an intersection of a split line `row` and a vertical line `col`
+/// is hidden by spans on both sides of the split line.
+///
+/// A side outside of the grid is considered hidden.
+fn is_split_line_inside_span(grid: &Grid, row: usize, col: usize) -> bool {
+    if grid.count_rows() == 0 {
+        return false;
+    }
+
+    let is_above_hidden = row == 0 || !is_cell_visible(grid, (row - 1, col));
+    let is_below_hidden = row == grid.count_rows() || !is_cell_visible(grid, (row, col));
+
+    is_above_hidden && is_below_hidden
+}
+
+/// Removes `\r` characters which are not a part of a `\r\n` sequence.
+/// Replaces each visible character of a text by `fill` repeated to its width,
+/// keeping whitespace and ANSI sequences.
+fn skeleton_text(text: &str, fill: char) -> String {
+    let fill_chars = |buf: &mut String, text: &str| {
+        for c in text.chars() {
+            if c.is_whitespace() {
+                buf.push(c);
+            } else {
+                buf.extend(std::iter::repeat(fill).take(char_width(c)));
+            }
+        }
+    };
+
+    let mut buf = String::with_capacity(text.len());
+
+    #[cfg(feature = "color")]
+    for part in ansi_parts(text) {
+        match part {
+            AnsiPart::Text(text) => fill_chars(&mut buf, text),
+            AnsiPart::Escape(escape) => buf.push_str(escape),
+        }
+    }
+
+    #[cfg(not(feature = "color"))]
+    fill_chars(&mut buf, text);
+
+    buf
+}
+
+fn strip_lone_carriage_returns(text: String) -> String {
+    if !text.contains('\r') {
+        return text;
+    }
+
+    let mut buf = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\r' && chars.peek() != Some(&'\n') {
+            continue;
+        }
+
+        buf.push(c);
+    }
+
+    buf
+}
+
 fn is_simple_cell(grid: &Grid, pos: Position) -> bool {
     let is_spanned = grid
         .spans
@@ -1236,24 +3511,35 @@ pub fn count_borders_in_range(grid: &Grid, start: usize, end: usize) -> usize {
         .count()
 }
 
-fn inc_range_width(widths: &mut [usize], size: usize, start: usize, end: usize) {
+fn inc_range_width(
+    widths: &mut [usize],
+    forced_widths: &HashMap<usize, usize>,
+    size: usize,
+    start: usize,
+    end: usize,
+) {
     if widths.is_empty() {
         return;
     }
 
-    let span = end - start;
+    // columns with a set width are not changed
+    let columns = (start..end)
+        .filter(|col| !forced_widths.contains_key(col))
+        .collect::<Vec<_>>();
+    if columns.is_empty() {
+        return;
+    }
+
+    let span = columns.len();
     let one = size / span;
     let rest = size - span * one;
 
-    let mut i = start;
-    while i < end {
-        if i == start {
-            widths[i] += one + rest;
+    for (i, &col) in columns.iter().enumerate() {
+        if i == 0 {
+            widths[col] += one + rest;
         } else {
-            widths[i] += one;
+            widths[col] += one;
         }
-
-        i += 1;
     }
 }
 
@@ -1275,9 +3561,9 @@ fn rows_height(grid: &Grid) -> impl Iterator<Item = usize> + '_ {
     (0..grid.count_rows()).map(move |row| {
         let mut max_height = 0;
         (0..grid.count_columns()).for_each(|col| {
-            let cell = &grid.cells[row][col];
             let style = grid.style(Entity::Cell(row, col));
-            let cell_height = cell_height(cell, style);
+            let cell = cut_height(&grid.cells[row][col], style);
+            let cell_height = cell_height(&cell, style);
             max_height = max(max_height, cell_height);
         });
 
@@ -1295,9 +3581,30 @@ fn cell_height(cell: &str, style: &Style) -> usize {
     content_height + style.padding.top.size + style.padding.bottom.size
 }
 
+const HEIGHT_TRUNCATION_INDICATOR: char = '…';
+
+/// Cuts the content lines to [Style::max_height] if it's set.
+fn cut_height<'a>(text: &'a str, style: &Style) -> Cow<'a, str> {
+    let max_height = match style.max_height {
+        Some(max_height) => max_height,
+        None => return Cow::Borrowed(text),
+    };
+
+    if count_lines(text) <= max_height {
+        return Cow::Borrowed(text);
+    }
+
+    let mut lines = text.lines().take(max_height).collect::<Vec<_>>().join("\n");
+    if max_height > 0 {
+        lines.push(HEIGHT_TRUNCATION_INDICATOR);
+    }
+
+    Cow::Owned(lines)
+}
+
 fn replace_tab(text: &str, n: usize) -> String {
     // it's a general case which probably must be faster?
-    if n == 4 {
+    if n == 4 && !text.contains("\\\t") {
         text.replace('\t', "    ")
     } else {
         let mut text = text.to_owned();
@@ -1311,7 +3618,14 @@ fn replace_tab_range(cell: &mut String, n: usize) -> &str {
     while let &Some(pos) = &cell[skip..].find('\t') {
         let pos = skip + pos;
 
-        let is_escaped = pos > 0 && cell.get(pos - 1..pos) == Some("\\");
+        // a tab is escaped only by an odd amount of backslashes,
+        // because a pair of backslashes is an escaped backslash itself.
+        let count_backslashes = cell[..pos]
+            .bytes()
+            .rev()
+            .take_while(|&b| b == b'\\')
+            .count();
+        let is_escaped = count_backslashes % 2 == 1;
         if is_escaped {
             skip = pos + 1;
         } else if n == 0 {
@@ -1332,9 +3646,9 @@ fn replace_tab_range(cell: &mut String, n: usize) -> &str {
 }
 
 // only valid to call for stabilized widths.
-fn total_width(grid: &Grid, widths: &[usize], margin: &Margin) -> usize {
+fn width_breakdown(grid: &Grid, widths: &[usize], margin: &Margin) -> WidthBreakdown {
     if grid.count_rows() == 0 {
-        return 0;
+        return WidthBreakdown::default();
     }
 
     let content_width = (0..grid.count_columns())
@@ -1355,96 +3669,205 @@ fn total_width(grid: &Grid, widths: &[usize], margin: &Margin) -> usize {
         last_col_border + rest_borders
     };
 
-    content_width + count_borders + margin.left.size + margin.right.size
+    WidthBreakdown {
+        content: content_width,
+        borders: count_borders,
+        margin: margin.left.size + margin.right.size,
+    }
 }
 
 /// strip cuts the string to a specific width.
 ///
 /// Width is expected to be in bytes.
 pub fn cut_str(s: &str, width: usize) -> String {
-    __cut_str(s, width)
+    cut_str_range(s, 0, width)
+}
+
+/// Cuts the string to a range of display columns `start..end`.
+///
+/// The string is cut by grapheme clusters,
+/// so a cluster which is only partially in the range is dropped as a whole.
+///
+/// # Example
+///
+/// ```
+/// use papergrid::cut_str_range;
+///
+/// assert_eq!(cut_str_range("Hello World", 3, 8), "lo Wo");
+/// assert_eq!(cut_str_range("😳😳😳", 1, 5), "😳");
+/// ```
+pub fn cut_str_range(s: &str, start: usize, end: usize) -> String {
+    let end = cmp::max(start, end);
+    __cut_str_range(s, start, end)
 }
 
 #[cfg(not(feature = "color"))]
-fn __cut_str(s: &str, width: usize) -> String {
-    const REPLACEMENT: char = '\u{FFFD}';
+fn __cut_str_range(s: &str, start: usize, end: usize) -> String {
+    let (byte_start, byte_end) = grapheme_byte_range(s, start, end);
+    s[byte_start..byte_end].to_owned()
+}
+
+#[cfg(feature = "color")]
+fn __cut_str_range(s: &str, start: usize, end: usize) -> String {
+    let stripped = ansi_str::AnsiStr::ansi_strip(s);
+    let (byte_start, byte_end) = grapheme_byte_range(&stripped, start, end);
+    ansi_str::AnsiStr::ansi_cut(s, byte_start..byte_end)
+}
 
-    let mut buf = String::with_capacity(width);
+// Returns a byte range of grapheme clusters which fully fit into the `start..end` columns.
+fn grapheme_byte_range(s: &str, start: usize, end: usize) -> (usize, usize) {
+    let mut byte_start = 0;
+    let mut byte_end = 0;
     let mut i = 0;
-    for c in s.chars() {
-        if i == width {
+    for (pos, grapheme) in unicode_segmentation::UnicodeSegmentation::grapheme_indices(s, true) {
+        let width = unicode_width::UnicodeWidthStr::width(grapheme);
+        let next_pos = pos + grapheme.len();
+
+        if i < start {
+            i += width;
+            byte_start = next_pos;
+            byte_end = next_pos;
+            continue;
+        }
+
+        if i + width > end {
             break;
-        };
+        }
 
-        let c_width = unicode_width::UnicodeWidthChar::width(c).unwrap_or(0);
+        i += width;
+        byte_end = next_pos;
+    }
 
-        // We cut the chars which takes more then 1 symbol to display,
-        // in order to archive the necessary width.
-        if i + c_width > width {
-            let count = width - i;
-            i += count;
+    (byte_start, byte_end)
+}
 
-            for _ in 0..count {
-                buf.push(REPLACEMENT);
-            }
-        } else {
-            i += c_width;
-            buf.push(c);
-        }
-    }
+/// Returns a display width of a char, control characters have no width.
+fn char_width(c: char) -> usize {
+    unicode_width::UnicodeWidthChar::width(c).unwrap_or(0)
+}
 
-    buf
+/// Returns a string width.
+#[cfg(not(feature = "color"))]
+pub fn string_width(text: &str) -> usize {
+    unicode_width::UnicodeWidthStr::width(text)
 }
 
+/// Returns a string width.
 #[cfg(feature = "color")]
-fn __cut_str(s: &str, width: usize) -> String {
-    let stripped = ansi_str::AnsiStr::ansi_strip(s);
-    let (byte_length, count_unknowns, _) = cut_str_to_min_length(&stripped, width);
-    let mut buf = ansi_str::AnsiStr::ansi_cut(s, ..byte_length);
+pub fn string_width(text: &str) -> usize {
+    let b = strip_ansi_escapes::strip(text.as_bytes()).unwrap();
+    let s = std::str::from_utf8(&b).unwrap();
+    unicode_width::UnicodeWidthStr::width(s)
+}
 
-    const REPLACEMENT: char = '\u{FFFD}';
-    buf.extend(std::iter::repeat(REPLACEMENT).take(count_unknowns));
+/// Returns a width of a string treating it as a single line.
+///
+/// A `\n` char is not considered as a line break, so the result is the same as [string_width].
+/// Use [string_width_multiline] to get a max width across lines.
+///
+/// Unlike [string_width] it doesn't allocate, as ANSI sequences are skipped in place,
+/// and an ASCII text is measured by its bytes.
+pub fn string_width_single_line(text: &str) -> usize {
+    #[cfg(feature = "color")]
+    {
+        ansi_parts(text)
+            .map(|part| match part {
+                AnsiPart::Text(text) => text_width(text),
+                AnsiPart::Escape(_) => 0,
+            })
+            .sum()
+    }
 
-    buf
+    #[cfg(not(feature = "color"))]
+    {
+        text_width(text)
+    }
 }
 
-#[cfg(feature = "color")]
-fn cut_str_to_min_length(s: &str, width: usize) -> (usize, usize, usize) {
-    let mut length = 0;
-    let mut i = 0;
-    for c in s.chars() {
-        if i == width {
-            break;
-        };
+/// Returns a width of a text without ANSI sequences.
+fn text_width(text: &str) -> usize {
+    if text.is_ascii() {
+        // control characters have no width
+        text.bytes().filter(|b| !b.is_ascii_control()).count()
+    } else {
+        unicode_width::UnicodeWidthStr::width(text)
+    }
+}
 
-        let c_width = unicode_width::UnicodeWidthChar::width(c).unwrap_or(0);
+/// A part of a string split by [ansi_parts].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnsiPart<'a> {
+    /// A text without escape sequences.
+    Text(&'a str),
+    /// An ANSI escape sequence, including its `ESC` character.
+    Escape(&'a str),
+}
 
-        // We cut the chars which takes more then 1 symbol to display,
-        // in order to archive the necessary width.
-        if i + c_width > width {
-            let count = width - i;
-            return (length, count, c.len_utf8());
+/// Splits a string into ANSI escape sequences and the text between them.
+///
+/// A CSI sequence ends with a character in `@..=~` range,
+/// an OSC sequence ends with either `BEL` or `ESC \`,
+/// any other sequence is considered to be `ESC` followed by a single character.
+/// An unfinished sequence takes the rest of the string.
+///
+/// # Example
+///
+/// ```
+/// use papergrid::{ansi_parts, AnsiPart};
+///
+/// assert_eq!(
+///     ansi_parts("\u{1b}[31mred\u{1b}[39m").collect::<Vec<_>>(),
+///     [
+///         AnsiPart::Escape("\u{1b}[31m"),
+///         AnsiPart::Text("red"),
+///         AnsiPart::Escape("\u{1b}[39m"),
+///     ]
+/// );
+/// ```
+pub fn ansi_parts(s: &str) -> impl Iterator<Item = AnsiPart<'_>> {
+    let mut rest = s;
+    std::iter::from_fn(move || {
+        if rest.is_empty() {
+            return None;
         }
 
-        i += c_width;
-        length += c.len_utf8();
-    }
+        let (part, tail) = if rest.starts_with('\u{1b}') {
+            let (escape, tail) = rest.split_at(escape_sequence_len(rest));
+            (AnsiPart::Escape(escape), tail)
+        } else {
+            let end = rest.find('\u{1b}').unwrap_or(rest.len());
+            let (text, tail) = rest.split_at(end);
+            (AnsiPart::Text(text), tail)
+        };
 
-    (length, 0, 0)
+        rest = tail;
+        Some(part)
+    })
 }
 
-/// Returns a string width.
-#[cfg(not(feature = "color"))]
-pub fn string_width(text: &str) -> usize {
-    unicode_width::UnicodeWidthStr::width(text)
-}
+/// Returns a length in bytes of an escape sequence which `s` starts with.
+fn escape_sequence_len(s: &str) -> usize {
+    let mut chars = s.char_indices().skip(1);
+    match chars.next() {
+        Some((_, '[')) => chars
+            .find(|&(_, c)| ('@'..='~').contains(&c))
+            .map_or(s.len(), |(i, c)| i + c.len_utf8()),
+        Some((_, ']')) => {
+            for (i, c) in chars {
+                if c == '\u{7}' {
+                    return i + 1;
+                }
 
-/// Returns a string width.
-#[cfg(feature = "color")]
-pub fn string_width(text: &str) -> usize {
-    let b = strip_ansi_escapes::strip(text.as_bytes()).unwrap();
-    let s = std::str::from_utf8(&b).unwrap();
-    unicode_width::UnicodeWidthStr::width(s)
+                if c == '\u{1b}' && s[i + 1..].starts_with('\\') {
+                    return i + 2;
+                }
+            }
+
+            s.len()
+        }
+        Some((i, c)) => i + c.len_utf8(),
+        None => s.len(),
+    }
 }
 
 /// Returns a max string width of a line.
@@ -1468,6 +3891,14 @@ pub fn string_width_multiline(text: &str) -> usize {
         .unwrap_or(0)
 }
 
+/// Returns a max string width of a line, where tabs are expanded into `tab_width` spaces.
+///
+/// It's the width a text is going to take when rendered with the given tab width,
+/// unlike [string_width_multiline] which doesn't expand tabs.
+pub fn string_width_with_tabs(text: &str, tab_width: usize) -> usize {
+    string_width_multiline(&replace_tab(text, tab_width))
+}
+
 fn string_width_tab(text: &str, tab_width: usize) -> usize {
     let width = string_width(text);
     let count_tabs = count_tabs(text);
@@ -1487,9 +3918,31 @@ struct Theme {
     borders: Borders,
     override_borders: BordersMap,
     override_lines: HashMap<usize, Line>,
+    intersection_resolver: Option<IntersectionResolver>,
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Clone)]
+struct IntersectionResolver(Arc<dyn Fn(BorderSides) -> Symbol + Send + Sync>);
+
+impl fmt::Debug for IntersectionResolver {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("IntersectionResolver")
+    }
+}
+
+/// BorderSides represents the border segments which meet at an intersection.
+///
+/// A segment is `None` if there's no border on the side.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BorderSides {
+    pub up: Option<Symbol>,
+    pub down: Option<Symbol>,
+    pub left: Option<Symbol>,
+    pub right: Option<Symbol>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Borders {
     pub top: Option<Symbol>,
     pub top_left: Option<Symbol>,
@@ -1512,14 +3965,436 @@ pub struct Borders {
     pub intersection: Option<Symbol>,
 }
 
+impl Borders {
+    /// Returns borders of an ASCII style, which is the default one.
+    ///
+    /// ```text
+    /// +---+---+
+    /// |0-0|0-1|
+    /// +---+---+
+    /// ```
+    pub const fn ascii() -> Self {
+        DEFAULT_BORDERS
+    }
+
+    /// Returns borders of a modern style.
+    ///
+    /// ```text
+    /// ┌───┬───┐
+    /// │0-0│0-1│
+    /// ├───┼───┤
+    /// │1-0│1-1│
+    /// └───┴───┘
+    /// ```
+    pub const fn modern() -> Self {
+        Self {
+            top: symbol('─'),
+            top_left: symbol('┌'),
+            top_right: symbol('┐'),
+            top_intersection: symbol('┬'),
+
+            bottom: symbol('─'),
+            bottom_left: symbol('└'),
+            bottom_right: symbol('┘'),
+            bottom_intersection: symbol('┴'),
+
+            horizontal: symbol('─'),
+            horizontal_left: symbol('├'),
+            horizontal_right: symbol('┤'),
+
+            vertical_left: symbol('│'),
+            vertical_intersection: symbol('│'),
+            vertical_right: symbol('│'),
+
+            intersection: symbol('┼'),
+        }
+    }
+
+    /// Returns borders of a rounded style.
+    ///
+    /// It has no inner horizontal lines.
+    ///
+    /// ```text
+    /// ╭───┬───╮
+    /// │0-0│0-1│
+    /// │1-0│1-1│
+    /// ╰───┴───╯
+    /// ```
+    pub const fn rounded() -> Self {
+        Self {
+            top: symbol('─'),
+            top_left: symbol('╭'),
+            top_right: symbol('╮'),
+            top_intersection: symbol('┬'),
+
+            bottom: symbol('─'),
+            bottom_left: symbol('╰'),
+            bottom_right: symbol('╯'),
+            bottom_intersection: symbol('┴'),
+
+            horizontal: None,
+            horizontal_left: None,
+            horizontal_right: None,
+
+            vertical_left: symbol('│'),
+            vertical_intersection: symbol('│'),
+            vertical_right: symbol('│'),
+
+            intersection: None,
+        }
+    }
+
+    /// Returns borders of an extended style.
+    ///
+    /// ```text
+    /// ╔═══╦═══╗
+    /// ║0-0║0-1║
+    /// ╠═══╬═══╣
+    /// ║1-0║1-1║
+    /// ╚═══╩═══╝
+    /// ```
+    pub const fn extended() -> Self {
+        Self {
+            top: symbol('═'),
+            top_left: symbol('╔'),
+            top_right: symbol('╗'),
+            top_intersection: symbol('╦'),
+
+            bottom: symbol('═'),
+            bottom_left: symbol('╚'),
+            bottom_right: symbol('╝'),
+            bottom_intersection: symbol('╩'),
+
+            horizontal: symbol('═'),
+            horizontal_left: symbol('╠'),
+            horizontal_right: symbol('╣'),
+
+            vertical_left: symbol('║'),
+            vertical_intersection: symbol('║'),
+            vertical_right: symbol('║'),
+
+            intersection: symbol('╬'),
+        }
+    }
+
+    /// Returns borders of a psql style.
+    ///
+    /// A header line is not a part of [Borders], it can be set by [Grid::set_split_line].
+    ///
+    /// ```text
+    /// 0-0|0-1
+    /// 1-0|1-1
+    /// ```
+    pub const fn psql() -> Self {
+        Self {
+            top: None,
+            top_left: None,
+            top_right: None,
+            top_intersection: None,
+
+            bottom: None,
+            bottom_left: None,
+            bottom_right: None,
+            bottom_intersection: None,
+
+            horizontal: None,
+            horizontal_left: None,
+            horizontal_right: None,
+
+            vertical_left: None,
+            vertical_intersection: symbol('|'),
+            vertical_right: None,
+
+            intersection: None,
+        }
+    }
+
+    /// Returns borders of a github markdown style.
+    ///
+    /// A header line is not a part of [Borders], it can be set by [Grid::set_split_line].
+    ///
+    /// ```text
+    /// |0-0|0-1|
+    /// |1-0|1-1|
+    /// ```
+    pub const fn github_markdown() -> Self {
+        Self {
+            top: None,
+            top_left: None,
+            top_right: None,
+            top_intersection: None,
+
+            bottom: None,
+            bottom_left: None,
+            bottom_right: None,
+            bottom_intersection: None,
+
+            horizontal: None,
+            horizontal_left: None,
+            horizontal_right: None,
+
+            vertical_left: symbol('|'),
+            vertical_intersection: symbol('|'),
+            vertical_right: symbol('|'),
+
+            intersection: None,
+        }
+    }
+
+    /// Serializes borders into a descriptor string like `top=-,top_left=+`.
+    ///
+    /// Only set symbols are listed, and they're always listed in the same order.
+    /// The `,` and `\` characters in a symbol are escaped by a `\`.
+    /// A [Symbol::empty] is listed with an empty value, like `top=`.
+    ///
+    /// ```rust
+    ///     use papergrid::Borders;
+    ///     let borders = Borders::ascii();
+    ///     assert_eq!(Borders::from_descriptor(&borders.to_descriptor()), Ok(borders));
+    /// ```
+    pub fn to_descriptor(&self) -> String {
+        write_descriptor(&[
+            ("top", &self.top),
+            ("top_left", &self.top_left),
+            ("top_right", &self.top_right),
+            ("top_intersection", &self.top_intersection),
+            ("bottom", &self.bottom),
+            ("bottom_left", &self.bottom_left),
+            ("bottom_right", &self.bottom_right),
+            ("bottom_intersection", &self.bottom_intersection),
+            ("horizontal", &self.horizontal),
+            ("horizontal_left", &self.horizontal_left),
+            ("horizontal_right", &self.horizontal_right),
+            ("vertical_left", &self.vertical_left),
+            ("vertical_intersection", &self.vertical_intersection),
+            ("vertical_right", &self.vertical_right),
+            ("intersection", &self.intersection),
+        ])
+    }
+
+    /// Parses borders from a descriptor created by [Borders::to_descriptor].
+    ///
+    /// Not listed symbols are left empty.
+    pub fn from_descriptor(descriptor: &str) -> Result<Self, DescriptorError> {
+        let mut borders = Self::default();
+        for (key, symbol) in parse_descriptor(descriptor)? {
+            let field = match key.as_str() {
+                "top" => &mut borders.top,
+                "top_left" => &mut borders.top_left,
+                "top_right" => &mut borders.top_right,
+                "top_intersection" => &mut borders.top_intersection,
+                "bottom" => &mut borders.bottom,
+                "bottom_left" => &mut borders.bottom_left,
+                "bottom_right" => &mut borders.bottom_right,
+                "bottom_intersection" => &mut borders.bottom_intersection,
+                "horizontal" => &mut borders.horizontal,
+                "horizontal_left" => &mut borders.horizontal_left,
+                "horizontal_right" => &mut borders.horizontal_right,
+                "vertical_left" => &mut borders.vertical_left,
+                "vertical_intersection" => &mut borders.vertical_intersection,
+                "vertical_right" => &mut borders.vertical_right,
+                "intersection" => &mut borders.intersection,
+                _ => return Err(DescriptorError::UnknownKey(key)),
+            };
+
+            *field = Some(symbol);
+        }
+
+        Ok(borders)
+    }
+}
+
+impl Line {
+    /// Serializes a line into a descriptor string like `horizontal=-,intersection=+`.
+    ///
+    /// See [Borders::to_descriptor].
+    pub fn to_descriptor(&self) -> String {
+        write_descriptor(&[
+            ("horizontal", &self.horizontal),
+            ("intersection", &self.intersection),
+            ("left", &self.left),
+            ("right", &self.right),
+        ])
+    }
+
+    /// Parses a line from a descriptor created by [Line::to_descriptor].
+    ///
+    /// Not listed symbols are left empty.
+    pub fn from_descriptor(descriptor: &str) -> Result<Self, DescriptorError> {
+        let mut line = Self::default();
+        for (key, symbol) in parse_descriptor(descriptor)? {
+            let field = match key.as_str() {
+                "horizontal" => &mut line.horizontal,
+                "intersection" => &mut line.intersection,
+                "left" => &mut line.left,
+                "right" => &mut line.right,
+                _ => return Err(DescriptorError::UnknownKey(key)),
+            };
+
+            *field = Some(symbol);
+        }
+
+        Ok(line)
+    }
+}
+
+fn write_descriptor(fields: &[(&str, &Option<Symbol>)]) -> String {
+    let mut buf = String::new();
+    for (key, symbol) in fields {
+        let symbol = match symbol {
+            Some(symbol) => symbol.to_string(),
+            None => continue,
+        };
+
+        if !buf.is_empty() {
+            buf.push(',');
+        }
+
+        buf.push_str(key);
+        buf.push('=');
+        for c in symbol.chars() {
+            if c == ',' || c == '\\' {
+                buf.push('\\');
+            }
+
+            buf.push(c);
+        }
+    }
+
+    buf
+}
+
+fn parse_descriptor(descriptor: &str) -> Result<Vec<(String, Symbol)>, DescriptorError> {
+    let mut fields = Vec::new();
+    let mut chars = descriptor.chars().peekable();
+    while chars.peek().is_some() {
+        let mut key = String::new();
+        loop {
+            match chars.next() {
+                Some('=') => break,
+                Some(c) => key.push(c),
+                None => return Err(DescriptorError::MissingValue(key)),
+            }
+        }
+
+        let mut value = String::new();
+        loop {
+            match chars.next() {
+                Some(',') | None => break,
+                Some('\\') => value.extend(chars.next()),
+                Some(c) => value.push(c),
+            }
+        }
+
+        // unlike in serde an empty value is accepted, as it's how an empty symbol is written
+        if value.is_empty() {
+            fields.push((key, Symbol::empty()));
+            continue;
+        }
+
+        match parse_symbol(value) {
+            Some(symbol) => fields.push((key, symbol)),
+            None => return Err(DescriptorError::InvalidSymbol(key)),
+        }
+    }
+
+    Ok(fields)
+}
+
+fn parse_symbol(s: String) -> Option<Symbol> {
+    #[cfg(feature = "color")]
+    {
+        Symbol::ansi(s)
+    }
+
+    #[cfg(not(feature = "color"))]
+    {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Some(Symbol::plain(c)),
+            _ => None,
+        }
+    }
+}
+
+const fn symbol(c: char) -> Option<Symbol> {
+    Some(Symbol::from_char(c))
+}
+
+fn non_empty_symbol(c: &Symbol) -> Option<&Symbol> {
+    if c.is_empty() {
+        None
+    } else {
+        Some(c)
+    }
+}
+
 #[derive(Debug, Clone)]
 struct BordersMap {
-    vertical: HashMap<Position, Symbol>,
-    horizontal: HashMap<Position, Symbol>,
-    intersection: HashMap<Position, Symbol>,
+    vertical: HashMap<Position, CellSymbol>,
+    horizontal: HashMap<Position, CellSymbol>,
+    intersection: HashMap<Position, CellSymbol>,
 }
 
-#[derive(Debug, Clone, Default)]
+impl BordersMap {
+    /// Moves the symbols set for the cells of row `a` to row `b` and vice versa.
+    fn swap_rows(&mut self, a: usize, b: usize) {
+        let swap = |(line, col): Position, (row_offset, _): Position| {
+            (swap_index(line - row_offset, a, b) + row_offset, col)
+        };
+
+        remap_cell_symbols(&mut self.vertical, swap);
+        remap_cell_symbols(&mut self.horizontal, swap);
+        remap_cell_symbols(&mut self.intersection, swap);
+    }
+
+    /// Moves the symbols set for the cells of column `a` to column `b` and vice versa.
+    fn swap_columns(&mut self, a: usize, b: usize) {
+        let swap = |(row, line): Position, (_, col_offset): Position| {
+            (row, swap_index(line - col_offset, a, b) + col_offset)
+        };
+
+        remap_cell_symbols(&mut self.vertical, swap);
+        remap_cell_symbols(&mut self.horizontal, swap);
+        remap_cell_symbols(&mut self.intersection, swap);
+    }
+}
+
+/// CellSymbol is a border symbol set for a particular cell.
+///
+/// A line is shared by 2 cells so the symbol keeps an offset from its position to the cell,
+/// which is `(0, 0)` for a top left corner and `(1, 1)` for a bottom right one.
+#[derive(Debug, Clone)]
+struct CellSymbol {
+    symbol: Symbol,
+    offset: Position,
+}
+
+impl CellSymbol {
+    fn new(symbol: Symbol, offset: Position) -> Self {
+        Self { symbol, offset }
+    }
+}
+
+fn remap_cell_symbols(
+    map: &mut HashMap<Position, CellSymbol>,
+    f: impl Fn(Position, Position) -> Position,
+) {
+    let symbols = std::mem::take(map);
+    for (pos, c) in symbols {
+        let pos = f(pos, c.offset);
+        // if 2 cells claim the same line the one which has it as a top or left side wins.
+        match map.get(&pos) {
+            Some(other) if other.offset < c.offset => {}
+            _ => {
+                map.insert(pos, c);
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Line {
     pub horizontal: Option<Symbol>,
     pub intersection: Option<Symbol>,
@@ -1527,65 +4402,227 @@ pub struct Line {
     pub right: Option<Symbol>,
 }
 
+/// Color is a pair of ANSI sequences which are put before and after a text.
+///
+/// It makes it possible to color a grid without any color crate.
+///
+/// # Example
+///
+/// ```rust
+///     use papergrid::{Color, Symbol};
+///     let red = Color::new("\u{1b}[31m", "\u{1b}[39m");
+///     let symbol = Symbol::colored('*', red.clone());
+///
+///     assert_eq!(red.colorize("Hello"), "\u{1b}[31mHello\u{1b}[39m");
+///     assert_eq!(symbol.to_string(), "\u{1b}[31m*\u{1b}[39m");
+/// ```
+#[cfg(feature = "color")]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Color {
+    prefix: String,
+    suffix: String,
+}
+
+#[cfg(feature = "color")]
+impl Color {
+    /// Creates a [Color] from a sequence which turns a color on and the one which turns it off.
+    pub fn new<P: Into<String>, S: Into<String>>(prefix: P, suffix: S) -> Self {
+        Self {
+            prefix: prefix.into(),
+            suffix: suffix.into(),
+        }
+    }
+
+    /// Returns a sequence which goes before a text.
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    /// Returns a sequence which goes after a text.
+    pub fn suffix(&self) -> &str {
+        &self.suffix
+    }
+
+    /// Wraps each line of a text by the color sequences.
+    pub fn colorize(&self, text: &str) -> String {
+        text.split('\n')
+            .map(|line| format!("{}{}{}", self.prefix, line, self.suffix))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
 /// A single character representation.
 ///
 /// It uses String to support ANSI colors.
-#[cfg(feature = "color")]
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct Symbol(InnerSymbol);
 
-#[cfg(feature = "color")]
 #[derive(Debug, Clone, Eq, PartialEq)]
 enum InnerSymbol {
-    Ansi(String),
+    /// A string with ANSI sequences and its display width.
+    #[cfg(feature = "color")]
+    Ansi(String, usize),
     Char(char),
+    /// An explicitly absent symbol, see [Symbol::empty].
+    Empty,
 }
 
-/// A single character representation.
-///
-/// It uses String to support ANSI colors.
-#[cfg(not(feature = "color"))]
-#[derive(Debug, Clone, Default, Eq, PartialEq)]
-pub struct Symbol(char);
+impl Symbol {
+    /// Creates a new [Symbol] from the String.
+    /// The string must contain 1 UTF-8 character and any list of Ansi sequences.
+    /// The character may be followed by combining marks, like `e` followed by a combining acute accent,
+    /// as long as the whole sequence is displayed as a single column.
+    ///
+    /// If it's displayed wider or narrower then 1 column `None` will be returned,
+    /// unless the string is a single [char].
+    ///
+    /// ```rust
+    ///     use papergrid::Symbol;
+    ///
+    ///     assert!(Symbol::ansi("e\u{301}".to_owned()).is_some());
+    ///     assert!(Symbol::ansi("\u{301}\u{301}".to_owned()).is_none());
+    ///     assert!(Symbol::ansi("ab".to_owned()).is_none());
+    /// ```
+    #[cfg(feature = "color")]
+    pub fn ansi(s: String) -> Option<Self> {
+        let mut chars = s.chars();
+        let c = chars.next();
+        let no_other_chars = chars.next().is_none();
+        drop(chars);
+        match c {
+            Some(c) if no_other_chars => return Some(Self(InnerSymbol::Char(c))),
+            _ => (),
+        }
+
+        let width = string_width(&s);
+        if width != 1 {
+            return None;
+        }
+
+        Some(Self(InnerSymbol::Ansi(s, width)))
+    }
+
+    /// Creates a [Symbol] from a [char] painted with a [Color].
+    #[cfg(feature = "color")]
+    pub fn colored(c: char, color: Color) -> Self {
+        if color.prefix.is_empty() && color.suffix.is_empty() {
+            return Self::from_char(c);
+        }
+
+        Self(InnerSymbol::Ansi(
+            format!("{}{}{}", color.prefix, c, color.suffix),
+            char_width(c),
+        ))
+    }
+
+    /// Creates a [Symbol] from a plain [char].
+    ///
+    /// It's available regardless of the `color` feature.
+    pub const fn plain(c: char) -> Self {
+        Self::from_char(c)
+    }
+
+    /// Creates a [Symbol] from a string which may contain ANSI sequences.
+    ///
+    /// Unlike [Symbol::ansi] it's available regardless of the `color` feature,
+    /// but returns an error when the feature is disabled.
+    pub fn try_colored(s: &str) -> Result<Self, UncoloredBuildError> {
+        #[cfg(feature = "color")]
+        {
+            Self::ansi(s.to_owned()).ok_or(UncoloredBuildError::NotSingleChar)
+        }
+
+        #[cfg(not(feature = "color"))]
+        {
+            let _ = s;
+            Err(UncoloredBuildError::ColorDisabled)
+        }
+    }
+
+    /// Verifies whether 2 symbols are rendered as the same character,
+    /// ignoring any ANSI sequences.
+    pub fn same_glyph(&self, other: &Self) -> bool {
+        self.glyph() == other.glyph()
+    }
+
+    /// Returns a text of the [Symbol] without ANSI sequences,
+    /// which is a character with its combining marks.
+    fn glyph(&self) -> Option<String> {
+        match &self.0 {
+            #[cfg(feature = "color")]
+            InnerSymbol::Ansi(s, _) => {
+                let b = strip_ansi_escapes::strip(s.as_bytes()).ok()?;
+                std::str::from_utf8(&b).ok().map(ToOwned::to_owned)
+            }
+            InnerSymbol::Char(c) => Some(c.to_string()),
+            InnerSymbol::Empty => Some(String::new()),
+        }
+    }
 
-impl Symbol {
-    /// Creates a new [Symbol] from the String.
-    /// The string must contain 1 UTF-8 character and any list of Ansi sequences.
+    /// Returns a display width of the [Symbol].
     ///
-    /// If it contains more then 1 character `None` will be returned.
-    #[cfg(feature = "color")]
-    pub fn ansi(s: String) -> Option<Self> {
-        let mut chars = s.chars();
-        let c = chars.next();
-        let no_other_chars = chars.next().is_none();
-        drop(chars);
-        match c {
-            Some(c) if no_other_chars => return Some(Self(InnerSymbol::Char(c))),
-            _ => (),
+    /// ```rust
+    ///     use papergrid::Symbol;
+    ///
+    ///     assert_eq!(Symbol::from('-').width(), 1);
+    ///     assert_eq!(Symbol::empty().width(), 0);
+    /// ```
+    pub fn width(&self) -> usize {
+        match &self.0 {
+            #[cfg(feature = "color")]
+            InnerSymbol::Ansi(_, width) => *width,
+            InnerSymbol::Char(c) => char_width(*c),
+            InnerSymbol::Empty => 0,
         }
+    }
 
-        if string_width(&s) != 1 {
-            return None;
+    /// Creates a [Symbol] which marks a border side as explicitly absent.
+    ///
+    /// See [Border::empty].
+    pub const fn empty() -> Self {
+        Self(InnerSymbol::Empty)
+    }
+
+    /// Verifies whether the [Symbol] was created by [Symbol::empty].
+    pub fn is_empty(&self) -> bool {
+        matches!(self.0, InnerSymbol::Empty)
+    }
+
+    /// Returns ANSI sequences which go before and after the character.
+    #[cfg(feature = "color")]
+    fn ansi_sequences(&self) -> (&str, &str) {
+        let s = match &self.0 {
+            InnerSymbol::Ansi(s, _) => s,
+            InnerSymbol::Char(_) | InnerSymbol::Empty => return ("", ""),
+        };
+
+        let mut prefix = 0;
+        for part in ansi_parts(s) {
+            match part {
+                AnsiPart::Escape(escape) => prefix += escape.len(),
+                AnsiPart::Text(text) => {
+                    // combining marks belong to the character
+                    let c_size = text.chars().next().map_or(0, char::len_utf8);
+                    let end = text[c_size..]
+                        .char_indices()
+                        .find(|&(_, c)| char_width(c) != 0)
+                        .map_or(text.len(), |(i, _)| c_size + i);
+
+                    return (&s[..prefix], &s[prefix + end..]);
+                }
+            }
         }
 
-        Some(Self(InnerSymbol::Ansi(s)))
+        ("", "")
     }
 
     /// A function which create a [Symbol] from [char].
     pub const fn from_char(c: char) -> Self {
-        #[cfg(feature = "color")]
-        {
-            Self(InnerSymbol::Char(c))
-        }
-
-        #[cfg(not(feature = "color"))]
-        {
-            Self(c)
-        }
+        Self(InnerSymbol::Char(c))
     }
 }
 
-#[cfg(feature = "color")]
 impl Default for Symbol {
     fn default() -> Self {
         Self(InnerSymbol::Char(char::default()))
@@ -1600,21 +4637,119 @@ impl From<char> for Symbol {
 
 impl fmt::Display for Symbol {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        #[cfg(feature = "color")]
-        {
-            match &self.0 {
-                InnerSymbol::Ansi(s) => f.write_str(s),
-                InnerSymbol::Char(c) => f.write_char(*c),
+        match &self.0 {
+            #[cfg(feature = "color")]
+            InnerSymbol::Ansi(s, _) => f.write_str(s),
+            InnerSymbol::Char(c) => f.write_char(*c),
+            InnerSymbol::Empty => Ok(()),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Symbol {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Symbol {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        parse_symbol(s)
+            .ok_or_else(|| serde::de::Error::custom("a symbol must contain exactly 1 character"))
+    }
+}
+
+/// An error returned by [Grid::validate], [Grid::try_set] and [Grid::try_set_text].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GridError {
+    /// An entity refers to a row or a column which is not present on the grid.
+    EntityOutOfBounds(Entity),
+    /// A span of a cell (row, col) goes beyond the grid.
+    SpanOutOfBounds { row: usize, col: usize },
+    /// A span of a cell (row, col) overlaps a span of a previous cell in the row.
+    OverlappingSpans { row: usize, col: usize },
+}
+
+impl fmt::Display for GridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GridError::EntityOutOfBounds(entity) => {
+                write!(f, "an entity {:?} is out of bounds", entity)
+            }
+            GridError::SpanOutOfBounds { row, col } => {
+                write!(f, "a span of a cell ({}, {}) is out of bounds", row, col)
+            }
+            GridError::OverlappingSpans { row, col } => {
+                write!(
+                    f,
+                    "a span of a cell ({}, {}) overlaps another span",
+                    row, col
+                )
             }
         }
+    }
+}
 
-        #[cfg(not(feature = "color"))]
-        {
-            f.write_char(self.0)
+impl std::error::Error for GridError {}
+
+/// An error returned by [Symbol::try_colored].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UncoloredBuildError {
+    /// The crate was built without the `color` feature.
+    ColorDisabled,
+    /// The string doesn't represent a single character.
+    NotSingleChar,
+}
+
+impl fmt::Display for UncoloredBuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UncoloredBuildError::ColorDisabled => {
+                f.write_str("a colored symbol requires the `color` feature")
+            }
+            UncoloredBuildError::NotSingleChar => {
+                f.write_str("a symbol must contain exactly 1 character")
+            }
+        }
+    }
+}
+
+impl std::error::Error for UncoloredBuildError {}
+
+/// An error returned by [Borders::from_descriptor] and [Line::from_descriptor].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DescriptorError {
+    /// A key is not a name of a symbol.
+    UnknownKey(String),
+    /// A key is not followed by `=`.
+    MissingValue(String),
+    /// A value of a key is neither a single character nor empty.
+    InvalidSymbol(String),
+}
+
+impl fmt::Display for DescriptorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DescriptorError::UnknownKey(key) => write!(f, "an unknown key {:?}", key),
+            DescriptorError::MissingValue(key) => write!(f, "a key {:?} has no value", key),
+            DescriptorError::InvalidSymbol(key) => {
+                write!(f, "a value of a key {:?} is not a single character", key)
+            }
         }
     }
 }
 
+impl std::error::Error for DescriptorError {}
+
 pub type Position = (usize, usize);
 
 impl Theme {
@@ -1627,51 +4762,69 @@ impl Theme {
                 intersection: HashMap::new(),
             },
             override_lines: HashMap::new(),
+            intersection_resolver: None,
         }
     }
 
     fn override_border(&mut self, pos: Position, border: Border) {
+        let (row, col) = pos;
+        let borders = &mut self.override_borders;
+
         if let Some(c) = border.top {
-            self.override_borders.horizontal.insert(pos, c);
+            borders.horizontal.insert(pos, CellSymbol::new(c, (0, 0)));
         }
 
         if let Some(c) = border.bottom {
-            self.override_borders
-                .horizontal
-                .insert((pos.0 + 1, pos.1), c);
+            let c = CellSymbol::new(c, (1, 0));
+            borders.horizontal.insert((row + 1, col), c);
         }
 
         if let Some(c) = border.left {
-            self.override_borders.vertical.insert(pos, c);
+            borders.vertical.insert(pos, CellSymbol::new(c, (0, 0)));
         }
 
         if let Some(c) = border.right {
-            self.override_borders.vertical.insert((pos.0, pos.1 + 1), c);
+            let c = CellSymbol::new(c, (0, 1));
+            borders.vertical.insert((row, col + 1), c);
         }
 
         if let Some(c) = border.left_top_corner {
-            self.override_borders.intersection.insert((pos.0, pos.1), c);
+            borders.intersection.insert(pos, CellSymbol::new(c, (0, 0)));
         }
 
         if let Some(c) = border.left_bottom_corner {
-            self.override_borders
-                .intersection
-                .insert((pos.0 + 1, pos.1), c);
+            let c = CellSymbol::new(c, (1, 0));
+            borders.intersection.insert((row + 1, col), c);
         }
 
         if let Some(c) = border.right_top_corner {
-            self.override_borders
-                .intersection
-                .insert((pos.0, pos.1 + 1), c);
+            let c = CellSymbol::new(c, (0, 1));
+            borders.intersection.insert((row, col + 1), c);
         }
 
         if let Some(c) = border.right_bottom_corner {
-            self.override_borders
-                .intersection
-                .insert((pos.0 + 1, pos.1 + 1), c);
+            let c = CellSymbol::new(c, (1, 1));
+            borders.intersection.insert((row + 1, col + 1), c);
         }
     }
 
+    fn remove_border_side(&mut self, pos: Position, side: Side) {
+        let (row, col) = pos;
+        match side {
+            Side::Top => self.override_borders.horizontal.remove(&pos),
+            Side::Bottom => self.override_borders.horizontal.remove(&(row + 1, col)),
+            Side::Left => self.override_borders.vertical.remove(&pos),
+            Side::Right => self.override_borders.vertical.remove(&(row, col + 1)),
+            Side::TopLeftCorner => self.override_borders.intersection.remove(&pos),
+            Side::TopRightCorner => self.override_borders.intersection.remove(&(row, col + 1)),
+            Side::BottomLeftCorner => self.override_borders.intersection.remove(&(row + 1, col)),
+            Side::BottomRightCorner => self
+                .override_borders
+                .intersection
+                .remove(&(row + 1, col + 1)),
+        };
+    }
+
     fn override_line(&mut self, row: usize, line: Line) {
         self.override_lines.insert(row, line);
     }
@@ -1679,124 +4832,30 @@ impl Theme {
     // we can take only a border of a cell
     // which is a pitty,
     // would be cool if we could take a border of any Entity
+    //
+    // The border is built from the same lookups which are used on rendering,
+    // so a corner shared by several cells is resolved the same way for each of them.
     fn get_border(&self, pos: Position, count_rows: usize, count_cols: usize) -> Border {
-        let is_first_row = pos.0 == 0;
-        let is_last_row = pos.0 + 1 == count_rows;
-        let is_first_col = pos.1 == 0;
-        let is_last_col = pos.1 + 1 == count_cols;
-
-        let top = if is_first_row {
-            &self.borders.top
-        } else {
-            &self.borders.horizontal
-        };
-
-        let bottom = if is_last_row {
-            &self.borders.bottom
-        } else {
-            &self.borders.horizontal
-        };
-
-        let left = if is_first_col {
-            &self.borders.vertical_left
-        } else {
-            &self.borders.vertical_intersection
-        };
-
-        let left_top_corner = if is_first_row && is_first_col {
-            &self.borders.top_left
-        } else if is_first_col {
-            &self.borders.horizontal_left
-        } else {
-            &self.borders.intersection
-        };
-
-        let left_bottom_corner = if is_last_row && is_first_col {
-            &self.borders.bottom_left
-        } else if is_first_col {
-            &self.borders.horizontal_left
-        } else {
-            &self.borders.intersection
-        };
-
-        let right = if is_last_col {
-            &self.borders.vertical_right
-        } else {
-            &self.borders.vertical_intersection
-        };
-
-        let right_top_corner = if is_first_row && is_last_col {
-            &self.borders.top_right
-        } else if is_last_col {
-            &self.borders.horizontal_right
-        } else {
-            &self.borders.intersection
-        };
-
-        let right_bottom_corner = if is_last_row && is_last_col {
-            &self.borders.bottom_right
-        } else if is_last_col {
-            &self.borders.horizontal_right
-        } else {
-            &self.borders.intersection
-        };
-
-        let mut border = Border {
-            top: top.clone(),
-            bottom: bottom.clone(),
-            left: left.clone(),
-            left_top_corner: left_top_corner.clone(),
-            left_bottom_corner: left_bottom_corner.clone(),
-            right: right.clone(),
-            right_top_corner: right_top_corner.clone(),
-            right_bottom_corner: right_bottom_corner.clone(),
-        };
-
-        if let Some(line) = self.override_lines.get(&pos.0) {
-            border.top = line.horizontal.clone().or(border.top);
-
-            if is_first_col {
-                border.left_top_corner = line.left.clone().or(border.left_top_corner);
-            } else {
-                border.left_top_corner = line.intersection.clone().or(border.left_top_corner);
-            }
-
-            if is_last_col {
-                border.right_top_corner = line.right.clone().or(border.right_top_corner);
-            } else {
-                border.right_top_corner = line.intersection.clone().or(border.right_top_corner);
-            }
-        }
-
-        if let Some(line) = self.override_lines.get(&(pos.0 + 1)) {
-            border.bottom = line.horizontal.clone().or(border.bottom);
-
-            if is_first_col {
-                border.left_bottom_corner = line.left.clone().or(border.left_bottom_corner);
-            } else {
-                border.left_bottom_corner = line.intersection.clone().or(border.left_bottom_corner);
-            }
-
-            if is_last_col {
-                border.right_bottom_corner = line.right.clone().or(border.right_bottom_corner);
-            } else {
-                border.right_bottom_corner =
-                    line.intersection.clone().or(border.right_bottom_corner);
-            }
+        let (row, col) = pos;
+
+        Border {
+            top: self.get_horizontal((row, col), count_rows).cloned(),
+            bottom: self.get_horizontal((row + 1, col), count_rows).cloned(),
+            left: self.get_vertical((row, col), count_cols).cloned(),
+            right: self.get_vertical((row, col + 1), count_cols).cloned(),
+            left_top_corner: self
+                .get_intersection((row, col), count_rows, count_cols)
+                .map(Cow::into_owned),
+            left_bottom_corner: self
+                .get_intersection((row + 1, col), count_rows, count_cols)
+                .map(Cow::into_owned),
+            right_top_corner: self
+                .get_intersection((row, col + 1), count_rows, count_cols)
+                .map(Cow::into_owned),
+            right_bottom_corner: self
+                .get_intersection((row + 1, col + 1), count_rows, count_cols)
+                .map(Cow::into_owned),
         }
-
-        if let Some(b) = self.get_override_border(&pos) {
-            border.top = b.top.or(border.top);
-            border.bottom = b.bottom.or(border.bottom);
-            border.left = b.left.or(border.left);
-            border.left_top_corner = b.left_top_corner.or(border.left_top_corner);
-            border.left_bottom_corner = b.left_bottom_corner.or(border.left_bottom_corner);
-            border.right = b.right.or(border.right);
-            border.right_top_corner = b.right_top_corner.or(border.right_top_corner);
-            border.right_bottom_corner = b.right_bottom_corner.or(border.right_bottom_corner);
-        }
-
-        border
     }
 
     fn get_vertical(&self, pos: Position, count_cols: usize) -> Option<&Symbol> {
@@ -1804,7 +4863,7 @@ impl Theme {
         let use_right = pos.1 == count_cols;
 
         if let Some(b) = self.override_borders.vertical.get(&pos) {
-            return Some(b);
+            return non_empty_symbol(&b.symbol);
         }
 
         if use_right {
@@ -1821,7 +4880,7 @@ impl Theme {
         let use_bottom = pos.0 == count_rows;
 
         if let Some(b) = self.override_borders.horizontal.get(&pos) {
-            return Some(b);
+            return non_empty_symbol(&b.symbol);
         }
 
         if let Some(line) = self.override_lines.get(&pos.0) {
@@ -1844,31 +4903,75 @@ impl Theme {
         pos: Position,
         count_rows: usize,
         count_cols: usize,
-    ) -> Option<&Symbol> {
-        let use_top = pos.0 == 0;
-        let use_bottom = pos.0 == count_rows;
-
+    ) -> Option<Cow<'_, Symbol>> {
         let use_left = pos.1 == 0;
         let use_right = pos.1 == count_cols;
 
         if let Some(b) = self.override_borders.intersection.get(&pos) {
-            return Some(b);
+            return non_empty_symbol(&b.symbol).map(Cow::Borrowed);
         }
 
         if let Some(line) = self.override_lines.get(&pos.0) {
             if use_left && line.left.is_some() {
-                return line.left.as_ref();
+                return line.left.as_ref().map(Cow::Borrowed);
             }
 
             if use_right && line.right.is_some() {
-                return line.right.as_ref();
+                return line.right.as_ref().map(Cow::Borrowed);
             }
 
             if !use_right && !use_left && line.intersection.is_some() {
-                return line.intersection.as_ref();
+                return line.intersection.as_ref().map(Cow::Borrowed);
             }
         }
 
+        if let Some(resolver) = &self.intersection_resolver {
+            let sides = self.get_border_sides(pos, count_rows, count_cols);
+            let is_empty = sides == BorderSides::default();
+            if !is_empty {
+                return Some(Cow::Owned((resolver.0)(sides)));
+            }
+        }
+
+        self.get_default_intersection(pos, count_rows, count_cols)
+            .map(Cow::Borrowed)
+    }
+
+    fn get_border_sides(&self, pos: Position, count_rows: usize, count_cols: usize) -> BorderSides {
+        let (row, col) = pos;
+
+        BorderSides {
+            up: (row > 0)
+                .then(|| self.get_vertical((row - 1, col), count_cols))
+                .flatten()
+                .cloned(),
+            down: (row < count_rows)
+                .then(|| self.get_vertical((row, col), count_cols))
+                .flatten()
+                .cloned(),
+            left: (col > 0)
+                .then(|| self.get_horizontal((row, col - 1), count_rows))
+                .flatten()
+                .cloned(),
+            right: (col < count_cols)
+                .then(|| self.get_horizontal((row, col), count_rows))
+                .flatten()
+                .cloned(),
+        }
+    }
+
+    fn get_default_intersection(
+        &self,
+        pos: Position,
+        count_rows: usize,
+        count_cols: usize,
+    ) -> Option<&Symbol> {
+        let use_top = pos.0 == 0;
+        let use_bottom = pos.0 == count_rows;
+
+        let use_left = pos.1 == 0;
+        let use_right = pos.1 == count_cols;
+
         if use_top && use_left {
             self.borders.top_left.as_ref()
         } else if use_top && use_right {
@@ -1889,99 +4992,227 @@ impl Theme {
             self.borders.intersection.as_ref()
         }
     }
+}
 
-    fn get_override_border(&self, pos: &Position) -> Option<Border> {
-        let top = self.override_borders.horizontal.get(pos);
-        let bottom = self.override_borders.horizontal.get(&(pos.0 + 1, pos.1));
-        let left = self.override_borders.vertical.get(pos);
-        let right = self.override_borders.vertical.get(&(pos.0, pos.1 + 1));
-        let left_top = self.override_borders.intersection.get(pos);
-        let left_bottom = self.override_borders.intersection.get(&(pos.0 + 1, pos.1));
-        let right_top = self.override_borders.intersection.get(&(pos.0, pos.1 + 1));
-        let right_bottom = self
-            .override_borders
-            .intersection
-            .get(&(pos.0 + 1, pos.1 + 1));
-
-        if top.is_some()
-            || bottom.is_some()
-            || left.is_some()
-            || right.is_some()
-            || left_top.is_some()
-            || left_bottom.is_some()
-            || right_top.is_some()
-            || right_bottom.is_some()
-        {
-            return Some(Border {
-                top: top.cloned(),
-                bottom: bottom.cloned(),
-                left: left.cloned(),
-                left_top_corner: left_top.cloned(),
-                left_bottom_corner: left_bottom.cloned(),
-                right: right.cloned(),
-                right_top_corner: right_top.cloned(),
-                right_bottom_corner: right_bottom.cloned(),
-            });
-        }
-
-        None
+fn print_grid(
+    f: &mut impl Write,
+    grid: &Grid,
+    widths: Vec<usize>,
+    heights: impl Iterator<Item = usize>,
+    on_row: impl FnMut(usize),
+) -> fmt::Result {
+    if grid.trim_trailing_whitespace {
+        let mut f = TrimTrailingWriter::new(f);
+        print_grid_lines(&mut f, grid, widths, heights, on_row)?;
+        f.finish()
+    } else {
+        print_grid_lines(f, grid, widths, heights, on_row)
     }
 }
 
-fn print_grid(
-    f: &mut fmt::Formatter,
+fn print_grid_lines(
+    f: &mut impl Write,
     grid: &Grid,
     widths: Vec<usize>,
     mut heights: impl Iterator<Item = usize>,
+    mut on_row: impl FnMut(usize),
 ) -> fmt::Result {
     let table_width = row_width_grid(grid, &widths);
+    let columns_max_line_width = columns_max_line_width(grid);
     print_margin_top(f, &grid.margin, table_width)?;
+    print_caption(f, grid, table_width, CaptionPosition::Above)?;
 
     for row in 0..grid.count_rows() {
-        print_split_line(f, grid, &widths, table_width, row)?;
-
         let height = heights.next().unwrap();
+        print_row(
+            f,
+            grid,
+            &widths,
+            columns_max_line_width.as_deref(),
+            table_width,
+            row,
+            height,
+        )?;
+
+        on_row(row);
+    }
 
-        for i in 0..height {
-            print_margin_left(f, &grid.margin)?;
+    print_caption(f, grid, table_width, CaptionPosition::Below)?;
+    print_margin_bottom(f, &grid.margin, table_width)?;
 
-            for col in 0..grid.count_columns() {
-                let border = grid.get_border(row, col);
+    Ok(())
+}
 
-                if is_cell_visible(grid, (row, col)) {
-                    if let Some(c) = border.left {
-                        c.fmt(f)?;
-                    }
+// prints a line of a cell content including its padding.
+fn print_cell_line(
+    f: &mut impl Write,
+    grid: &Grid,
+    widths: &[usize],
+    columns_max_line_width: Option<&[usize]>,
+    (row, col): Position,
+    line: usize,
+    height: usize,
+) -> fmt::Result {
+    let style = grid.style(Entity::Cell(row, col));
+    let width = grid_cell_width(grid, widths, (row, col));
+    let text = cut_height(&grid.cells[row][col], style);
+    let column_max_width = columns_max_line_width
+        .filter(|_| !is_spanned(grid, (row, col)))
+        .map_or(0, |widths| widths[col]);
+
+    #[cfg(feature = "color")]
+    let (prefix, suffix) = style.background.as_ref().map_or(("", ""), |background| {
+        (background.prefix(), background.suffix())
+    });
+
+    #[cfg(feature = "color")]
+    f.write_str(prefix)?;
+
+    build_line_cell(f, line, &text, style, width, height, column_max_width)?;
+
+    #[cfg(feature = "color")]
+    f.write_str(suffix)?;
 
-                    let style = grid.style(Entity::Cell(row, col));
-                    let width = grid_cell_width(grid, &widths, (row, col));
-                    let text = &grid.cells[row][col];
+    Ok(())
+}
+
+// prints a split line above the row, the row itself and the bottom split line if it's the last row.
+fn print_row(
+    f: &mut impl Write,
+    grid: &Grid,
+    widths: &[usize],
+    columns_max_line_width: Option<&[usize]>,
+    table_width: usize,
+    row: usize,
+    height: usize,
+) -> fmt::Result {
+    print_split_line(f, grid, widths, table_width, row)?;
 
-                    build_line_cell(f, i, text, style, width, height)?;
+    for i in 0..height {
+        print_margin_left(f, &grid.margin)?;
+
+        for col in 0..grid.count_columns() {
+            let border = grid.get_border(row, col);
+
+            // a cell covered by a span has no left border on any of the lines,
+            // even if one was set for it, as on split lines.
+            if is_cell_visible(grid, (row, col)) {
+                if let Some(c) = border.left {
+                    write!(f, "{}", c)?;
                 }
 
-                let is_last_column = col + 1 == grid.count_columns();
-                if is_last_column {
-                    if let Some(c) = border.right {
-                        c.fmt(f)?;
-                    }
+                print_cell_line(
+                    f,
+                    grid,
+                    widths,
+                    columns_max_line_width,
+                    (row, col),
+                    i,
+                    height,
+                )?;
+            }
+
+            let is_last_column = col + 1 == grid.count_columns();
+            if is_last_column {
+                if let Some(c) = border.right {
+                    write!(f, "{}", c)?;
                 }
             }
+        }
+
+        print_margin_right(f, &grid.margin)?;
+
+        f.write_char('\n')?;
+    }
+
+    let spacing = grid.row_spacing.get(&row).copied().unwrap_or(0);
+    for _ in 0..spacing {
+        print_blank_line(f, grid, widths, row)?;
+    }
+
+    let is_last_row = row + 1 == grid.count_rows();
+    if is_last_row {
+        print_split_line(f, grid, widths, table_width, row + 1)?;
+    }
+
+    Ok(())
+}
 
-            print_margin_right(f, &grid.margin)?;
+fn print_blank_line(f: &mut impl Write, grid: &Grid, widths: &[usize], row: usize) -> fmt::Result {
+    print_margin_left(f, &grid.margin)?;
+
+    for col in 0..grid.count_columns() {
+        let border = grid.get_border(row, col);
+
+        if is_cell_visible(grid, (row, col)) {
+            if let Some(c) = border.left {
+                write!(f, "{}", c)?;
+            }
 
-            f.write_char('\n')?;
+            let width = grid_cell_width(grid, widths, (row, col));
+            repeat_char(f, ' ', width)?;
         }
 
-        let is_last_row = row + 1 == grid.count_rows();
-        if is_last_row {
-            print_split_line(f, grid, &widths, table_width, row + 1)?;
+        let is_last_column = col + 1 == grid.count_columns();
+        if is_last_column {
+            if let Some(c) = border.right {
+                write!(f, "{}", c)?;
+            }
         }
     }
 
-    print_margin_bottom(f, &grid.margin, table_width)?;
+    print_margin_right(f, &grid.margin)?;
 
-    Ok(())
+    f.write_char('\n')
+}
+
+// it's computed only if there's a column alignment set.
+fn columns_max_line_width(grid: &Grid) -> Option<Vec<usize>> {
+    let has_column_alignment = grid
+        .styles
+        .values()
+        .any(|style| style.formatting.allow_column_alignment);
+    if !has_column_alignment {
+        return None;
+    }
+
+    let widths = (0..grid.count_columns())
+        .map(|col| {
+            (0..grid.count_rows())
+                .filter(|&row| is_cell_visible(grid, (row, col)) && !is_spanned(grid, (row, col)))
+                .map(|row| (row, grid.style(Entity::Cell(row, col))))
+                .filter(|(_, style)| style.formatting.allow_column_alignment)
+                .map(|(row, style)| {
+                    let text = cut_height(&grid.cells[row][col], style);
+                    cell_max_line_width(&text, style)
+                })
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    Some(widths)
+}
+
+fn cell_max_line_width(text: &str, style: &Style) -> usize {
+    text.lines()
+        .map(|line| {
+            let line = if style.formatting.horizontal_trim {
+                line.trim_end()
+            } else {
+                line
+            };
+
+            string_width_tab(line, style.formatting.tab_width)
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+fn is_spanned(grid: &Grid, (row, col): Position) -> bool {
+    grid.spans
+        .iter()
+        .any(|(&(start, end), rows)| start == col && end - start > 1 && rows.contains(&row))
 }
 
 fn grid_cell_width(grid: &Grid, widths: &[usize], pos: Position) -> usize {
@@ -1996,7 +5227,7 @@ fn grid_cell_width(grid: &Grid, widths: &[usize], pos: Position) -> usize {
     }
 }
 
-fn print_margin_top(f: &mut fmt::Formatter, margin: &Margin, table_width: usize) -> fmt::Result {
+fn print_margin_top(f: &mut impl Write, margin: &Margin, table_width: usize) -> fmt::Result {
     let size = table_width + margin.left.size + margin.right.size;
     for _ in 0..margin.top.size {
         repeat_char(f, margin.top.fill, size)?;
@@ -2006,7 +5237,7 @@ fn print_margin_top(f: &mut fmt::Formatter, margin: &Margin, table_width: usize)
     Ok(())
 }
 
-fn print_margin_bottom(f: &mut fmt::Formatter, margin: &Margin, table_width: usize) -> fmt::Result {
+fn print_margin_bottom(f: &mut impl Write, margin: &Margin, table_width: usize) -> fmt::Result {
     let size = table_width + margin.left.size + margin.right.size;
     for _ in 0..margin.bottom.size {
         repeat_char(f, margin.bottom.fill, size)?;
@@ -2016,16 +5247,49 @@ fn print_margin_bottom(f: &mut fmt::Formatter, margin: &Margin, table_width: usi
     Ok(())
 }
 
-fn print_margin_left(f: &mut fmt::Formatter, margin: &Margin) -> fmt::Result {
+fn print_caption(
+    f: &mut impl Write,
+    grid: &Grid,
+    table_width: usize,
+    position: CaptionPosition,
+) -> fmt::Result {
+    let caption = match grid.captions.get(&position) {
+        Some(caption) => caption,
+        None => return Ok(()),
+    };
+
+    for line in caption.text.lines() {
+        // a caption never makes the table wider.
+        let line = cut_str(line, table_width);
+        let diff = table_width.saturating_sub(string_width(&line));
+        let left = match caption.alignment {
+            AlignmentHorizontal::Left => 0,
+            AlignmentHorizontal::Right => diff,
+            AlignmentHorizontal::Center => diff / 2,
+            AlignmentHorizontal::CenterLeftBias => (diff + 1) / 2,
+        };
+
+        print_margin_left(f, &grid.margin)?;
+        repeat_char(f, ' ', left)?;
+        f.write_str(&line)?;
+        repeat_char(f, ' ', diff - left)?;
+        print_margin_right(f, &grid.margin)?;
+        f.write_char('\n')?;
+    }
+
+    Ok(())
+}
+
+fn print_margin_left(f: &mut impl Write, margin: &Margin) -> fmt::Result {
     repeat_char(f, margin.left.fill, margin.left.size)
 }
 
-fn print_margin_right(f: &mut fmt::Formatter, margin: &Margin) -> fmt::Result {
+fn print_margin_right(f: &mut impl Write, margin: &Margin) -> fmt::Result {
     repeat_char(f, margin.right.fill, margin.right.size)
 }
 
 fn print_split_line(
-    f: &mut fmt::Formatter,
+    f: &mut impl Write,
     grid: &Grid,
     widths: &[usize],
     max_width: usize,
@@ -2041,6 +5305,7 @@ fn print_split_line(
     let override_text = grid.override_split_lines.get(&row);
     if let Some(text) = override_text {
         if !text.is_empty() {
+            // `max_width` doesn't include a margin so the text never overlaps it.
             let text = cut_str(text, max_width);
             let line = text.lines().next().unwrap();
             char_skip = string_width(line);
@@ -2055,7 +5320,7 @@ fn print_split_line(
                 .get_intersection((row, col), grid.count_rows(), grid.count_columns())
                 .or_else(|| {
                     if has_vertical(grid, col) {
-                        Some(DEFAULT_BORDER_VERTICAL_SYMBOL_REF)
+                        Some(Cow::Borrowed(DEFAULT_BORDER_VERTICAL_SYMBOL_REF))
                     } else {
                         None
                     }
@@ -2063,7 +5328,7 @@ fn print_split_line(
 
             if let Some(c) = left {
                 if char_skip == 0 {
-                    c.fmt(f)?;
+                    write!(f, "{}", c)?;
                 } else {
                     char_skip -= 1;
                 }
@@ -2088,7 +5353,7 @@ fn print_split_line(
             .get_intersection((row, col + 1), grid.count_rows(), grid.count_columns())
             .or_else(|| {
                 if has_vertical(grid, col + 1) {
-                    Some(DEFAULT_BORDER_VERTICAL_SYMBOL_REF)
+                    Some(Cow::Borrowed(DEFAULT_BORDER_VERTICAL_SYMBOL_REF))
                 } else {
                     None
                 }
@@ -2096,7 +5361,14 @@ fn print_split_line(
 
         if let Some(c) = right {
             if char_skip == 0 {
-                c.fmt(f)?;
+                if is_split_line_inside_span(grid, row, col + 1) {
+                    match main {
+                        Some(c) => write!(f, "{}", c)?,
+                        None => f.write_char(DEFAULT_BORDER_HORIZONTAL_CHAR)?,
+                    }
+                } else {
+                    write!(f, "{}", c)?;
+                }
             } else {
                 char_skip -= 1;
             }
@@ -2142,260 +5414,433 @@ pub fn wrap_text(text: &str, width: usize, keep_words: bool) -> String {
     }
 }
 
-pub fn split_by_lines(s: &str, width: usize) -> String {
-    if width == 0 {
-        return String::new();
+/// Wraps a text like [wrap_text] does, but puts a `-` where a word is split.
+///
+/// The hyphen takes a place of the last character on the line.
+/// With `keep_words` only the words which are longer than `width` are split.
+///
+/// Nothing is hyphenated if `width` is less than 2.
+pub fn wrap_text_hyphenated(text: &str, width: usize, keep_words: bool) -> String {
+    if width < 2 {
+        return wrap_text(text, width, keep_words);
     }
 
-    chunks(s, width).join("\n")
-}
+    #[cfg(feature = "color")]
+    let stripped = ansi_str::AnsiStr::ansi_strip(text);
+    #[cfg(not(feature = "color"))]
+    let stripped = text;
+
+    let mut lines = Vec::new();
+    let mut start = 0;
+    for line in stripped.split('\n') {
+        let end = start + line.len();
+        if keep_words {
+            hyphenate_words(&stripped, start, end, width, &mut lines);
+        } else {
+            hyphenate_chars(&stripped, start, end, width, &mut lines);
+        }
 
-#[cfg(not(feature = "color"))]
-fn chunks(s: &str, width: usize) -> Vec<String> {
-    const REPLACEMENT: char = '\u{FFFD}';
+        start = end + 1;
+    }
 
-    let mut buf = String::with_capacity(width);
-    let mut list = Vec::new();
-    let mut i = 0;
-    for c in s.chars() {
-        let c_width = unicode_width::UnicodeWidthChar::width(c).unwrap_or(0);
-        if i + c_width > width {
-            let count_unknowns = width - i;
-            buf.extend(std::iter::repeat(REPLACEMENT).take(count_unknowns));
-            i += count_unknowns;
-        } else {
-            buf.push(c);
-            i += c_width;
+    let ranges = lines.iter().map(|&(start, end, _)| (start, end));
+    let mut buf = String::with_capacity(text.len());
+    for (i, (line, (_, _, hyphen))) in cut_lines(text, ranges).zip(&lines).enumerate() {
+        if i > 0 {
+            buf.push('\n');
         }
 
-        if i == width {
-            list.push(buf);
-            buf = String::with_capacity(width);
-            i = 0;
+        buf.push_str(&line);
+
+        if *hyphen {
+            buf.push('-');
         }
     }
 
-    if !buf.is_empty() {
-        list.push(buf);
+    buf
+}
+
+/// Wraps a text using line break opportunities defined by the Unicode Line Breaking Algorithm (UAX #14).
+///
+/// Unlike [wrap_text] with `keep_words` it can break a line not only on spaces,
+/// for example between CJK characters.
+/// A part of a text which is longer than `width` and has no break opportunities is split by characters.
+///
+/// # Example
+///
+/// ```
+/// use papergrid::wrap_text_unicode;
+///
+/// assert_eq!(wrap_text_unicode("Hello World", 8), "Hello\nWorld");
+/// assert_eq!(wrap_text_unicode("日本語です", 4), "日本\n語で\nす");
+/// ```
+#[cfg(feature = "linebreak")]
+pub fn wrap_text_unicode(text: &str, width: usize) -> String {
+    if width == 0 {
+        return String::new();
+    }
+
+    #[cfg(feature = "color")]
+    let stripped = ansi_str::AnsiStr::ansi_strip(text);
+    #[cfg(not(feature = "color"))]
+    let stripped = text;
+
+    let mut lines = Vec::new();
+    let mut start = 0;
+    for line in stripped.split('\n') {
+        let end = start + line.len();
+        split_by_line_breaks(&stripped, start, end, width, &mut lines);
+        start = end + 1;
     }
 
-    list
+    cut_lines(text, lines).collect::<Vec<_>>().join("\n")
 }
 
-#[cfg(feature = "color")]
-fn chunks(s: &str, width: usize) -> Vec<String> {
-    const REPLACEMENT: char = '\u{FFFD}';
-
-    let mut list = Vec::new();
-    let mut text = s.to_string();
-    while !text.is_empty() {
-        let stripped_text = ansi_str::AnsiStr::ansi_strip(&text);
-        let (length, count_unknowns, char_size) = cut_str_to_min_length(&stripped_text, width);
+/// Splits a line `s[base..end]` into lines of `width` width on line break opportunities.
+///
+/// Each line is represented as a byte range, trailing whitespaces are not included.
+#[cfg(feature = "linebreak")]
+fn split_by_line_breaks(
+    s: &str,
+    base: usize,
+    end: usize,
+    width: usize,
+    lines: &mut Vec<(usize, usize)>,
+) {
+    let str_width =
+        |start: usize, end: usize| unicode_width::UnicodeWidthStr::width(&s[start..end]);
+
+    let mut line_start = base;
+    let mut line_end = base;
+    let mut segment_start = base;
+    for (pos, _) in unicode_linebreak::linebreaks(&s[base..end]) {
+        let segment_end = base + pos;
+        let segment_end = segment_start + s[segment_start..segment_end].trim_end().len();
+
+        if str_width(line_start, segment_end) > width {
+            if line_end > line_start {
+                lines.push((line_start, line_end));
+                line_start = segment_start;
+            }
 
-        if length == 0 && count_unknowns == 0 {
-            break;
+            while str_width(line_start, segment_end) > width {
+                let pos = cut_to_width(s, line_start, segment_end, width)
+                    .max(next_grapheme_end(s, line_start));
+                lines.push((line_start, pos));
+                line_start = pos;
+            }
         }
 
-        if length != 0 && count_unknowns != 0 {
-            let (mut lhs, _) = ansi_str::AnsiStr::ansi_split_at(&text, length);
-            lhs.extend(std::iter::repeat(REPLACEMENT).take(count_unknowns));
+        line_end = segment_end;
+        segment_start = base + pos;
+    }
 
-            list.push(lhs);
+    lines.push((line_start, line_end.max(line_start)));
+}
 
-            let (_, rhs) = ansi_str::AnsiStr::ansi_split_at(&text, length + char_size);
-            text = rhs;
-        } else if length == 0 {
-            let mut s = String::with_capacity(count_unknowns);
-            s.extend(std::iter::repeat(REPLACEMENT).take(count_unknowns));
-            list.push(s);
+/// Splits a line `s[start..end]` into lines of `width` width.
+///
+/// Each line is represented as a byte range and a flag whether it must be ended by a hyphen.
+fn hyphenate_chars(
+    s: &str,
+    base: usize,
+    end: usize,
+    width: usize,
+    lines: &mut Vec<(usize, usize, bool)>,
+) {
+    let mut start = base;
+    let mut line_width = 0;
+    let mut prev = None;
+    for (i, grapheme) in graphemes(s, base, end) {
+        let grapheme_width = unicode_width::UnicodeWidthStr::width(grapheme);
+        if line_width + grapheme_width > width && i > start {
+            let mut split = i;
+            let mut hyphen = false;
+
+            let is_splitting_word =
+                !is_whitespace(grapheme) && prev.map_or(false, |g| !is_whitespace(g));
+            if is_splitting_word {
+                let pos = cut_to_width(s, start, i, width - 1);
+                let is_word_end = graphemes(s, start, pos)
+                    .last()
+                    .map_or(false, |(_, g)| !is_whitespace(g));
+                if is_word_end {
+                    split = pos;
+                    hyphen = true;
+                }
+            }
 
-            let (_, rhs) = ansi_str::AnsiStr::ansi_split_at(&text, char_size);
-            text = rhs;
-        } else {
-            let (lhs, rhs) = ansi_str::AnsiStr::ansi_split_at(&text, length);
-            list.push(lhs);
-            text = rhs;
+            lines.push((start, split, hyphen));
+            line_width = unicode_width::UnicodeWidthStr::width(&s[split..i]);
+            start = split;
         }
+
+        line_width += grapheme_width;
+        prev = Some(grapheme);
     }
 
-    list
+    lines.push((start, end, false));
 }
 
-#[cfg(not(feature = "color"))]
-fn split_by_line_keeping_words(s: &str, width: usize) -> String {
-    let mut buf = String::new();
-    let mut i = 0;
-    for c in s.chars() {
-        let c_width = unicode_width::UnicodeWidthChar::width(c).unwrap_or(0);
-        let is_splitting_pos = i + c_width > width;
-        if !is_splitting_pos {
-            i += c_width;
-            buf.push(c);
+/// Splits a line `s[start..end]` into lines of `width` width moving words which don't fit to the next line.
+///
+/// Only words longer than `width` are split.
+fn hyphenate_words(
+    s: &str,
+    base: usize,
+    end: usize,
+    width: usize,
+    lines: &mut Vec<(usize, usize, bool)>,
+) {
+    let mut start = base;
+    let mut line_width = 0;
+    let mut word_start = None;
+    for (i, grapheme) in graphemes(s, base, end).chain(std::iter::once((end, " "))) {
+        if !is_whitespace(grapheme) {
+            word_start.get_or_insert(i);
             continue;
         }
 
-        if c_width > 1 {
-            let count_unknowns = width - i;
-            const REPLACEMENT: char = '\u{FFFD}';
-            buf.extend(std::iter::repeat(REPLACEMENT).take(count_unknowns));
-            buf.push('\n');
-            i = 0;
-            continue;
+        if let Some(word_start) = word_start.take() {
+            let word_width = unicode_width::UnicodeWidthStr::width(&s[word_start..i]);
+            if line_width + word_width <= width {
+                line_width += word_width;
+            } else {
+                if word_start > start {
+                    lines.push((start, word_start, false));
+                    start = word_start;
+                }
+
+                while unicode_width::UnicodeWidthStr::width(&s[start..i]) > width {
+                    let pos = cut_to_width(s, start, i, width - 1);
+                    if pos > start {
+                        lines.push((start, pos, true));
+                        start = pos;
+                    } else {
+                        // a wide grapheme which doesn't leave a place for a hyphen
+                        let pos = cut_to_width(s, start, i, width).max(next_grapheme_end(s, start));
+                        lines.push((start, pos, false));
+                        start = pos;
+                    }
+                }
+
+                line_width = unicode_width::UnicodeWidthStr::width(&s[start..i]);
+            }
         }
 
-        if i == 0 && width == 1 {
-            buf.push(c);
-            buf.push('\n');
-            continue;
+        if i == end {
+            break;
         }
 
-        let is_prev_whitespace = buf.chars().last().map(char::is_whitespace).unwrap_or(false);
-        let is_splitting_word = !is_prev_whitespace && !c.is_whitespace();
-        if !is_splitting_word {
-            // This place doesn't separate a word
-            // So we just do a general split.
-            buf.push('\n');
-            buf.push(c);
-            i = 1;
-            continue;
+        let grapheme_width = unicode_width::UnicodeWidthStr::width(grapheme);
+        if line_width + grapheme_width > width && i > start {
+            lines.push((start, i, false));
+            start = i;
+            line_width = 0;
         }
 
-        let pos = buf.chars().rev().position(|c| c == ' ');
-        match pos {
-            Some(pos) if pos < width => {
-                // it's a part of a word which we is ok to move to the next line;
-                // we know that there will be enough space for this part + next character.
-                //
-                // todo: test about this next char space
-                let range_len = buf
-                    .chars()
-                    .rev()
-                    .take(pos)
-                    .map(|c| unicode_width::UnicodeWidthChar::width(c).unwrap_or(0))
-                    .sum::<usize>();
-
-                // put an spaces in order to not limit widths and keep it correct.
-                for i in 0..range_len {
-                    buf.insert(buf.len() - range_len - i, ' ');
-                }
+        line_width += grapheme_width;
+    }
 
-                buf.insert(buf.len() - range_len, '\n');
-                buf.push(c);
+    lines.push((start, end, false));
+}
 
-                i = range_len + 1;
-            }
-            Some(_) => {
-                // The words is too long to be moved,
-                // we can't move it any way so just leave everything as it is
-                buf.push('\n');
-                buf.push(c);
-                i = 1;
-            }
-            None => {
-                // We don't find a whitespace
-                // so its a long word so we can do nothing about it
-                buf.push('\n');
-                buf.push(c);
-                i = 1;
-            }
+/// Returns the largest grapheme boundary in `s[start..end]` such that `s[start..pos]` fits in `width`.
+fn cut_to_width(s: &str, start: usize, end: usize, width: usize) -> usize {
+    let mut line_width = 0;
+    for (i, grapheme) in graphemes(s, start, end) {
+        line_width += unicode_width::UnicodeWidthStr::width(grapheme);
+        if line_width > width {
+            return i;
         }
     }
 
-    buf.trim_end_matches('\n').to_owned()
+    end
 }
 
-#[cfg(feature = "color")]
-fn split_by_line_keeping_words(s: &str, width: usize) -> String {
-    use ansi_str::AnsiStr;
-    const REPLACEMENT: char = '\u{FFFD}';
+/// Returns grapheme clusters of `s[start..end]` with their byte positions in `s`.
+fn graphemes(s: &str, start: usize, end: usize) -> impl Iterator<Item = (usize, &str)> {
+    unicode_segmentation::UnicodeSegmentation::grapheme_indices(&s[start..end], true)
+        .map(move |(i, grapheme)| (start + i, grapheme))
+}
 
-    let mut buf = Vec::new();
-    let mut text = s.to_string();
-    while !text.is_empty() {
-        let stripped = ansi_str::AnsiStr::ansi_strip(&text);
-        let (byte_length, count_unknowns, split_char_size) =
-            cut_str_to_min_length(&stripped, width);
-        let (mut lhs, mut rhs) = if byte_length == 0 {
-            if split_char_size == 0 {
-                break;
+/// Returns a byte position right after the grapheme cluster which starts at `start`.
+fn next_grapheme_end(s: &str, start: usize) -> usize {
+    graphemes(s, start, s.len())
+        .next()
+        .map_or(start, |(i, grapheme)| i + grapheme.len())
+}
+
+fn is_whitespace(grapheme: &str) -> bool {
+    grapheme.chars().all(char::is_whitespace)
+}
+
+/// Cuts lines, given as byte ranges of a text stripped from ANSI sequences, out of the `text`.
+///
+/// The ranges must go in order and must not overlap.
+fn cut_lines<'a>(
+    text: &'a str,
+    lines: impl IntoIterator<Item = (usize, usize)> + 'a,
+) -> impl Iterator<Item = Cow<'a, str>> + 'a {
+    #[cfg(feature = "color")]
+    {
+        // the text is split one line after another to keep the colors of each part
+        let mut rest = text.to_owned();
+        let mut pos = 0;
+        lines.into_iter().map(move |(start, end)| {
+            if start > pos {
+                let (_, rhs) = ansi_str::AnsiStr::ansi_split_at(&rest, start - pos);
+                rest = rhs;
             }
 
-            let mut s = String::with_capacity(count_unknowns);
-            s.extend(std::iter::repeat(REPLACEMENT).take(count_unknowns));
+            let (lhs, rhs) = ansi_str::AnsiStr::ansi_split_at(&rest, end - start);
+            rest = rhs;
+            pos = end;
 
-            let (_, rhs) = ansi_str::AnsiStr::ansi_split_at(&text, split_char_size);
+            Cow::Owned(lhs)
+        })
+    }
 
-            (s, rhs)
-        } else {
-            let (mut lhs, rhs) = ansi_str::AnsiStr::ansi_split_at(&text, byte_length);
+    #[cfg(not(feature = "color"))]
+    {
+        lines
+            .into_iter()
+            .map(move |(start, end)| Cow::Borrowed(&text[start..end]))
+    }
+}
 
-            lhs.extend(std::iter::repeat(REPLACEMENT).take(count_unknowns));
+/// Splits a text into lines of `width` width by grapheme clusters,
+/// so a cluster, like an emoji ZWJ sequence, is never split between lines.
+///
+/// A cluster which is wider than `width` is put on its own line as it is.
+pub fn split_by_lines(s: &str, width: usize) -> String {
+    if width == 0 {
+        return String::new();
+    }
 
-            (lhs, rhs)
-        };
+    #[cfg(feature = "color")]
+    let stripped = ansi_str::AnsiStr::ansi_strip(s);
+    #[cfg(not(feature = "color"))]
+    let stripped = s;
+
+    let mut lines = Vec::new();
+    let mut start = 0;
+    for line in stripped.split('\n') {
+        let end = start + line.len();
+        split_by_graphemes(&stripped, start, end, width, &mut lines);
+        start = end + 1;
+    }
 
-        let lhs_stripped = lhs.ansi_strip();
-        let left_ends_with_letter = lhs_stripped.chars().last().map_or(false, |c| c != ' ');
-        let right_starts_with_letter = rhs.ansi_strip().chars().next().map_or(false, |c| c != ' ');
+    cut_lines(s, lines).collect::<Vec<_>>().join("\n")
+}
 
-        let is_splitting_word = left_ends_with_letter && right_starts_with_letter;
-        if !is_splitting_word {
-            buf.push(lhs);
-            text = rhs;
-            continue;
+/// Splits a line `s[base..end]` into lines of `width` width by grapheme clusters.
+///
+/// Each line is represented as a byte range.
+fn split_by_graphemes(
+    s: &str,
+    base: usize,
+    end: usize,
+    width: usize,
+    lines: &mut Vec<(usize, usize)>,
+) {
+    let mut start = base;
+    let mut line_width = 0;
+    for (i, grapheme) in graphemes(s, base, end) {
+        let grapheme_width = unicode_width::UnicodeWidthStr::width(grapheme);
+        if line_width + grapheme_width > width && i > start {
+            lines.push((start, i));
+            start = i;
+            line_width = 0;
         }
 
-        let pos = lhs_stripped.chars().rev().position(|c| c == ' ');
-        match pos {
-            Some(pos) => {
-                if pos < width {
-                    // it's a part of a word which we is ok to move to the next line;
-                    // we know that there will be enough space for this part + next character.
-                    //
-                    // todo: test about this next char space
-                    let range_len = lhs_stripped
-                        .chars()
-                        .rev()
-                        .take(pos)
-                        .map(|c| unicode_width::UnicodeWidthChar::width(c).unwrap_or(0))
-                        .sum::<usize>();
-
-                    let range_len_bytes = lhs_stripped
-                        .chars()
-                        .rev()
-                        .take(pos)
-                        .map(|c| c.len_utf8())
-                        .sum::<usize>();
-
-                    let move_part = lhs
-                        .ansi_get(lhs_stripped.len() - range_len_bytes..)
-                        .unwrap();
-                    lhs = lhs
-                        .ansi_get(..lhs_stripped.len() - range_len_bytes)
-                        .unwrap();
-                    rhs = move_part + &rhs;
-
-                    // put an spaces in order to not limit widths and keep it correct.
-                    lhs.extend(std::iter::repeat(' ').take(range_len));
-
-                    buf.push(lhs);
-                } else {
-                    // The words is too long to be moved,
-                    // we can't move it any way so just leave everything as it is
-                    buf.push(lhs);
+        line_width += grapheme_width;
+    }
+
+    lines.push((start, end));
+}
+
+/// Splits a text into lines of `width` width by grapheme clusters
+/// moving a part of a word which doesn't fit to the next line.
+///
+/// A line a part of a word was moved from is padded by spaces,
+/// so the words which are longer than `width` are split as they are.
+fn split_by_line_keeping_words(s: &str, width: usize) -> String {
+    #[cfg(feature = "color")]
+    let stripped = ansi_str::AnsiStr::ansi_strip(s);
+    #[cfg(not(feature = "color"))]
+    let stripped = s;
+
+    let mut lines = Vec::new();
+    let mut start = 0;
+    for line in stripped.split('\n') {
+        let end = start + line.len();
+        split_keeping_words(&stripped, start, end, width, &mut lines);
+        start = end + 1;
+    }
+
+    let ranges = lines.iter().map(|&(start, end, _)| (start, end));
+    let mut buf = String::with_capacity(s.len());
+    for (i, (line, (_, _, padding))) in cut_lines(s, ranges).zip(&lines).enumerate() {
+        if i > 0 {
+            buf.push('\n');
+        }
+
+        buf.push_str(&line);
+        buf.extend(std::iter::repeat(' ').take(*padding));
+    }
+
+    buf
+}
+
+/// Splits a line `s[base..end]` into lines of `width` width moving words which don't fit to the next line.
+///
+/// Each line is represented as a byte range and a width of the part of a word moved from it.
+fn split_keeping_words(
+    s: &str,
+    base: usize,
+    end: usize,
+    width: usize,
+    lines: &mut Vec<(usize, usize, usize)>,
+) {
+    let mut start = base;
+    let mut line_width = 0;
+    let mut prev = None;
+    for (i, grapheme) in graphemes(s, base, end) {
+        let grapheme_width = unicode_width::UnicodeWidthStr::width(grapheme);
+        if line_width + grapheme_width > width && i > start {
+            let is_splitting_word =
+                !is_whitespace(grapheme) && prev.map_or(false, |g| !is_whitespace(g));
+            let word_start = s[start..i].rfind(' ').map(|pos| start + pos + 1);
+
+            match word_start {
+                Some(word_start) if is_splitting_word => {
+                    let word_width = unicode_width::UnicodeWidthStr::width(&s[word_start..i]);
+                    if word_width + grapheme_width <= width {
+                        // it's a part of a word which is ok to move to the next line
+                        lines.push((start, word_start, word_width));
+                        start = word_start;
+                        line_width = word_width;
+                    } else {
+                        // the word is too long to be moved so it's split as it is
+                        lines.push((start, i, 0));
+                        start = i;
+                        line_width = 0;
+                    }
+                }
+                _ => {
+                    lines.push((start, i, 0));
+                    start = i;
+                    line_width = 0;
                 }
-            }
-            None => {
-                // We don't find a whitespace
-                // so its a long word so we can do nothing about it
-                buf.push(lhs);
             }
         }
 
-        text = rhs;
+        line_width += grapheme_width;
+        prev = Some(grapheme);
     }
 
-    buf.join("\n")
+    lines.push((start, end, 0));
 }
 
 fn count_tabs(s: &str) -> usize {
@@ -2424,6 +5869,30 @@ fn count_lines(s: &str) -> usize {
     count
 }
 
+/// Maps `a` to `b` and `b` to `a`, leaving other indexes as they are.
+fn swap_index(i: usize, a: usize, b: usize) -> usize {
+    if i == a {
+        b
+    } else if i == b {
+        a
+    } else {
+        i
+    }
+}
+
+fn swap_keys<V>(map: &mut HashMap<usize, V>, a: usize, b: usize) {
+    let value_a = map.remove(&a);
+    let value_b = map.remove(&b);
+
+    if let Some(value) = value_a {
+        map.insert(b, value);
+    }
+
+    if let Some(value) = value_b {
+        map.insert(a, value);
+    }
+}
+
 fn bounds_to_usize(left: Bound<&usize>, right: Bound<&usize>, length: usize) -> (usize, usize) {
     match (left, right) {
         (Bound::Included(x), Bound::Included(y)) => (*x, y + 1),
@@ -2442,6 +5911,36 @@ fn bounds_to_usize(left: Bound<&usize>, right: Bound<&usize>, length: usize) ->
 mod tests {
     use super::*;
 
+    #[test]
+    fn ansi_parts_test() {
+        use AnsiPart::*;
+
+        let parts = |s| ansi_parts(s).collect::<Vec<_>>();
+
+        assert_eq!(parts(""), []);
+        assert_eq!(parts("text"), [Text("text")]);
+        assert_eq!(
+            parts("a\u{1b}[1;31mb\u{1b}[0m"),
+            [
+                Text("a"),
+                Escape("\u{1b}[1;31m"),
+                Text("b"),
+                Escape("\u{1b}[0m")
+            ]
+        );
+        assert_eq!(
+            parts("\u{1b}]8;;url\u{7}link\u{1b}]8;;\u{1b}\\"),
+            [
+                Escape("\u{1b}]8;;url\u{7}"),
+                Text("link"),
+                Escape("\u{1b}]8;;\u{1b}\\")
+            ]
+        );
+        assert_eq!(parts("\u{1b}(Bx"), [Escape("\u{1b}("), Text("Bx")]);
+        assert_eq!(parts("x\u{1b}[31"), [Text("x"), Escape("\u{1b}[31")]);
+        assert_eq!(parts("x\u{1b}"), [Text("x"), Escape("\u{1b}")]);
+    }
+
     #[test]
     fn replace_tab_test() {
         assert_eq!(replace_tab("123\t\tabc\t", 3), "123      abc   ");
@@ -2455,6 +5954,13 @@ mod tests {
         assert_eq!(replace_tab("\\t", 4), "\\t");
         assert_eq!(replace_tab("123\\tabc", 0), "123\\tabc");
         assert_eq!(replace_tab("123\\tabc", 4), "123\\tabc");
+
+        assert_eq!(replace_tab("\\\t", 0), "\\\t");
+        assert_eq!(replace_tab("\\\t", 4), "\\\t");
+        assert_eq!(replace_tab("\\\\\t", 0), "\\\\");
+        assert_eq!(replace_tab("\\\\\t", 2), "\\\\  ");
+        assert_eq!(replace_tab("\\\\\t", 4), "\\\\    ");
+        assert_eq!(replace_tab("\\\\\\\t", 4), "\\\\\\\t");
     }
 
     #[test]
@@ -2466,6 +5972,32 @@ mod tests {
         assert_eq!(string_width_multiline("Go 👍\nC 😎"), 5);
     }
 
+    #[test]
+    fn string_width_single_line_test() {
+        assert_eq!(string_width_multiline("a\nbbb"), 3);
+        assert_eq!(string_width_single_line("a\nbbb"), 4);
+        assert_eq!(string_width_single_line("Go 👍"), 5);
+        assert_eq!(string_width_single_line("\ta\r"), 1);
+    }
+
+    #[cfg(feature = "color")]
+    #[test]
+    fn string_width_single_line_color_test() {
+        let text = "\u{1b}[31mGo\u{1b}[39m \u{1b}]8;;https://a.b\u{1b}\\👍\u{1b}]8;;\u{1b}\\";
+        assert_eq!(string_width_single_line(text), 5);
+        assert_eq!(string_width_single_line(text), string_width(text));
+        assert_eq!(string_width_single_line("\u{1b}[31ma\nbbb\u{1b}[39m"), 4);
+    }
+
+    #[test]
+    fn string_width_with_tabs_test() {
+        assert_eq!(string_width_multiline("\tHello"), 5);
+        assert_eq!(string_width_with_tabs("\tHello", 4), 9);
+        assert_eq!(string_width_with_tabs("\tHello", 2), 7);
+        assert_eq!(string_width_with_tabs("\tHello", 0), 5);
+        assert_eq!(string_width_with_tabs("\tHello\n\t\tWorld", 4), 13);
+    }
+
     #[test]
     fn horizontal_aligment_test() {
         use std::fmt;
@@ -2507,6 +6039,21 @@ mod tests {
         assert_eq!(AlignmentVertical::Center.top_ident(4, 1), 1);
     }
 
+    #[test]
+    fn justify_line_index_test() {
+        let lines = (0..7)
+            .map(|i| justify_line_index(i, 3, 7))
+            .collect::<Vec<_>>();
+        assert_eq!(lines, [Some(0), None, None, Some(1), None, None, Some(2)]);
+
+        let lines = (0..6)
+            .map(|i| justify_line_index(i, 3, 6))
+            .collect::<Vec<_>>();
+        assert_eq!(lines, [Some(0), None, None, Some(1), None, Some(2)]);
+
+        assert_eq!(justify_line_index(2, 1, 7), Some(2));
+    }
+
     #[cfg(feature = "color")]
     #[test]
     fn colored_string_width_test() {
@@ -2520,6 +6067,87 @@ mod tests {
         assert_eq!(string_width(&"0".red().to_string()), 1);
     }
 
+    #[test]
+    fn validate_test() {
+        let mut grid = Grid::new(2, 3);
+        grid.set(Entity::Cell(0, 0), Settings::new().span(2));
+        assert_eq!(grid.validate(), Ok(()));
+
+        grid.set(Entity::Cell(0, 1), Settings::new().span(2));
+        assert_eq!(
+            grid.validate(),
+            Err(GridError::OverlappingSpans { row: 0, col: 1 })
+        );
+    }
+
+    #[test]
+    fn validate_out_of_bounds_span_test() {
+        let mut grid = Grid::new(2, 3);
+        grid.set(Entity::Cell(1, 1), Settings::new().span(2));
+        assert_eq!(grid.validate(), Ok(()));
+
+        // spans are cut on setting so we put the broken one manually
+        grid.spans.insert((2, 4), vec![1].into_iter().collect());
+        assert_eq!(
+            grid.validate(),
+            Err(GridError::SpanOutOfBounds { row: 1, col: 2 })
+        );
+    }
+
+    #[test]
+    fn symbol_plain_test() {
+        assert_eq!(Symbol::plain('+'), Symbol::from_char('+'));
+        assert_eq!(Symbol::plain('+').to_string(), "+");
+    }
+
+    #[cfg(not(feature = "color"))]
+    #[test]
+    fn symbol_try_colored_without_color_test() {
+        assert_eq!(
+            Symbol::try_colored("+"),
+            Err(UncoloredBuildError::ColorDisabled)
+        );
+        assert_eq!(
+            Symbol::try_colored("\u{1b}[31m+\u{1b}[39m"),
+            Err(UncoloredBuildError::ColorDisabled)
+        );
+    }
+
+    #[cfg(feature = "color")]
+    #[test]
+    fn symbol_try_colored_test() {
+        use owo_colors::OwoColorize;
+
+        let colored = "+".red().to_string();
+        assert_eq!(Symbol::try_colored(&colored).unwrap().to_string(), colored);
+        assert_eq!(Symbol::try_colored("+"), Ok(Symbol::plain('+')));
+        assert_eq!(
+            Symbol::try_colored(&"++".red().to_string()),
+            Err(UncoloredBuildError::NotSingleChar)
+        );
+    }
+
+    #[test]
+    fn symbol_same_glyph_test() {
+        assert!(Symbol::plain('+').same_glyph(&Symbol::plain('+')));
+        assert!(!Symbol::plain('+').same_glyph(&Symbol::plain('-')));
+    }
+
+    #[cfg(feature = "color")]
+    #[test]
+    fn symbol_same_glyph_color_test() {
+        use owo_colors::OwoColorize;
+
+        let plain = Symbol::from_char('+');
+        let red = Symbol::ansi("+".red().to_string()).unwrap();
+        let red_minus = Symbol::ansi("-".red().to_string()).unwrap();
+
+        assert_ne!(plain, red);
+        assert!(plain.same_glyph(&red));
+        assert!(red.same_glyph(&plain));
+        assert!(!red.same_glyph(&red_minus));
+    }
+
     #[test]
     fn strip_test() {
         assert_eq!(cut_str("123456", 0), "");
@@ -2529,35 +6157,94 @@ mod tests {
         assert_eq!(cut_str("a week ago", 4), "a we");
 
         assert_eq!(cut_str("😳😳😳😳😳", 0), "");
-        assert_eq!(cut_str("😳😳😳😳😳", 3), "😳�");
+        assert_eq!(cut_str("😳😳😳😳😳", 3), "😳");
         assert_eq!(cut_str("😳😳😳😳😳", 4), "😳😳");
         assert_eq!(cut_str("😳😳😳😳😳", 20), "😳😳😳😳😳");
 
         assert_eq!(cut_str("🏳️🏳️", 0), "");
-        assert_eq!(cut_str("🏳️🏳️", 1), "🏳");
-        assert_eq!(cut_str("🏳️🏳️", 2), "🏳\u{fe0f}🏳");
+        assert_eq!(cut_str("🏳️🏳️", 1), "🏳\u{fe0f}");
+        assert_eq!(cut_str("🏳️🏳️", 2), "🏳\u{fe0f}🏳\u{fe0f}");
         assert_eq!(string_width("🏳️🏳️"), string_width("🏳\u{fe0f}🏳"));
 
-        assert_eq!(cut_str("🎓", 1), "�");
+        assert_eq!(cut_str("🎓", 1), "");
         assert_eq!(cut_str("🎓", 2), "🎓");
 
-        assert_eq!(cut_str("🥿", 1), "�");
+        assert_eq!(cut_str("🥿", 1), "");
         assert_eq!(cut_str("🥿", 2), "🥿");
 
-        assert_eq!(cut_str("🩰", 1), "�");
+        assert_eq!(cut_str("🩰", 1), "");
         assert_eq!(cut_str("🩰", 2), "🩰");
 
-        assert_eq!(cut_str("👍🏿", 1), "�");
-        assert_eq!(cut_str("👍🏿", 2), "👍");
-        assert_eq!(cut_str("👍🏿", 3), "👍�");
+        assert_eq!(cut_str("👍🏿", 1), "");
+        assert_eq!(cut_str("👍🏿", 2), "");
+        assert_eq!(cut_str("👍🏿", 3), "");
         assert_eq!(cut_str("👍🏿", 4), "👍🏿");
 
-        assert_eq!(cut_str("🇻🇬", 1), "🇻");
+        assert_eq!(cut_str("🇻🇬", 1), "");
         assert_eq!(cut_str("🇻🇬", 2), "🇻🇬");
         assert_eq!(cut_str("🇻🇬", 3), "🇻🇬");
         assert_eq!(cut_str("🇻🇬", 4), "🇻🇬");
     }
 
+    #[test]
+    fn strip_grapheme_clusters_test() {
+        // a family emoji is a ZWJ sequence of 3 emojies
+        let family = "👨\u{200d}👩\u{200d}👧";
+        assert_eq!(cut_str(family, 2), "");
+        assert_eq!(cut_str(family, 5), "");
+        assert_eq!(cut_str(family, 6), family);
+        assert_eq!(cut_str(&format!("{}{}", family, family), 11), family);
+
+        let bike = "🚴\u{200d}♂\u{fe0f}";
+        assert_eq!(cut_str(&format!("a{}", bike), 2), "a");
+
+        // combining accents
+        assert_eq!(cut_str("e\u{301}e\u{301}", 1), "e\u{301}");
+        assert_eq!(cut_str("ae\u{301}", 1), "a");
+        assert_eq!(cut_str("ae\u{301}", 2), "ae\u{301}");
+        assert_eq!(cut_str_range("ae\u{301}b", 1, 2), "e\u{301}");
+    }
+
+    #[test]
+    fn strip_range_test() {
+        assert_eq!(cut_str_range("123456", 0, 3), "123");
+        assert_eq!(cut_str_range("123456", 2, 4), "34");
+        assert_eq!(cut_str_range("123456", 4, 10), "56");
+        assert_eq!(cut_str_range("123456", 3, 3), "");
+        assert_eq!(cut_str_range("123456", 4, 2), "");
+        assert_eq!(cut_str_range("123456", 10, 20), "");
+
+        assert_eq!(cut_str_range("😳😳😳😳😳", 2, 6), "😳😳");
+        assert_eq!(cut_str_range("😳😳😳😳😳", 1, 6), "😳😳");
+        assert_eq!(cut_str_range("😳😳😳😳😳", 2, 5), "😳");
+        assert_eq!(cut_str_range("😳😳😳😳😳", 1, 2), "");
+    }
+
+    #[cfg(feature = "color")]
+    #[test]
+    fn strip_range_color_test() {
+        use owo_colors::OwoColorize;
+
+        let numbers = "123456".red().on_bright_black().to_string();
+
+        assert_eq!(
+            cut_str_range(&numbers, 2, 4),
+            "\u{1b}[31;100m34\u{1b}[39m\u{1b}[49m"
+        );
+        assert_eq!(cut_str_range(&numbers, 4, 10), "\u{1b}[31;100m56\u{1b}[0m");
+
+        let emojies = "😳😳😳😳😳".red().on_bright_black().to_string();
+
+        assert_eq!(
+            cut_str_range(&emojies, 1, 6),
+            "\u{1b}[31;100m😳😳\u{1b}[39m\u{1b}[49m"
+        );
+        assert_eq!(
+            cut_str_range(&emojies, 2, 5),
+            "\u{1b}[31;100m😳\u{1b}[39m\u{1b}[49m"
+        );
+    }
+
     #[cfg(feature = "color")]
     #[test]
     fn strip_color_test() {
@@ -2575,10 +6262,7 @@ mod tests {
         let emojies = "😳😳😳😳😳".red().on_bright_black().to_string();
 
         assert_eq!(cut_str(&emojies, 0), "\u{1b}[31;100m\u{1b}[39m\u{1b}[49m");
-        assert_eq!(
-            cut_str(&emojies, 3),
-            "\u{1b}[31;100m😳\u{1b}[39m\u{1b}[49m�"
-        );
+        assert_eq!(cut_str(&emojies, 3), "\u{1b}[31;100m😳\u{1b}[39m\u{1b}[49m");
         assert_eq!(
             cut_str(&emojies, 4),
             "\u{1b}[31;100m😳😳\u{1b}[39m\u{1b}[49m"
@@ -2588,10 +6272,13 @@ mod tests {
         let emojies = "🏳️🏳️".red().on_bright_black().to_string();
 
         assert_eq!(cut_str(&emojies, 0), "\u{1b}[31;100m\u{1b}[39m\u{1b}[49m");
-        assert_eq!(cut_str(&emojies, 1), "\u{1b}[31;100m🏳\u{1b}[39m\u{1b}[49m");
+        assert_eq!(
+            cut_str(&emojies, 1),
+            "\u{1b}[31;100m🏳\u{fe0f}\u{1b}[39m\u{1b}[49m"
+        );
         assert_eq!(
             cut_str(&emojies, 2),
-            "\u{1b}[31;100m🏳\u{fe0f}🏳\u{1b}[39m\u{1b}[49m"
+            "\u{1b}[31;100m🏳\u{fe0f}🏳\u{fe0f}\u{1b}[0m"
         );
         assert_eq!(
             string_width(&emojies),
@@ -2609,28 +6296,20 @@ mod tests {
         assert_eq!(split_by_lines("123456", 6), "123456");
         assert_eq!(split_by_lines("123456", 10), "123456");
 
-        assert_eq!(split_by_lines("😳😳😳😳😳", 1), "�\n�\n�\n�\n�");
+        assert_eq!(split_by_lines("😳😳😳😳😳", 1), "😳\n😳\n😳\n😳\n😳");
         assert_eq!(split_by_lines("😳😳😳😳😳", 2), "😳\n😳\n😳\n😳\n😳");
-        assert_eq!(split_by_lines("😳😳😳😳😳", 3), "😳�\n😳�\n😳");
+        assert_eq!(split_by_lines("😳😳😳😳😳", 3), "😳\n😳\n😳\n😳\n😳");
         assert_eq!(split_by_lines("😳😳😳😳😳", 6), "😳😳😳\n😳😳");
         assert_eq!(split_by_lines("😳😳😳😳😳", 20), "😳😳😳😳😳");
 
-        assert_eq!(split_by_lines("😳123😳", 1), "�\n1\n2\n3\n�");
-        assert_eq!(split_by_lines("😳12😳3", 1), "�\n1\n2\n�\n3");
-    }
-
-    #[cfg(feature = "color")]
-    #[test]
-    fn chunks_test() {
-        assert_eq!(chunks("123456", 0), [""; 0]);
-
-        assert_eq!(chunks("123456", 1), ["1", "2", "3", "4", "5", "6"]);
-        assert_eq!(chunks("123456", 2), ["12", "34", "56"]);
-        assert_eq!(chunks("12345", 2), ["12", "34", "5"]);
+        assert_eq!(split_by_lines("😳123😳", 1), "😳\n1\n2\n3\n😳");
+        assert_eq!(split_by_lines("😳12😳3", 1), "😳\n1\n2\n😳\n3");
 
-        assert_eq!(chunks("😳😳😳😳😳", 1), ["�", "�", "�", "�", "�"]);
-        assert_eq!(chunks("😳😳😳😳😳", 2), ["😳", "😳", "😳", "😳", "😳"]);
-        assert_eq!(chunks("😳😳😳😳😳", 3), ["😳�", "😳�", "😳"]);
+        assert_eq!(split_by_lines("12\n345", 2), "12\n34\n5");
+        assert_eq!(
+            split_by_lines("e\u{301}e\u{301}e\u{301}", 2),
+            "e\u{301}e\u{301}\ne\u{301}"
+        );
     }
 
     #[test]
@@ -2641,7 +6320,13 @@ mod tests {
 
         assert_eq!(
             split_by_line_keeping_words("😳😳😳😳😳", 1),
-            "�\n�\n�\n�\n�"
+            "😳\n😳\n😳\n😳\n😳"
+        );
+        assert_eq!(split_by_line_keeping_words("ab cd", 4), "ab  \ncd");
+        assert_eq!(split_by_line_keeping_words("ab\ncd ef", 3), "ab\ncd \nef");
+        assert_eq!(
+            split_by_line_keeping_words("ab e\u{301}e\u{301}", 3),
+            "ab \ne\u{301}e\u{301}"
         );
     }
 
@@ -2649,7 +6334,7 @@ mod tests {
     #[test]
     fn split_by_line_keeping_words_color_test() {
         let text = "\u{1b}[37mJapanese “vacancy” button\u{1b}[0m";
-        assert_eq!(split_by_line_keeping_words(text, 2), "\u{1b}[37mJa\u{1b}[39m\n\u{1b}[37mpa\u{1b}[39m\n\u{1b}[37mne\u{1b}[39m\n\u{1b}[37mse\u{1b}[39m\n\u{1b}[37m \u{1b}[39m \n\u{1b}[37m“\u{1b}[39m\u{1b}[37mv\u{1b}[39m\n\u{1b}[37mac\u{1b}[39m\n\u{1b}[37man\u{1b}[39m\n\u{1b}[37mcy\u{1b}[39m\n\u{1b}[37m” \u{1b}[39m\n\u{1b}[37mbu\u{1b}[39m\n\u{1b}[37mtt\u{1b}[39m\n\u{1b}[37mon\u{1b}[39m");
+        assert_eq!(split_by_line_keeping_words(text, 2), "\u{1b}[37mJa\u{1b}[39m\n\u{1b}[37mpa\u{1b}[39m\n\u{1b}[37mne\u{1b}[39m\n\u{1b}[37mse\u{1b}[39m\n\u{1b}[37m \u{1b}[39m \n\u{1b}[37m“v\u{1b}[39m\n\u{1b}[37mac\u{1b}[39m\n\u{1b}[37man\u{1b}[39m\n\u{1b}[37mcy\u{1b}[39m\n\u{1b}[37m” \u{1b}[39m\n\u{1b}[37mbu\u{1b}[39m\n\u{1b}[37mtt\u{1b}[39m\n\u{1b}[37mon\u{1b}[39m");
         assert_eq!(split_by_line_keeping_words(text, 1), "\u{1b}[37mJ\u{1b}[39m\n\u{1b}[37ma\u{1b}[39m\n\u{1b}[37mp\u{1b}[39m\n\u{1b}[37ma\u{1b}[39m\n\u{1b}[37mn\u{1b}[39m\n\u{1b}[37me\u{1b}[39m\n\u{1b}[37ms\u{1b}[39m\n\u{1b}[37me\u{1b}[39m\n\u{1b}[37m \u{1b}[39m\n\u{1b}[37m“\u{1b}[39m\n\u{1b}[37mv\u{1b}[39m\n\u{1b}[37ma\u{1b}[39m\n\u{1b}[37mc\u{1b}[39m\n\u{1b}[37ma\u{1b}[39m\n\u{1b}[37mn\u{1b}[39m\n\u{1b}[37mc\u{1b}[39m\n\u{1b}[37my\u{1b}[39m\n\u{1b}[37m”\u{1b}[39m\n\u{1b}[37m \u{1b}[39m\n\u{1b}[37mb\u{1b}[39m\n\u{1b}[37mu\u{1b}[39m\n\u{1b}[37mt\u{1b}[39m\n\u{1b}[37mt\u{1b}[39m\n\u{1b}[37mo\u{1b}[39m\n\u{1b}[37mn\u{1b}[39m");
     }
 }
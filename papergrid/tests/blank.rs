@@ -0,0 +1,42 @@
+use papergrid::{Entity, Grid, Settings};
+
+mod util;
+
+#[test]
+fn is_blank_on_empty_grid() {
+    assert!(Grid::new(0, 0).is_blank());
+    assert!(Grid::new(0, 3).is_blank());
+    assert!(Grid::new(3, 0).is_blank());
+}
+
+#[test]
+fn is_blank_on_grid_with_empty_cells() {
+    let mut grid = Grid::new(2, 2);
+    grid.set(Entity::Global, Settings::new().padding_uniform(1));
+
+    assert!(grid.is_blank());
+}
+
+#[test]
+fn is_blank_on_grid_with_one_non_empty_cell() {
+    let mut grid = Grid::new(2, 2);
+    grid.set(Entity::Cell(1, 0), Settings::new().text("1-0"));
+
+    assert!(!grid.is_blank());
+}
+
+#[test]
+fn is_blank_on_filled_grid() {
+    let grid = util::new_grid::<2, 2>();
+
+    assert!(!grid.is_blank());
+}
+
+#[test]
+fn is_blank_ignores_cells_hidden_by_span() {
+    let mut grid = Grid::new(2, 2);
+    grid.set(Entity::Cell(0, 1), Settings::new().text("0-1"));
+    grid.set(Entity::Cell(0, 0), Settings::new().span(2));
+
+    assert!(grid.is_blank());
+}
@@ -0,0 +1,128 @@
+use papergrid::{Entity, Grid, Settings, WIDE_CHAR_PLACEHOLDER};
+
+mod util;
+
+#[test]
+fn char_matrix_test() {
+    let mut grid = util::new_grid::<2, 2>();
+    grid.set(Entity::Cell(0, 1), Settings::new().text("a\nlong\ntext"));
+
+    let matrix = grid.to_char_matrix();
+
+    assert_eq!(matrix.len(), 7);
+    assert!(matrix.iter().all(|line| line.len() == 10));
+
+    let text = matrix
+        .iter()
+        .map(|line| line.iter().collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    assert_eq!(text, grid.to_string_trimmed());
+}
+
+#[test]
+fn char_matrix_pads_lines_test() {
+    let mut grid = Grid::new(1, 1);
+    grid.set(Entity::Cell(0, 0), Settings::new().text("😳a"));
+
+    assert_eq!(
+        grid.to_char_matrix(),
+        vec![
+            vec!['+', '-', '-', '-', '+'],
+            vec!['|', '😳', WIDE_CHAR_PLACEHOLDER, 'a', '|'],
+            vec!['+', '-', '-', '-', '+'],
+        ]
+    );
+}
+
+#[test]
+fn char_matrix_matches_display_columns_test() {
+    let mut grid = util::new_grid::<2, 2>();
+    grid.set(Entity::Cell(0, 0), Settings::new().text("日本"));
+    grid.set(Entity::Cell(1, 1), Settings::new().text("e\u{301}\ntext"));
+
+    let matrix = grid.to_char_matrix();
+
+    let lines = grid.to_string_trimmed();
+    let lines = lines.lines().collect::<Vec<_>>();
+    assert_eq!(matrix.len(), lines.len());
+    for (row, line) in matrix.iter().zip(lines) {
+        assert_eq!(row.len(), papergrid::string_width(line));
+    }
+
+    assert_eq!(
+        matrix[1],
+        vec![
+            '|',
+            '日',
+            WIDE_CHAR_PLACEHOLDER,
+            '本',
+            WIDE_CHAR_PLACEHOLDER,
+            '|',
+            '0',
+            '-',
+            '1',
+            ' ',
+            '|'
+        ]
+    );
+    assert_eq!(
+        matrix[3],
+        vec!['|', '1', '-', '0', ' ', '|', 'e', ' ', ' ', ' ', '|']
+    );
+}
+
+#[test]
+fn char_matrix_empty_test() {
+    let grid = Grid::new(0, 0);
+    assert!(grid.to_char_matrix().is_empty());
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn styled_char_matrix_test() {
+    use owo_colors::OwoColorize;
+    use papergrid::StyledChar;
+
+    let mut grid = Grid::new(1, 2);
+    grid.set(
+        Entity::Cell(0, 0),
+        Settings::new().text("ab".red().to_string()),
+    );
+    grid.set(Entity::Cell(0, 1), Settings::new().text("c"));
+
+    let matrix = grid.to_styled_char_matrix();
+
+    assert_eq!(grid.to_char_matrix()[1], vec!['|', 'a', 'b', '|', 'c', '|']);
+    assert_eq!(
+        matrix[1][1],
+        StyledChar {
+            c: 'a',
+            style: String::from("\u{1b}[31m")
+        }
+    );
+    assert_eq!(matrix[1][2].to_string(), "\u{1b}[31mb\u{1b}[0m");
+    assert_eq!(matrix[1][4].to_string(), "c");
+    assert_eq!(matrix[0][0].to_string(), "+");
+
+    grid.set(
+        Entity::Cell(0, 1),
+        Settings::new().text("c".red().on_bright_black().to_string()),
+    );
+
+    let matrix = grid.to_styled_char_matrix();
+    assert_eq!(matrix[1][4].style, "\u{1b}[31m\u{1b}[100m");
+    assert_eq!(matrix[1][5].style, "");
+
+    grid.set(
+        Entity::Cell(0, 1),
+        Settings::new().text("日".red().to_string()),
+    );
+
+    let matrix = grid.to_styled_char_matrix();
+    assert_eq!(matrix[1][4].to_string(), "\u{1b}[31m日\u{1b}[0m");
+    assert_eq!(matrix[1][5].c, WIDE_CHAR_PLACEHOLDER);
+    assert_eq!(matrix[1][5].style, "\u{1b}[31m");
+    assert_eq!(matrix[1][6].to_string(), "|");
+}
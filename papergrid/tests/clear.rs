@@ -0,0 +1,74 @@
+use papergrid::{AlignmentHorizontal, Entity, Indent, Margin, Settings};
+
+mod util;
+
+#[test]
+fn clear_contents_test() {
+    let mut grid = util::new_grid::<2, 2>();
+    grid.set(
+        Entity::Global,
+        Settings::new().padding(
+            Indent::spaced(1),
+            Indent::spaced(1),
+            Indent::default(),
+            Indent::default(),
+        ),
+    );
+    grid.margin(Margin {
+        left: Indent::new(1, '>'),
+        ..Default::default()
+    });
+
+    grid.clear_contents();
+
+    assert_eq!(grid.count_rows(), 2);
+    assert_eq!(grid.count_columns(), 2);
+    assert_eq!(
+        grid.to_string(),
+        ">+--+--+\n\
+         >|  |  |\n\
+         >+--+--+\n\
+         >|  |  |\n\
+         >+--+--+\n"
+    );
+}
+
+#[test]
+fn clear_all_test() {
+    let mut grid = util::new_grid::<2, 2>();
+    grid.set(
+        Entity::Global,
+        Settings::new()
+            .padding(
+                Indent::spaced(1),
+                Indent::spaced(1),
+                Indent::default(),
+                Indent::default(),
+            )
+            .alignment(AlignmentHorizontal::Right),
+    );
+    grid.set(Entity::Cell(0, 0), Settings::new().span(2));
+
+    grid.clear_all();
+
+    assert_eq!(grid.count_rows(), 2);
+    assert_eq!(grid.count_columns(), 2);
+    assert_eq!(
+        grid.to_string(),
+        "+++\n\
+         |||\n\
+         +++\n\
+         |||\n\
+         +++\n"
+    );
+
+    grid.set(Entity::Cell(0, 0), Settings::new().text("a"));
+    assert_eq!(
+        grid.to_string(),
+        "+-++\n\
+         |a||\n\
+         +-++\n\
+         | ||\n\
+         +-++\n"
+    );
+}
@@ -0,0 +1,128 @@
+use papergrid::{Borders, Entity, Indent, Margin, Settings, WidthBreakdown};
+
+mod util;
+
+#[test]
+fn column_width_narrower_than_content() {
+    let mut grid = util::new_grid::<2, 2>();
+    grid.set_column_width(0, 2);
+
+    assert_eq!(
+        grid.to_string(),
+        "+--+---+\n\
+         |0-|0-1|\n\
+         +--+---+\n\
+         |1-|1-1|\n\
+         +--+---+\n"
+    )
+}
+
+#[test]
+fn column_width_wider_than_content() {
+    let mut grid = util::new_grid::<2, 2>();
+    grid.set_column_width(1, 6);
+
+    assert_eq!(
+        grid.to_string(),
+        "+---+------+\n\
+         |0-0|0-1   |\n\
+         +---+------+\n\
+         |1-0|1-1   |\n\
+         +---+------+\n"
+    )
+}
+
+#[test]
+fn column_width_is_kept_by_span() {
+    let mut grid = util::new_grid::<2, 2>();
+    grid.set(
+        Entity::Cell(0, 0),
+        Settings::new().text("long spanned text").span(2),
+    );
+    grid.set_column_width(0, 3);
+
+    assert_eq!(
+        grid.to_string(),
+        "+-----------------+\n\
+         |long spanned text|\n\
+         +---+-------------+\n\
+         |1-0|1-1          |\n\
+         +---+-------------+\n"
+    )
+}
+
+#[test]
+fn natural_column_widths_ignore_set_width() {
+    let mut grid = util::new_grid::<2, 2>();
+    grid.set(
+        Entity::Cell(0, 0),
+        Settings::new().text("long spanned text").span(2),
+    );
+    grid.set_column_width(0, 6);
+
+    assert_eq!(grid.build_widths(), vec![6, 10]);
+    assert_eq!(grid.natural_column_widths(), vec![8, 8]);
+}
+
+#[test]
+fn width_breakdown_test() {
+    let mut grid = util::new_grid::<2, 3>();
+    grid.set(Entity::Cell(0, 0), Settings::new().text("a long cell"));
+
+    let cases = [
+        (
+            Borders::ascii(),
+            WidthBreakdown {
+                content: 17,
+                borders: 4,
+                margin: 0,
+            },
+        ),
+        (
+            Borders::psql(),
+            WidthBreakdown {
+                content: 17,
+                borders: 2,
+                margin: 0,
+            },
+        ),
+        (
+            Borders::default(),
+            WidthBreakdown {
+                content: 17,
+                borders: 0,
+                margin: 0,
+            },
+        ),
+    ];
+
+    for (borders, expected) in cases {
+        grid.clear_theme();
+        grid.set_borders(borders);
+
+        assert_eq!(grid.width_breakdown(), expected);
+        assert_eq!(grid.width_breakdown().total(), grid.total_width());
+    }
+
+    grid.margin(Margin {
+        left: Indent::new(2, ' '),
+        right: Indent::new(1, ' '),
+        top: Indent::default(),
+        bottom: Indent::default(),
+    });
+    grid.set(Entity::Cell(1, 1), Settings::new().span(2));
+
+    assert_eq!(
+        grid.width_breakdown(),
+        WidthBreakdown {
+            content: 17,
+            borders: 0,
+            margin: 3,
+        }
+    );
+    assert_eq!(grid.width_breakdown().total(), grid.total_width());
+    assert_eq!(
+        grid.to_string().lines().map(|l| l.chars().count()).max(),
+        Some(grid.total_width())
+    );
+}
@@ -0,0 +1,158 @@
+use papergrid::{AlignmentHorizontal, Border, Entity, Settings};
+
+mod util;
+
+#[test]
+fn insert_column_front_test() {
+    let mut grid = util::new_grid::<2, 2>();
+    grid.set(
+        Entity::Column(0),
+        Settings::new().alignment(AlignmentHorizontal::Right),
+    );
+    grid.set(Entity::Cell(1, 0), Settings::new().text("a long cell"));
+
+    grid.insert_column(0);
+
+    assert_eq!(grid.count_columns(), 3);
+    assert_eq!(
+        grid.to_string(),
+        "++-----------+---+\n\
+         ||        0-0|0-1|\n\
+         ++-----------+---+\n\
+         ||a long cell|1-1|\n\
+         ++-----------+---+\n"
+    );
+
+    grid.set(Entity::Column(0), Settings::new().text("new"));
+
+    assert_eq!(
+        grid.to_string(),
+        "+---+-----------+---+\n\
+         |new|        0-0|0-1|\n\
+         +---+-----------+---+\n\
+         |new|a long cell|1-1|\n\
+         +---+-----------+---+\n"
+    );
+}
+
+#[test]
+fn insert_column_middle_test() {
+    let mut grid = util::new_grid::<2, 3>();
+    grid.set(Entity::Cell(0, 0), Settings::new().span(2));
+    grid.set(
+        Entity::Cell(1, 2),
+        Settings::new().border(Border::default().left('*').top('#')),
+    );
+
+    grid.insert_column(1);
+    grid.set(Entity::Column(1), Settings::new().text("new"));
+
+    assert_eq!(
+        grid.to_string(),
+        "+-----------+---+\n\
+         |0-0        |0-2|\n\
+         +---+---+---+###+\n\
+         |1-0|new|1-1*1-2|\n\
+         +---+---+---+---+\n"
+    );
+}
+
+#[test]
+fn insert_column_end_test() {
+    let mut grid = util::new_grid::<2, 2>();
+    grid.set(
+        Entity::Cell(0, 1),
+        Settings::new().border(Border::default().right('*')),
+    );
+
+    grid.insert_column(2);
+    grid.set(Entity::Column(2), Settings::new().text("new"));
+
+    assert_eq!(
+        grid.to_string(),
+        "+---+---+---+\n\
+         |0-0|0-1|new*\n\
+         +---+---+---+\n\
+         |1-0|1-1|new|\n\
+         +---+---+---+\n"
+    );
+}
+
+#[test]
+fn remove_column_test() {
+    let mut grid = util::new_grid::<2, 3>();
+    grid.set(
+        Entity::Column(2),
+        Settings::new().alignment(AlignmentHorizontal::Right),
+    );
+    grid.set(Entity::Cell(0, 2), Settings::new().text("a long cell"));
+    grid.set(Entity::Cell(1, 0), Settings::new().span(2));
+
+    grid.remove_column(1);
+
+    assert_eq!(grid.count_columns(), 2);
+    assert_eq!(
+        grid.to_string(),
+        "+---+-----------+\n\
+         |0-0|a long cell|\n\
+         +---+-----------+\n\
+         |1-0|        1-2|\n\
+         +---+-----------+\n"
+    );
+
+    grid.remove_column(1);
+
+    assert_eq!(
+        grid.to_string(),
+        "+---+\n\
+         |0-0|\n\
+         +---+\n\
+         |1-0|\n\
+         +---+\n"
+    );
+}
+
+#[test]
+fn insert_and_remove_column_test() {
+    let mut grid = util::new_grid::<2, 3>();
+    grid.set(Entity::Cell(0, 1), Settings::new().span(2));
+    grid.set(
+        Entity::Cell(1, 1),
+        Settings::new().border(Border::default().left('*').right('*')),
+    );
+
+    let expected = grid.to_string();
+
+    for at in 0..=3 {
+        grid.insert_column(at);
+        grid.remove_column(at);
+
+        assert_eq!(grid.to_string(), expected);
+    }
+}
+
+#[test]
+#[should_panic]
+fn insert_column_out_of_bounds_test() {
+    let mut grid = util::new_grid::<2, 2>();
+    grid.insert_column(3);
+}
+
+#[test]
+#[should_panic]
+fn remove_column_out_of_bounds_test() {
+    let mut grid = util::new_grid::<2, 2>();
+    grid.remove_column(2);
+}
+
+#[test]
+fn count_visible_columns_in_row_test() {
+    let mut grid = util::new_grid::<3, 5>();
+    grid.set(Entity::Cell(0, 1), Settings::new().span(3));
+    grid.set(Entity::Cell(2, 0), Settings::new().span(5));
+
+    assert_eq!(grid.count_columns(), 5);
+    assert_eq!(grid.count_visible_columns_in_row(0), 3);
+    assert_eq!(grid.count_visible_columns_in_row(1), 5);
+    assert_eq!(grid.count_visible_columns_in_row(2), 1);
+}
@@ -0,0 +1,88 @@
+use papergrid::{Borders, DescriptorError, Line, Symbol, DEFAULT_BORDERS};
+
+#[test]
+fn borders_descriptor_round_trip_test() {
+    let descriptor = DEFAULT_BORDERS.to_descriptor();
+
+    assert_eq!(
+        descriptor,
+        "top=-,top_left=+,top_right=+,top_intersection=+,\
+         bottom=-,bottom_left=+,bottom_right=+,bottom_intersection=+,\
+         horizontal=-,horizontal_left=+,horizontal_right=+,\
+         vertical_left=|,vertical_intersection=|,vertical_right=|,\
+         intersection=+"
+    );
+    assert_eq!(Borders::from_descriptor(&descriptor), Ok(DEFAULT_BORDERS));
+}
+
+#[test]
+fn borders_descriptor_partially_empty_round_trip_test() {
+    let borders = Borders {
+        top: Some(Symbol::plain('─')),
+        vertical_intersection: Some(Symbol::plain(',')),
+        intersection: Some(Symbol::plain('\\')),
+        ..Default::default()
+    };
+
+    let descriptor = borders.to_descriptor();
+
+    assert_eq!(
+        descriptor,
+        "top=─,vertical_intersection=\\,,intersection=\\\\"
+    );
+    assert_eq!(Borders::from_descriptor(&descriptor), Ok(borders));
+
+    assert_eq!(Borders::from_descriptor(""), Ok(Borders::default()));
+}
+
+#[test]
+fn line_descriptor_round_trip_test() {
+    let line = Line {
+        horizontal: Some(Symbol::plain('=')),
+        left: Some(Symbol::plain('[')),
+        ..Default::default()
+    };
+
+    let descriptor = line.to_descriptor();
+
+    assert_eq!(descriptor, "horizontal==,left=[");
+    assert_eq!(Line::from_descriptor(&descriptor), Ok(line));
+}
+
+#[test]
+fn descriptor_errors_test() {
+    assert_eq!(
+        Borders::from_descriptor("top=-,header=="),
+        Err(DescriptorError::UnknownKey(String::from("header")))
+    );
+    assert_eq!(
+        Borders::from_descriptor("top=-,bottom"),
+        Err(DescriptorError::MissingValue(String::from("bottom")))
+    );
+    assert_eq!(
+        Line::from_descriptor("left=ab"),
+        Err(DescriptorError::InvalidSymbol(String::from("left")))
+    );
+}
+
+#[test]
+fn descriptor_empty_symbol_round_trip_test() {
+    let line = Line {
+        horizontal: Some(Symbol::plain('-')),
+        left: Some(Symbol::empty()),
+        ..Default::default()
+    };
+
+    let descriptor = line.to_descriptor();
+
+    assert_eq!(descriptor, "horizontal=-,left=");
+    assert_eq!(Line::from_descriptor(&descriptor), Ok(line));
+    assert_eq!(
+        Borders::from_descriptor("top=,bottom=-"),
+        Ok(Borders {
+            top: Some(Symbol::empty()),
+            bottom: Some(Symbol::plain('-')),
+            ..Default::default()
+        })
+    );
+}
@@ -1,3 +1,5 @@
+use papergrid::{Indent, Margin};
+
 mod util;
 
 #[test]
@@ -91,3 +93,30 @@ fn extract_empty_test() {
     let grid = grid.extract(.., ..);
     assert_eq!(grid.to_string(), "");
 }
+
+#[test]
+fn extract_keeps_margin_and_override_split_lines_test() {
+    let mut grid = util::new_grid::<4, 2>();
+    grid.margin(Margin {
+        top: Indent::new(1, '#'),
+        bottom: Indent::default(),
+        left: Indent::new(1, '>'),
+        right: Indent::default(),
+    });
+    grid.override_split_line(0, "Top");
+    grid.override_split_line(2, "Mid");
+
+    let grid = grid.extract(1..3, ..);
+
+    assert_eq!(
+        grid.to_string(),
+        concat!(
+            "##########\n",
+            ">+---+---+\n",
+            ">|1-0|1-1|\n",
+            ">Mid-+---+\n",
+            ">|2-0|2-1|\n",
+            ">+---+---+\n",
+        )
+    )
+}
@@ -1,4 +1,4 @@
-use papergrid::{AlignmentHorizontal, AlignmentVertical, Entity, Formatting, Settings};
+use papergrid::{AlignmentHorizontal, AlignmentVertical, Entity, Formatting, Indent, Settings};
 
 mod util;
 
@@ -1153,3 +1153,79 @@ fn tab_size_test() {
          +-----------------------+\n"
     );
 }
+
+#[test]
+fn formatting_new_differs_from_default_in_tabs_test() {
+    assert_eq!(Formatting::new().tab_width, 4);
+    assert_eq!(Formatting::default().tab_width, 0);
+
+    let mut grid = util::new_grid::<1, 1>();
+    grid.set(Entity::Cell(0, 0), Settings::new().text("\tasd"));
+
+    let expected = grid.to_string();
+
+    grid.set(
+        Entity::Global,
+        Settings::new().formatting(Formatting::new()),
+    );
+    assert_eq!(grid.to_string(), expected);
+    assert_eq!(
+        grid.to_string(),
+        "+-------+\n\
+         |    asd|\n\
+         +-------+\n"
+    );
+
+    grid.set(
+        Entity::Global,
+        Settings::new().formatting(Formatting::default()),
+    );
+    assert_eq!(
+        grid.to_string(),
+        "+---+\n\
+         |asd|\n\
+         +---+\n"
+    );
+}
+
+#[test]
+fn formatting_rtl_test() {
+    let mut grid = util::new_grid::<3, 1>();
+    grid.set(
+        Entity::Global,
+        Settings::new().padding(
+            Indent::new(1, '<'),
+            Indent::new(2, '>'),
+            Indent::default(),
+            Indent::default(),
+        ),
+    );
+    grid.set(Entity::Cell(0, 0), Settings::new().text("a long cell"));
+    grid.set(
+        Entity::Cell(1, 0),
+        Settings::new().formatting(Formatting {
+            rtl: true,
+            ..Formatting::new()
+        }),
+    );
+    grid.set(
+        Entity::Cell(2, 0),
+        Settings::new()
+            .alignment(AlignmentHorizontal::Center)
+            .formatting(Formatting {
+                rtl: true,
+                ..Formatting::new()
+            }),
+    );
+
+    assert_eq!(
+        grid.to_string(),
+        "+--------------+\n\
+         |<a long cell>>|\n\
+         +--------------+\n\
+         |>>        1-0<|\n\
+         +--------------+\n\
+         |>>    2-0    <|\n\
+         +--------------+\n"
+    );
+}
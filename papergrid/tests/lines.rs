@@ -0,0 +1,94 @@
+use papergrid::{
+    AlignmentHorizontal, Caption, CaptionPosition, Entity, Grid, Indent, Margin, Settings,
+};
+
+mod util;
+
+#[test]
+fn lines_test() {
+    let mut grid = util::new_grid::<3, 2>();
+    grid.set(Entity::Cell(1, 0), Settings::new().text("multi\nline"));
+    grid.set(Entity::Cell(2, 0), Settings::new().span(2));
+
+    let lines = grid.lines().collect::<Vec<_>>();
+
+    assert_eq!(lines.len(), 8);
+    assert_eq!(lines.join("\n"), grid.to_string_trimmed());
+}
+
+#[test]
+fn lines_with_caption_test() {
+    let mut grid = util::new_grid::<2, 2>();
+    grid.margin(Margin {
+        top: Indent::new(1, '^'),
+        bottom: Indent::default(),
+        left: Indent::new(1, '>'),
+        right: Indent::default(),
+    });
+    grid.set_caption(Caption {
+        text: String::from("above\nmultiline"),
+        position: CaptionPosition::Above,
+        alignment: AlignmentHorizontal::Right,
+    });
+    grid.set_caption(Caption {
+        text: String::from("below"),
+        position: CaptionPosition::Below,
+        alignment: AlignmentHorizontal::Center,
+    });
+
+    let lines = grid.lines().collect::<Vec<_>>();
+
+    assert_eq!(
+        lines,
+        vec![
+            "^^^^^^^^^^",
+            ">    above",
+            ">multiline",
+            ">+---+---+",
+            ">|0-0|0-1|",
+            ">+---+---+",
+            ">|1-0|1-1|",
+            ">+---+---+",
+            ">  below  ",
+        ]
+    );
+    assert_eq!(lines.join("\n") + "\n", grid.to_string());
+}
+
+#[test]
+fn lines_with_margin_test() {
+    let mut grid = util::new_grid::<2, 2>();
+    grid.margin(Margin {
+        top: Indent::new(1, '^'),
+        bottom: Indent::new(2, 'v'),
+        left: Indent::new(1, '>'),
+        right: Indent::new(1, '<'),
+    });
+
+    assert_eq!(
+        grid.lines().collect::<Vec<_>>(),
+        vec![
+            "^^^^^^^^^^^",
+            ">+---+---+<",
+            ">|0-0|0-1|<",
+            ">+---+---+<",
+            ">|1-0|1-1|<",
+            ">+---+---+<",
+            "vvvvvvvvvvv",
+            "vvvvvvvvvvv",
+        ]
+    );
+    assert_eq!(
+        grid.lines().collect::<Vec<_>>().join("\n"),
+        grid.to_string_trimmed()
+    );
+}
+
+#[test]
+fn lines_empty_test() {
+    let grid = Grid::new(0, 0);
+    assert_eq!(grid.lines().count(), 0);
+
+    let grid = Grid::new(2, 0);
+    assert_eq!(grid.lines().count(), 0);
+}
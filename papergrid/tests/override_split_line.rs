@@ -1,3 +1,5 @@
+use papergrid::{Indent, Margin};
+
 mod util;
 
 #[test]
@@ -165,3 +167,26 @@ fn override_bottom_test() {
         )
     );
 }
+
+#[test]
+fn override_top_with_margin_test() {
+    let mut grid = util::new_grid::<2, 2>();
+    grid.margin(Margin {
+        top: Indent::default(),
+        bottom: Indent::default(),
+        left: Indent::new(2, '>'),
+        right: Indent::new(1, '<'),
+    });
+    grid.override_split_line(0, "A caption longer than the table");
+
+    assert_eq!(
+        grid.to_string(),
+        concat!(
+            ">>A caption<\n",
+            ">>|0-0|0-1|<\n",
+            ">>+---+---+<\n",
+            ">>|1-0|1-1|<\n",
+            ">>+---+---+<\n",
+        )
+    );
+}
@@ -10,7 +10,9 @@
 // The above copyright notice and this permission notice shall be included in all
 // copies or substantial portions of the Software.
 
-use papergrid::{AlignmentHorizontal, AlignmentVertical, Borders, Entity, Grid, Indent, Settings};
+use papergrid::{
+    AlignmentHorizontal, AlignmentVertical, Borders, Entity, Formatting, Grid, Indent, Settings,
+};
 
 mod util;
 
@@ -135,17 +137,17 @@ fn render_multilane_alignment() {
     assert_eq!(
         grid.to_string(),
         concat!(
-            "+----------------------------------------+---------+\n\
-         |                  left                  |right one|\n\
-         |                  cell                  |         |\n\
-         +----------------------------------------+---------+\n\
-         |the second column got the beginning here| and here|\n\
-         |                                        | we      |\n\
-         |                                        | see     |\n\
-         |                                        | a       |\n\
-         |                                        | long    |\n\
-         |                                        | string  |\n\
-         +----------------------------------------+---------+\n"
+            "+----------------------------------------+---------+\n",
+            "|                  left                  |right one|\n",
+            "|                  cell                  |         |\n",
+            "+----------------------------------------+---------+\n",
+            "|the second column got the beginning here| and here|\n",
+            "|                                        | we      |\n",
+            "|                                        | see     |\n",
+            "|                                        | a       |\n",
+            "|                                        | long    |\n",
+            "|                                        | string  |\n",
+            "+----------------------------------------+---------+\n",
         )
     );
 }
@@ -177,17 +179,17 @@ fn render_multilane_vertical_alignment() {
     assert_eq!(
         grid.to_string(),
         concat!(
-            "+----------------------------------------+---------+\n\
-         |                  left                  |right one|\n\
-         |                  cell                  |         |\n\
-         +----------------------------------------+---------+\n\
-         |                                        | and here|\n\
-         |                                        | we      |\n\
-         |the second column got the beginning here| see     |\n\
-         |                                        | a       |\n\
-         |                                        | long    |\n\
-         |                                        | string  |\n\
-         +----------------------------------------+---------+\n"
+            "+----------------------------------------+---------+\n",
+            "|                  left                  |right one|\n",
+            "|                  cell                  |         |\n",
+            "+----------------------------------------+---------+\n",
+            "|                                        | and here|\n",
+            "|                                        | we      |\n",
+            "|the second column got the beginning here| see     |\n",
+            "|                                        | a       |\n",
+            "|                                        | long    |\n",
+            "|                                        | string  |\n",
+            "+----------------------------------------+---------+\n",
         )
     );
 }
@@ -224,7 +226,7 @@ fn render_row_span() {
     assert_eq!(
         grid.to_string(),
         concat!(
-            "+---+---+\n",
+            "+-------+\n",
             "|  0-0  |\n",
             "+---+---+\n",
             "|1-0|1-1|\n",
@@ -248,7 +250,7 @@ fn render_miltiline_span() {
     assert_eq!(
         grid.to_string(),
         concat!(
-            "+---+---+\n",
+            "+-------+\n",
             "|  0-0  |\n",
             "|  0-1  |\n",
             "+---+---+\n",
@@ -281,7 +283,7 @@ fn render_row_span_multilane() {
     assert_eq!(
         grid.to_string(),
         concat!(
-            "+-----+----+----+\n",
+            "+----------+----+\n",
             "|first line|e.g.|\n",
             "+-----+----+----+\n",
             "|0    |1   |2   |\n",
@@ -289,7 +291,7 @@ fn render_row_span_multilane() {
             "|0    |1   |2   |\n",
             "+-----+----+----+\n",
             "|full last line |\n",
-            "+-----+----+----+\n",
+            "+---------------+\n",
         )
     );
 }
@@ -311,7 +313,7 @@ fn render_row_span_with_horizontal_ident() {
     assert_eq!(
         grid.to_string(),
         concat!(
-            "+-----------+---+\n",
+            "+---------------+\n",
             "|0-0            |\n",
             "+-----------+---+\n",
             "|    1-0    |1-1|\n",
@@ -333,13 +335,13 @@ fn render_row_span_3x3_with_horizontal_ident() {
     assert_eq!(
         grid.to_string(),
         concat!(
-            "+-+-+---+\n",
+            "+-------+\n",
             "|0-0    |\n",
-            "+-+-+---+\n",
+            "+---+---+\n",
             "|1-0|1-2|\n",
-            "+-+-+---+\n",
+            "+---+---+\n",
             "|2-0|2-2|\n",
-            "+-+-+---+\n",
+            "+---+---+\n",
         )
     );
 }
@@ -356,7 +358,7 @@ fn render_2_colided_row_span_3x3() {
     assert_eq!(
         grid.to_string(),
         concat!(
-            "+-----+----+---+\n",
+            "+----------+---+\n",
             "|0-0xxxxxxx|0-2|\n",
             "+-----+----+---+\n",
             "|1-0  |1-1     |\n",
@@ -376,7 +378,7 @@ fn render_2_colided_row_span_3x3() {
     assert_eq!(
         grid.to_string(),
         concat!(
-            "+---+-----+----+\n",
+            "+---------+----+\n",
             "|0-0      |0-2 |\n",
             "+---+-----+----+\n",
             "|1-0|1-1xxxxxxx|\n",
@@ -397,7 +399,7 @@ fn render_2_colided_row_span_3x3() {
     assert_eq!(
         grid.to_string(),
         concat!(
-            "+----------------+-----+----+\n",
+            "+----------------------+----+\n",
             "|0-0                   |0-2 |\n",
             "+----------------+-----+----+\n",
             "|1-0             |1-1xxxxxxx|\n",
@@ -415,7 +417,7 @@ fn render_2_colided_row_span_3x3() {
     assert_eq!(
         grid.to_string(),
         concat!(
-            "+---+----------------+---+\n",
+            "+--------------------+---+\n",
             "|0-0                 |0-2|\n",
             "+---+----------------+---+\n",
             "|1-0|1-1                 |\n",
@@ -433,7 +435,7 @@ fn render_2_colided_row_span_3x3() {
     assert_eq!(
         grid.to_string(),
         concat!(
-            "+---+---+----------------+\n",
+            "+-------+----------------+\n",
             "|0-0    |0-2xxxxxxxxxxxxx|\n",
             "+---+---+----------------+\n",
             "|1-0|1-1                 |\n",
@@ -456,7 +458,7 @@ fn render_spaned_column_in_first_cell_3x3() {
     assert_eq!(
         grid.to_string(),
         concat!(
-            "+-----+----+---+\n",
+            "+----------+---+\n",
             "|0-0xxxxxxx|0-2|\n",
             "+-----+----+---+\n",
             "|1-0  |1-1 |1-2|\n",
@@ -485,13 +487,13 @@ fn render_row_span_with_different_length() {
     assert_eq!(
         grid.to_string(),
         concat!(
-            "+---------+---------+\n",
+            "+-------------------+\n",
             "|first row          |\n",
             "+---------+---------+\n",
             "|0        |1        |\n",
             "+---------+---------+\n",
             "|a longer second row|\n",
-            "+---------+---------+\n",
+            "+-------------------+\n",
         )
     );
 }
@@ -506,7 +508,7 @@ fn render_row_span_with_odd_length() {
 
     assert_eq!(
         grid.to_string(),
-        concat!("+--+-+\n", "|3   |\n", "+--+-+\n", "|2 |4|\n", "+--+-+\n")
+        concat!("+----+\n", "|3   |\n", "+--+-+\n", "|2 |4|\n", "+--+-+\n")
     );
 }
 
@@ -520,13 +522,13 @@ fn render_only_row_spaned() {
 
     assert_eq!(
         grid.to_string(),
-        "+-+-+\n\
+        "+---+\n\
          |0-0|\n\
-         +-+-+\n\
+         +---+\n\
          |1-0|\n\
-         +-+-+\n\
+         +---+\n\
          |2-0|\n\
-         +-+-+\n"
+         +---+\n"
     );
 }
 
@@ -538,7 +540,7 @@ fn grid_2x2_span_test() {
 
     assert_eq!(
         grid.to_string(),
-        "+---+---+\n\
+        "+-------+\n\
          |123    |\n\
          +---+---+\n\
          |asd|asd|\n\
@@ -555,11 +557,11 @@ fn grid_2x2_span_2_test() {
 
     assert_eq!(
         grid.to_string(),
-        "+--+-+\n\
+        "+----+\n\
          |1234|\n\
-         +--+-+\n\
+         +----+\n\
          |asdw|\n\
-         +--+-+\n"
+         +----+\n"
     );
 
     grid.set(Entity::Cell(0, 0), Settings::new().text("1"));
@@ -568,11 +570,11 @@ fn grid_2x2_span_2_test() {
     let str = grid.to_string();
     assert_eq!(
         str,
-        "+++\n\
+        "+-+\n\
          |1|\n\
-         +++\n\
+         +-+\n\
          |a|\n\
-         +++\n"
+         +-+\n"
     );
 }
 
@@ -627,7 +629,7 @@ fn render_zero_span_between_cells() {
     assert_eq!(
         grid.to_string(),
         concat!(
-            "+---+---+---+\n",
+            "+-------+---+\n",
             "|0-0    |0-2|\n",
             "+---+---+---+\n",
             "|1-0|1-1|1-2|\n",
@@ -640,11 +642,11 @@ fn render_zero_span_between_cells() {
     assert_eq!(
         grid.to_string(),
         concat!(
-            "+-+-+---+\n",
+            "+---+---+\n",
             "|0-0|0-2|\n",
-            "+-+-+---+\n",
+            "+---+---+\n",
             "|1-0|1-2|\n",
-            "+-+-+---+\n",
+            "+---+---+\n",
         )
     );
 }
@@ -659,7 +661,7 @@ fn render_zero_span_at_the_end() {
     assert_eq!(
         grid.to_string(),
         concat!(
-            "+---+---+---+\n",
+            "+-----------+\n",
             "|0-0        |\n",
             "+---+---+---+\n",
             "|1-0|1-1|1-2|\n",
@@ -672,7 +674,7 @@ fn render_zero_span_at_the_end() {
 
     assert_eq!(
         grid.to_string(),
-        concat!("+-+++\n", "|0-0|\n", "+-+++\n", "|1-0|\n", "+-+++\n")
+        concat!("+---+\n", "|0-0|\n", "+---+\n", "|1-0|\n", "+---+\n")
     );
 }
 
@@ -686,7 +688,7 @@ fn render_zero_span_grid() {
     grid.set(Entity::Cell(1, 1), Settings::new().span(0));
 
     // todo: determine if it's correct behaviour?
-    assert_eq!("+-+-+\n|0-0|\n+-+-+\n|1-0|\n+-+-+\n", grid.to_string());
+    assert_eq!("+---+\n|0-0|\n+---+\n|1-0|\n+---+\n", grid.to_string());
 }
 
 #[test]
@@ -719,3 +721,460 @@ fn hieroglyph_multiline_handling() {
          +----+--+\n"
     )
 }
+
+#[test]
+fn write_to_test() {
+    let mut grid = util::new_grid::<3, 2>();
+    grid.set(Entity::Cell(1, 1), Settings::new().text("multi\nline"));
+
+    let mut buf = Vec::new();
+    grid.write_to(&mut buf).unwrap();
+
+    assert_eq!(String::from_utf8(buf).unwrap(), grid.to_string());
+}
+
+#[test]
+fn write_to_with_callback_test() {
+    let grid = util::new_grid::<4, 3>();
+
+    let mut rows = Vec::new();
+    let mut buf = Vec::new();
+    grid.write_to_with(&mut buf, |row| rows.push(row)).unwrap();
+
+    assert_eq!(rows, [0, 1, 2, 3]);
+    assert_eq!(String::from_utf8(buf).unwrap(), grid.to_string());
+}
+
+#[test]
+fn to_tsv_test() {
+    let mut grid = util::new_grid::<2, 3>();
+    grid.set(Entity::Cell(0, 1), Settings::new().text("multi\nline"));
+    grid.set(Entity::Cell(1, 2), Settings::new().text("tab\tinside"));
+
+    assert_eq!(
+        grid.to_tsv(),
+        concat!("0-0\tmulti line\t0-2\n", "1-0\t1-1\ttab inside\n")
+    );
+}
+
+#[test]
+fn to_tsv_empty_test() {
+    assert_eq!(Grid::new(0, 0).to_tsv(), "");
+    assert_eq!(Grid::new(0, 3).to_tsv(), "");
+    assert_eq!(Grid::new(3, 0).to_tsv(), "");
+}
+
+#[test]
+fn to_string_trimmed_test() {
+    let grid = util::new_grid::<1, 1>();
+    assert_eq!(grid.to_string_trimmed(), "+---+\n|0-0|\n+---+");
+    assert_eq!(grid.to_string_trimmed() + "\n", grid.to_string());
+
+    let grid = util::new_grid::<3, 3>();
+    assert_eq!(
+        grid.to_string_trimmed(),
+        concat!(
+            "+---+---+---+\n",
+            "|0-0|0-1|0-2|\n",
+            "+---+---+---+\n",
+            "|1-0|1-1|1-2|\n",
+            "+---+---+---+\n",
+            "|2-0|2-1|2-2|\n",
+            "+---+---+---+",
+        )
+    );
+    assert_eq!(grid.to_string_trimmed() + "\n", grid.to_string());
+
+    assert_eq!(Grid::new(0, 0).to_string_trimmed(), "");
+}
+
+#[test]
+fn render_vertical_center_with_asymmetric_padding() {
+    let mut grid = Grid::new(1, 2);
+    grid.set(Entity::Cell(0, 1), Settings::new().text("1\n2\n3\n4\n5"));
+    grid.set(
+        Entity::Cell(0, 0),
+        Settings::new()
+            .text("x")
+            .vertical_alignment(AlignmentVertical::Center)
+            .padding(
+                Indent::default(),
+                Indent::default(),
+                Indent::spaced(2),
+                Indent::default(),
+            ),
+    );
+
+    assert_eq!(
+        grid.to_string(),
+        concat!("+-+-+\n", "| |1|\n", "| |2|\n", "| |3|\n", "|x|4|\n", "| |5|\n", "+-+-+\n",)
+    );
+
+    grid.set(
+        Entity::Cell(0, 0),
+        Settings::new().padding(
+            Indent::default(),
+            Indent::default(),
+            Indent::default(),
+            Indent::spaced(2),
+        ),
+    );
+
+    assert_eq!(
+        grid.to_string(),
+        concat!("+-+-+\n", "| |1|\n", "|x|2|\n", "| |3|\n", "| |4|\n", "| |5|\n", "+-+-+\n",)
+    );
+}
+
+#[test]
+fn render_padding_fill_on_each_side() {
+    let mut grid = Grid::new(1, 2);
+    grid.set(
+        Entity::Cell(0, 1),
+        Settings::new().text("1\n2\n3\n4\n5\n6\n7"),
+    );
+    grid.set(
+        Entity::Cell(0, 0),
+        Settings::new()
+            .text("x")
+            .vertical_alignment(AlignmentVertical::Center)
+            .padding(
+                Indent::new(1, '<'),
+                Indent::new(2, '>'),
+                Indent::new(1, '^'),
+                Indent::new(2, 'v'),
+            ),
+    );
+
+    assert_eq!(
+        grid.to_string(),
+        concat!(
+            "+----+-+\n",
+            "|^^^^|1|\n",
+            "|< >>|2|\n",
+            "|<x>>|3|\n",
+            "|< >>|4|\n",
+            "|< >>|5|\n",
+            "|vvvv|6|\n",
+            "|vvvv|7|\n",
+            "+----+-+\n",
+        )
+    );
+
+    grid.set(
+        Entity::Cell(0, 0),
+        Settings::new().vertical_alignment(AlignmentVertical::Top),
+    );
+
+    assert_eq!(
+        grid.to_string(),
+        concat!(
+            "+----+-+\n",
+            "|^^^^|1|\n",
+            "|<x>>|2|\n",
+            "|< >>|3|\n",
+            "|< >>|4|\n",
+            "|< >>|5|\n",
+            "|vvvv|6|\n",
+            "|vvvv|7|\n",
+            "+----+-+\n",
+        )
+    );
+}
+
+#[test]
+fn render_center_bias() {
+    let render = |width: usize, alignment: AlignmentHorizontal| {
+        let mut grid = Grid::new(2, 1);
+        grid.set(
+            Entity::Cell(0, 0),
+            Settings::new().text("abc").alignment(alignment),
+        );
+        grid.set(Entity::Cell(1, 0), Settings::new().text("-".repeat(width)));
+        grid.to_string().lines().nth(1).unwrap().to_owned()
+    };
+
+    assert_eq!(render(4, AlignmentHorizontal::Center), "|abc |");
+    assert_eq!(render(4, AlignmentHorizontal::CenterLeftBias), "| abc|");
+    assert_eq!(render(5, AlignmentHorizontal::Center), "| abc |");
+    assert_eq!(render(5, AlignmentHorizontal::CenterLeftBias), "| abc |");
+}
+
+#[test]
+fn render_max_height() {
+    let mut grid = Grid::new(1, 2);
+    grid.set(
+        Entity::Column(0),
+        Settings::new().text("1\n2\n3\n4\n5\n6").max_height(3),
+    );
+    grid.set(
+        Entity::Column(1),
+        Settings::new().text("a\nb").max_height(3),
+    );
+
+    assert_eq!(
+        grid.to_string(),
+        concat!("+--+-+\n", "|1 |a|\n", "|2 |b|\n", "|3…| |\n", "+--+-+\n",)
+    );
+}
+
+#[test]
+fn render_max_height_doesnt_affect_short_cells() {
+    let mut grid = util::new_grid::<2, 2>();
+    let expected = grid.to_string();
+
+    grid.set(Entity::Global, Settings::new().max_height(1));
+
+    assert_eq!(grid.to_string(), expected);
+}
+
+#[test]
+fn render_trim_trailing_whitespace_blank_style() {
+    let mut grid = util::new_grid::<3, 2>();
+    grid.set_borders(Borders {
+        vertical_intersection: Some(' '.into()),
+        ..Default::default()
+    });
+    grid.set(
+        Entity::Global,
+        Settings::new().padding(
+            Indent::spaced(1),
+            Indent::spaced(1),
+            Indent::default(),
+            Indent::default(),
+        ),
+    );
+    grid.set(Entity::Cell(0, 1), Settings::new().text("a long header"));
+    grid.set(Entity::Cell(2, 0), Settings::new().text("2-0\n2-0 2-0"));
+
+    assert_eq!(
+        grid.to_string(),
+        concat!(
+            " 0-0       a long header \n",
+            " 1-0       1-1           \n",
+            " 2-0       2-1           \n",
+            " 2-0 2-0                 \n",
+        )
+    );
+
+    grid.set_trim_trailing_whitespace(true);
+
+    let table = grid.to_string();
+    assert_eq!(
+        table,
+        concat!(
+            " 0-0       a long header\n",
+            " 1-0       1-1\n",
+            " 2-0       2-1\n",
+            " 2-0 2-0\n",
+        )
+    );
+
+    assert!(table.lines().all(|line| !line.ends_with(' ')));
+    assert!(grid.lines().all(|line| !line.ends_with(' ')));
+    assert_eq!(
+        grid.lines().collect::<Vec<_>>(),
+        table.lines().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn render_trim_trailing_whitespace_with_right_border() {
+    let mut grid = util::new_grid::<2, 2>();
+    grid.set(Entity::Cell(0, 1), Settings::new().text("a long"));
+
+    let expected = grid.to_string();
+
+    grid.set_trim_trailing_whitespace(true);
+
+    assert_eq!(grid.to_string(), expected);
+}
+
+#[test]
+fn render_vertical_justify_test() {
+    let mut grid = util::new_grid::<1, 3>();
+    grid.set(
+        Entity::Cell(0, 0),
+        Settings::new().text("1\n2\n3\n4\n5\n6\n7"),
+    );
+    grid.set(
+        Entity::Cell(0, 1),
+        Settings::new()
+            .text("a\nb\nc")
+            .vertical_alignment(AlignmentVertical::Justify),
+    );
+    grid.set(
+        Entity::Cell(0, 2),
+        Settings::new()
+            .text("one")
+            .vertical_alignment(AlignmentVertical::Justify),
+    );
+
+    assert_eq!(
+        grid.to_string(),
+        concat!(
+            "+-+-+---+\n",
+            "|1|a|one|\n",
+            "|2| |   |\n",
+            "|3| |   |\n",
+            "|4|b|   |\n",
+            "|5| |   |\n",
+            "|6| |   |\n",
+            "|7|c|   |\n",
+            "+-+-+---+\n",
+        )
+    );
+}
+
+#[test]
+fn render_vertical_justify_with_padding_test() {
+    let mut grid = util::new_grid::<1, 2>();
+    grid.set(
+        Entity::Cell(0, 0),
+        Settings::new().text("1\n2\n3\n4\n5\n6\n7"),
+    );
+    grid.set(
+        Entity::Cell(0, 1),
+        Settings::new()
+            .text("a\nb\nc")
+            .vertical_alignment(AlignmentVertical::Justify)
+            .padding(
+                Indent::default(),
+                Indent::default(),
+                Indent::new(1, '^'),
+                Indent::new(1, 'v'),
+            ),
+    );
+
+    assert_eq!(
+        grid.to_string(),
+        concat!(
+            "+-+-+\n", "|1|^|\n", "|2|a|\n", "|3| |\n", "|4|b|\n", "|5| |\n", "|6|c|\n", "|7|v|\n",
+            "+-+-+\n",
+        )
+    );
+}
+
+#[test]
+fn render_skeleton_keeps_dimensions() {
+    let mut grid = util::new_grid::<3, 3>();
+    grid.set(Entity::Cell(0, 0), Settings::new().text("a long\ncell"));
+    grid.set(Entity::Cell(1, 1), Settings::new().text("tab\tbed").span(2));
+    grid.set(
+        Entity::Row(2),
+        Settings::new()
+            .padding(
+                Indent::spaced(1),
+                Indent::spaced(2),
+                Indent::spaced(1),
+                Indent::default(),
+            )
+            .alignment(AlignmentHorizontal::Right),
+    );
+
+    let render = grid.to_string();
+    let skeleton = grid.to_string_skeleton('░');
+
+    assert_ne!(render, skeleton);
+    assert_eq!(render.lines().count(), skeleton.lines().count());
+    for (line, skeleton_line) in render.lines().zip(skeleton.lines()) {
+        assert_eq!(
+            papergrid::string_width(line),
+            papergrid::string_width(skeleton_line)
+        );
+    }
+
+    assert_eq!(
+        skeleton,
+        concat!(
+            "+------+------+------+\n",
+            "|░ ░░░░|░░░   |░░░   |\n",
+            "|░░░░  |      |      |\n",
+            "+------+------+------+\n",
+            "|░░░   |░░░    ░░░   |\n",
+            "+------+------+------+\n",
+            "|      |      |      |\n",
+            "| ░░░  | ░░░  | ░░░  |\n",
+            "+------+------+------+\n",
+        )
+    );
+}
+
+#[test]
+fn layout_test() {
+    let mut grid = util::new_grid::<2, 3>();
+    grid.set(
+        Entity::Cell(0, 0),
+        Settings::new().text("Hello\nWorld!").padding(
+            Indent::spaced(1),
+            Indent::spaced(1),
+            Indent::spaced(1),
+            Indent::default(),
+        ),
+    );
+    grid.set(
+        Entity::Column(1),
+        Settings::new().alignment(AlignmentHorizontal::Right),
+    );
+    grid.set(Entity::Cell(1, 1), Settings::new().span(2));
+    grid.set_column_width(2, 5);
+
+    let layout = grid.layout();
+
+    assert_eq!(layout.widths, vec![8, 3, 5]);
+    assert_eq!(layout.normal_widths, vec![8, 3, 3]);
+    assert_eq!(layout.heights, vec![3, 1]);
+    assert_eq!(layout.styles.len(), 2);
+    assert_eq!(layout.styles[0].len(), 3);
+    assert_eq!(layout.styles[0][0].padding.top.size, 1);
+    assert_eq!(layout.styles[1][0].padding.top.size, 0);
+    assert_eq!(layout.styles[1][1].alignment_h, AlignmentHorizontal::Right);
+    assert_eq!(layout.styles[1][2].alignment_h, AlignmentHorizontal::Left);
+    assert_eq!(layout.widths, grid.build_widths());
+    assert_eq!(layout.normal_widths, grid.natural_column_widths());
+}
+
+#[test]
+fn render_skeleton_with_trim() {
+    let mut grid = util::new_grid::<2, 2>();
+    grid.set(Entity::Cell(0, 0), Settings::new().text("  a b  \n   "));
+    grid.set(Entity::Cell(1, 1), Settings::new().text("1-1   "));
+    grid.set(
+        Entity::Global,
+        Settings::new()
+            .alignment(AlignmentHorizontal::Right)
+            .formatting(Formatting {
+                horizontal_trim: true,
+                vertical_trim: true,
+                allow_lines_alignement: true,
+                ..Formatting::new()
+            }),
+    );
+    grid.set_trim_trailing_whitespace(true);
+
+    let render = grid.to_string();
+    let skeleton = grid.to_string_skeleton('░');
+
+    assert_eq!(
+        render,
+        concat!(
+            "+-------+------+\n",
+            "|    a b|   0-1|\n",
+            "|       |      |\n",
+            "+-------+------+\n",
+            "|    1-0|   1-1|\n",
+            "+-------+------+\n",
+        )
+    );
+    assert_eq!(
+        skeleton,
+        concat!(
+            "+-------+------+\n",
+            "|    ░ ░|   ░░░|\n",
+            "|       |      |\n",
+            "+-------+------+\n",
+            "|    ░░░|   ░░░|\n",
+            "+-------+------+\n",
+        )
+    );
+}
@@ -0,0 +1,44 @@
+use papergrid::{AlignmentHorizontal, AlignmentVertical, Entity, Indent, Settings};
+
+mod util;
+
+#[test]
+fn render_cell_test() {
+    let mut grid = util::new_grid::<2, 2>();
+    grid.set(
+        Entity::Cell(0, 0),
+        Settings::new()
+            .text("a\nbcd")
+            .alignment(AlignmentHorizontal::Right)
+            .padding(
+                Indent::spaced(1),
+                Indent::spaced(2),
+                Indent::new(1, '^'),
+                Indent::default(),
+            ),
+    );
+    grid.set(Entity::Cell(1, 1), Settings::new().text("1-1\n\n\n"));
+    grid.set(
+        Entity::Cell(1, 0),
+        Settings::new().vertical_alignment(AlignmentVertical::Center),
+    );
+
+    assert_eq!(grid.render_cell(0, 0), vec!["^^^^^^", " a    ", " bcd  "]);
+    assert_eq!(grid.render_cell(0, 1), vec!["0-1", "   ", "   "]);
+    assert_eq!(grid.render_cell(1, 0), vec!["      ", "1-0   ", "      "]);
+    assert_eq!(grid.render_cell(1, 1), vec!["1-1", "   ", "   "]);
+
+    let lines = grid.to_string();
+    let lines = lines.lines().collect::<Vec<_>>();
+    assert_eq!(lines[1], "|^^^^^^|0-1|");
+    assert_eq!(lines[3], "| bcd  |   |");
+}
+
+#[test]
+fn render_cell_spanned_test() {
+    let mut grid = util::new_grid::<2, 2>();
+    grid.set(Entity::Cell(0, 0), Settings::new().span(2));
+
+    assert_eq!(grid.render_cell(0, 0), vec!["0-0    "]);
+    assert!(grid.render_cell(0, 1).is_empty());
+}
@@ -0,0 +1,66 @@
+use papergrid::{AlignmentHorizontal, Entity, Settings};
+
+mod util;
+
+#[test]
+fn resize_grow_test() {
+    let mut grid = util::new_grid::<2, 2>();
+    grid.resize(3, 4);
+
+    assert_eq!(grid.count_rows(), 3);
+    assert_eq!(grid.count_columns(), 4);
+    assert_eq!(grid.get_cell_content(0, 0), "0-0");
+    assert_eq!(grid.get_cell_content(1, 1), "1-1");
+    assert_eq!(grid.get_cell_content(2, 3), "");
+    assert_eq!(
+        grid.to_string(),
+        "+---+---+++\n\
+         |0-0|0-1|||\n\
+         +---+---+++\n\
+         |1-0|1-1|||\n\
+         +---+---+++\n\
+         |   |   |||\n\
+         +---+---+++\n"
+    );
+}
+
+#[test]
+fn resize_grow_and_shrink_back_test() {
+    let mut grid = util::new_grid::<2, 2>();
+    grid.resize(3, 4);
+    grid.set(Entity::Cell(2, 3), Settings::new().text("2-3"));
+    grid.set(
+        Entity::Column(3),
+        Settings::new().alignment(AlignmentHorizontal::Right),
+    );
+    grid.resize(2, 2);
+
+    assert_eq!(grid.count_rows(), 2);
+    assert_eq!(grid.count_columns(), 2);
+    assert_eq!(
+        grid.to_string(),
+        "+---+---+\n\
+         |0-0|0-1|\n\
+         +---+---+\n\
+         |1-0|1-1|\n\
+         +---+---+\n"
+    );
+
+    grid.resize(3, 4);
+
+    assert_eq!(grid.get_cell_content(2, 3), "");
+    assert_eq!(
+        grid.style(Entity::Column(3)).alignment_h,
+        AlignmentHorizontal::Left
+    );
+}
+
+#[test]
+fn resize_to_empty_test() {
+    let mut grid = util::new_grid::<2, 2>();
+    grid.resize(0, 0);
+
+    assert_eq!(grid.count_rows(), 0);
+    assert_eq!(grid.count_columns(), 0);
+    assert_eq!(grid.to_string(), "");
+}
@@ -0,0 +1,157 @@
+use papergrid::{AlignmentHorizontal, Border, Entity, Line, Settings, Symbol};
+
+mod util;
+
+#[test]
+fn insert_row_middle_test() {
+    let mut grid = util::new_grid::<3, 2>();
+    grid.set(Entity::Cell(0, 0), Settings::new().text("a long cell"));
+    grid.set(
+        Entity::Row(1),
+        Settings::new().alignment(AlignmentHorizontal::Right),
+    );
+    grid.set(
+        Entity::Row(2),
+        Settings::new().alignment(AlignmentHorizontal::Center),
+    );
+
+    grid.insert_row(1);
+    grid.set(Entity::Row(1), Settings::new().text("new"));
+
+    assert_eq!(grid.count_rows(), 4);
+    assert_eq!(
+        grid.to_string(),
+        "+-----------+---+\n\
+         |a long cell|0-1|\n\
+         +-----------+---+\n\
+         |new        |new|\n\
+         +-----------+---+\n\
+         |        1-0|1-1|\n\
+         +-----------+---+\n\
+         |    2-0    |2-1|\n\
+         +-----------+---+\n"
+    );
+
+    assert_eq!(
+        grid.style(Entity::Row(2)).alignment_h,
+        AlignmentHorizontal::Right
+    );
+    assert_eq!(
+        grid.style(Entity::Row(3)).alignment_h,
+        AlignmentHorizontal::Center
+    );
+}
+
+#[test]
+fn insert_row_moves_borders_and_lines_test() {
+    let mut grid = util::new_grid::<2, 2>();
+    grid.set(Entity::Cell(0, 0), Settings::new().span(2));
+    grid.set(
+        Entity::Cell(1, 1),
+        Settings::new().border(Border::default().left('*').bottom('#')),
+    );
+    grid.set_split_line(
+        1,
+        Line {
+            horizontal: Some(Symbol::from('=')),
+            ..Default::default()
+        },
+    );
+
+    grid.insert_row(0);
+    grid.set(Entity::Row(0), Settings::new().text("new"));
+
+    assert_eq!(
+        grid.to_string(),
+        "+---+---+\n\
+         |new|new|\n\
+         +---+---+\n\
+         |0-0    |\n\
+         +===+===+\n\
+         |1-0*1-1|\n\
+         +---+###+\n"
+    );
+}
+
+#[test]
+fn insert_row_end_test() {
+    let mut grid = util::new_grid::<2, 2>();
+    grid.set(
+        Entity::Cell(1, 0),
+        Settings::new().border(Border::default().bottom('#')),
+    );
+
+    grid.insert_row(2);
+    grid.set(Entity::Row(2), Settings::new().text("new"));
+
+    assert_eq!(
+        grid.to_string(),
+        "+---+---+\n\
+         |0-0|0-1|\n\
+         +---+---+\n\
+         |1-0|1-1|\n\
+         +---+---+\n\
+         |new|new|\n\
+         +###+---+\n"
+    );
+}
+
+#[test]
+fn remove_row_test() {
+    let mut grid = util::new_grid::<3, 2>();
+    grid.set(
+        Entity::Row(2),
+        Settings::new().alignment(AlignmentHorizontal::Right),
+    );
+    grid.set(Entity::Cell(2, 0), Settings::new().text("a long cell"));
+    grid.set(Entity::Cell(1, 0), Settings::new().span(2));
+
+    grid.remove_row(1);
+
+    assert_eq!(grid.count_rows(), 2);
+    assert_eq!(
+        grid.to_string(),
+        "+-----------+---+\n\
+         |0-0        |0-1|\n\
+         +-----------+---+\n\
+         |a long cell|2-1|\n\
+         +-----------+---+\n"
+    );
+    assert_eq!(
+        grid.style(Entity::Row(1)).alignment_h,
+        AlignmentHorizontal::Right
+    );
+}
+
+#[test]
+fn insert_and_remove_row_test() {
+    let mut grid = util::new_grid::<3, 2>();
+    grid.set(Entity::Cell(1, 0), Settings::new().span(2));
+    grid.set(
+        Entity::Cell(2, 1),
+        Settings::new().border(Border::default().top('*').bottom('*').left('#')),
+    );
+
+    let expected = grid.to_string();
+
+    for at in 0..=3 {
+        grid.insert_row(at);
+        grid.remove_row(at);
+
+        assert_eq!(grid.to_string(), expected);
+    }
+}
+
+#[test]
+#[should_panic]
+fn insert_row_out_of_bounds_test() {
+    let mut grid = util::new_grid::<2, 2>();
+    grid.insert_row(3);
+}
+
+#[test]
+#[should_panic]
+fn remove_row_out_of_bounds_test() {
+    let mut grid = util::new_grid::<2, 2>();
+    grid.remove_row(2);
+}
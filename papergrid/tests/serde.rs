@@ -0,0 +1,99 @@
+#![cfg(feature = "serde")]
+
+use papergrid::{
+    AlignmentHorizontal, AlignmentVertical, Border, Borders, Formatting, Indent, Line, Padding,
+    Symbol,
+};
+
+mod util;
+
+#[test]
+fn deserialize_borders_test() {
+    let json = r#"{
+        "top": "─",
+        "top_left": "┌",
+        "top_right": "┐",
+        "top_intersection": "┬",
+        "bottom": "─",
+        "bottom_left": "└",
+        "bottom_right": "┘",
+        "bottom_intersection": "┴",
+        "vertical_left": "│",
+        "vertical_intersection": "│",
+        "vertical_right": "│"
+    }"#;
+
+    let borders: Borders = serde_json::from_str(json).unwrap();
+
+    let mut grid = util::new_grid::<2, 2>();
+    grid.clear_theme();
+    grid.set_borders(borders);
+
+    assert_eq!(
+        grid.to_string(),
+        "┌───┬───┐\n\
+         │0-0│0-1│\n\
+         │1-0│1-1│\n\
+         └───┴───┘\n"
+    );
+}
+
+#[test]
+fn serialize_symbol_as_glyph_test() {
+    let line = Line {
+        horizontal: Some(Symbol::plain('=')),
+        ..Default::default()
+    };
+
+    let json = serde_json::to_string(&line).unwrap();
+
+    assert_eq!(
+        json,
+        r#"{"horizontal":"=","intersection":null,"left":null,"right":null}"#
+    );
+    assert_eq!(serde_json::from_str::<Line>(&json).unwrap(), line);
+
+    assert!(serde_json::from_str::<Symbol>(r#""ab""#).is_err());
+    assert!(serde_json::from_str::<Symbol>(r#""""#).is_err());
+}
+
+#[test]
+fn serde_round_trip_test() {
+    let border = Border::new('*', '*', '|', '|', '+', '+', '+', '+');
+    let json = serde_json::to_string(&border).unwrap();
+    assert_eq!(serde_json::from_str::<Border>(&json).unwrap(), border);
+
+    let padding = Padding {
+        top: Indent::new(1, '^'),
+        bottom: Indent::spaced(0),
+        left: Indent::new(2, '<'),
+        right: Indent::spaced(1),
+    };
+    let json = serde_json::to_string(&padding).unwrap();
+    assert_eq!(serde_json::from_str::<Padding>(&json).unwrap(), padding);
+
+    let formatting = Formatting::new();
+    let json = serde_json::to_string(&formatting).unwrap();
+    assert_eq!(
+        serde_json::from_str::<Formatting>(&json).unwrap(),
+        formatting
+    );
+
+    let json = serde_json::to_string(&AlignmentHorizontal::Right).unwrap();
+    assert_eq!(json, r#""Right""#);
+    assert_eq!(
+        serde_json::from_str::<AlignmentVertical>(r#""Bottom""#).unwrap(),
+        AlignmentVertical::Bottom
+    );
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn serde_colored_symbol_round_trip_test() {
+    let symbol = Symbol::ansi(String::from("\u{1b}[31m*\u{1b}[39m")).unwrap();
+
+    let json = serde_json::to_string(&symbol).unwrap();
+
+    assert_eq!(json, r#""\u001b[31m*\u001b[39m""#);
+    assert_eq!(serde_json::from_str::<Symbol>(&json).unwrap(), symbol);
+}
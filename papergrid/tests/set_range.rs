@@ -0,0 +1,58 @@
+use papergrid::{AlignmentHorizontal, Entity, Settings};
+
+mod util;
+
+#[test]
+fn set_range_center_inner_cells_test() {
+    let mut grid = util::new_grid::<4, 4>();
+    grid.set(Entity::Row(0), Settings::new().text("long"));
+    grid.set(
+        Entity::Row(2),
+        Settings::new().alignment(AlignmentHorizontal::Right),
+    );
+    grid.set_range(
+        1..3,
+        1..3,
+        Settings::new().alignment(AlignmentHorizontal::Center),
+    );
+
+    assert_eq!(
+        grid.style(Entity::Cell(1, 1)).alignment_h,
+        AlignmentHorizontal::Center
+    );
+    assert_eq!(
+        grid.style(Entity::Cell(2, 0)).alignment_h,
+        AlignmentHorizontal::Right
+    );
+    assert_eq!(
+        grid.style(Entity::Cell(2, 3)).alignment_h,
+        AlignmentHorizontal::Right
+    );
+    assert_eq!(
+        grid.to_string(),
+        "+----+----+----+----+\n\
+         |long|long|long|long|\n\
+         +----+----+----+----+\n\
+         |1-0 |1-1 |1-2 |1-3 |\n\
+         +----+----+----+----+\n\
+         | 2-0|2-1 |2-2 | 2-3|\n\
+         +----+----+----+----+\n\
+         |3-0 |3-1 |3-2 |3-3 |\n\
+         +----+----+----+----+\n"
+    );
+}
+
+#[test]
+fn set_range_out_of_grid_test() {
+    let mut grid = util::new_grid::<2, 2>();
+    grid.set_range(1..10, 1.., Settings::new().text("x"));
+
+    assert_eq!(
+        grid.to_string(),
+        "+---+---+\n\
+         |0-0|0-1|\n\
+         +---+---+\n\
+         |1-0|x  |\n\
+         +---+---+\n"
+    );
+}
@@ -1,4 +1,6 @@
-use papergrid::{Entity, Grid, Settings};
+use papergrid::{AlignmentHorizontal, Entity, Grid, GridError, Indent, Padding, Settings};
+
+mod util;
 
 #[test]
 fn set_global_text_2x2() {
@@ -46,3 +48,250 @@ fn global_styles_overrides_everything_2x2() {
          +-----+----------+\n"
     );
 }
+
+#[test]
+fn from_rows_ragged() {
+    let grid = Grid::from_rows(vec![
+        vec!["a".to_owned()],
+        vec!["b".to_owned(), "bb".to_owned(), "bbb".to_owned()],
+        vec![],
+    ]);
+
+    assert_eq!(grid.count_rows(), 3);
+    assert_eq!(grid.count_columns(), 3);
+    assert_eq!(grid.get_cell_content(0, 2), "");
+    assert_eq!(grid.get_cell_content(1, 2), "bbb");
+    assert_eq!(
+        grid.to_string(),
+        "+-+--+---+\n\
+         |a|  |   |\n\
+         +-+--+---+\n\
+         |b|bb|bbb|\n\
+         +-+--+---+\n\
+         | |  |   |\n\
+         +-+--+---+\n"
+    )
+}
+
+#[test]
+fn from_rows_empty() {
+    let grid = Grid::from_rows(Vec::new());
+
+    assert_eq!(grid.count_rows(), 0);
+    assert_eq!(grid.count_columns(), 0);
+    assert_eq!(grid.to_string(), "");
+}
+
+#[test]
+fn try_set_out_of_bounds() {
+    let mut grid = util::new_grid::<2, 3>();
+    let expected = grid.to_string();
+
+    for entity in [
+        Entity::Cell(2, 0),
+        Entity::Cell(0, 3),
+        Entity::Row(2),
+        Entity::Column(3),
+    ] {
+        assert_eq!(
+            grid.try_set(entity, Settings::new().text("xxx")),
+            Err(GridError::EntityOutOfBounds(entity))
+        );
+        assert_eq!(
+            grid.try_set_text(entity, String::from("xxx")),
+            Err(GridError::EntityOutOfBounds(entity))
+        );
+    }
+
+    assert_eq!(grid.to_string(), expected);
+}
+
+#[test]
+fn try_set_in_bounds() {
+    let mut grid = Grid::new(2, 2);
+
+    assert_eq!(
+        grid.try_set(Entity::Global, Settings::new().text("a")),
+        Ok(())
+    );
+    assert_eq!(
+        grid.try_set(Entity::Row(1), Settings::new().text("b")),
+        Ok(())
+    );
+    assert_eq!(
+        grid.try_set_text(Entity::Column(1), String::from("c")),
+        Ok(())
+    );
+    assert_eq!(
+        grid.try_set_text(Entity::Cell(1, 1), String::from("d")),
+        Ok(())
+    );
+
+    assert_eq!(
+        grid.to_string(),
+        "+-+-+\n\
+         |a|c|\n\
+         +-+-+\n\
+         |b|d|\n\
+         +-+-+\n"
+    );
+}
+
+#[test]
+fn set_text_strips_lone_carriage_returns() {
+    let mut grid = util::new_grid::<1, 2>();
+    grid.set_text(Entity::Cell(0, 0), String::from("a\rb\r\nc\nd\r"));
+
+    assert_eq!(grid.get_cell_content(0, 0), "ab\r\nc\nd");
+    assert_eq!(
+        grid.to_string(),
+        "+--+---+\n\
+         |ab|0-1|\n\
+         |c |   |\n\
+         |d |   |\n\
+         +--+---+\n"
+    );
+}
+
+#[test]
+fn cell_keeps_column_alignment() {
+    let mut grid = util::new_grid::<2, 2>();
+    grid.set(Entity::Cell(0, 1), Settings::new().text("a long text"));
+    grid.set(
+        Entity::Column(1),
+        Settings::new().alignment(AlignmentHorizontal::Right),
+    );
+    grid.set(Entity::Cell(1, 1), Settings::new().text("1"));
+    grid.set(Entity::Cell(1, 0), Settings::new().span(1));
+
+    assert_eq!(
+        grid.to_string(),
+        "+---+-----------+\n\
+         |0-0|a long text|\n\
+         +---+-----------+\n\
+         |1-0|          1|\n\
+         +---+-----------+\n"
+    );
+
+    grid.set(Entity::Cell(1, 1), Settings::new().span(1));
+    grid.set(Entity::Row(1), Settings::new().text("2"));
+
+    assert_eq!(
+        grid.to_string(),
+        "+---+-----------+\n\
+         |0-0|a long text|\n\
+         +---+-----------+\n\
+         |2  |          2|\n\
+         +---+-----------+\n"
+    );
+}
+
+#[test]
+fn map_cells_test() {
+    let mut grid = util::new_grid::<2, 2>();
+    grid.set(
+        Entity::Column(1),
+        Settings::new().alignment(AlignmentHorizontal::Right),
+    );
+    grid.set(Entity::Cell(1, 1), Settings::new().text("1-1 text"));
+    grid.map_cells(|_, _, text| text.to_uppercase());
+
+    assert_eq!(
+        grid.to_string(),
+        "+---+--------+\n\
+         |0-0|     0-1|\n\
+         +---+--------+\n\
+         |1-0|1-1 TEXT|\n\
+         +---+--------+\n"
+    );
+
+    grid.map_cells(|_, col, text| {
+        if col == 1 {
+            String::new()
+        } else {
+            text.to_owned()
+        }
+    });
+
+    assert_eq!(grid.get_cell_content(1, 1), "");
+    assert_eq!(
+        grid.to_string(),
+        "+---++\n\
+         |0-0||\n\
+         +---++\n\
+         |1-0||\n\
+         +---++\n"
+    );
+}
+
+#[test]
+fn new_with_styles_capacity_parity() {
+    let fill = |mut grid: Grid| {
+        for row in 0..3 {
+            for col in 0..3 {
+                let mut settings = Settings::new().text(format!("{}-{}", row, col));
+                if row == col {
+                    settings = settings.alignment(AlignmentHorizontal::Right);
+                }
+
+                grid.set(Entity::Cell(row, col), settings);
+            }
+        }
+
+        grid.set(Entity::Cell(0, 0), Settings::new().text("a long cell"));
+        grid.set_text(Entity::Cell(2, 1), String::from("a long cell"));
+
+        grid.to_string()
+    };
+
+    let expected = fill(Grid::new(3, 3));
+
+    assert_eq!(fill(Grid::new_with_styles_capacity(3, 3, 9)), expected);
+    assert_eq!(fill(Grid::new_with_styles_capacity(3, 3, 0)), expected);
+    assert_eq!(
+        expected,
+        "+-----------+-----------+---+\n\
+         |a long cell|0-1        |0-2|\n\
+         +-----------+-----------+---+\n\
+         |1-0        |        1-1|1-2|\n\
+         +-----------+-----------+---+\n\
+         |2-0        |a long cell|2-2|\n\
+         +-----------+-----------+---+\n"
+    );
+}
+
+#[test]
+fn padding_uniform_test() {
+    let mut grid = Grid::new(1, 2);
+    grid.set(Entity::Cell(0, 0), Settings::new().padding_uniform(2));
+    grid.set(
+        Entity::Cell(0, 1),
+        Settings::new().padding(
+            Indent::spaced(2),
+            Indent::spaced(2),
+            Indent::spaced(2),
+            Indent::spaced(2),
+        ),
+    );
+
+    assert_eq!(
+        grid.style(Entity::Cell(0, 0)).padding,
+        grid.style(Entity::Cell(0, 1)).padding
+    );
+    assert_eq!(grid.style(Entity::Cell(0, 0)).padding, Padding::uniform(2));
+}
+
+#[test]
+fn padding_horizontal_and_vertical_test() {
+    let horizontal = Padding::horizontal(1);
+    assert_eq!(horizontal.left, Indent::spaced(1));
+    assert_eq!(horizontal.right, Indent::spaced(1));
+    assert_eq!(horizontal.top, Indent::spaced(0));
+    assert_eq!(horizontal.bottom, Indent::spaced(0));
+
+    let vertical = Padding::vertical(1);
+    assert_eq!(vertical.left, Indent::spaced(0));
+    assert_eq!(vertical.right, Indent::spaced(0));
+    assert_eq!(vertical.top, Indent::spaced(1));
+    assert_eq!(vertical.bottom, Indent::spaced(1));
+}
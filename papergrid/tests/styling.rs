@@ -1,4 +1,4 @@
-use papergrid::{AlignmentHorizontal, Border, Borders, Entity, Indent, Settings};
+use papergrid::{AlignmentHorizontal, Border, Borders, Entity, Indent, Settings, Side, Symbol};
 
 mod util;
 
@@ -350,6 +350,147 @@ fn grid_2x2_ansi_border_none_if_string_is_not_1_char_test() {
     assert!(Symbol::ansi("1".truecolor(0, 1, 3).on_truecolor(1, 2, 3).to_string()).is_some());
 }
 
+#[cfg(feature = "color")]
+#[test]
+fn grid_2x2_background_test() {
+    use owo_colors::OwoColorize;
+    use papergrid::{AlignmentVertical, Symbol};
+
+    let mut grid = util::new_grid::<2, 2>();
+    grid.set(Entity::Cell(0, 0), Settings::new().text("0-0\n0"));
+    grid.set(
+        Entity::Cell(0, 1),
+        Settings::new()
+            .padding(
+                Indent::spaced(1),
+                Indent::spaced(1),
+                Indent::default(),
+                Indent::default(),
+            )
+            .vertical_alignment(AlignmentVertical::Bottom)
+            .background(Symbol::ansi(" ".on_red().to_string()).unwrap()),
+    );
+
+    assert_eq!(
+        grid.to_string(),
+        "+---+-----+\n\
+         |0-0|\u{1b}[41m     \u{1b}[49m|\n\
+         |0  |\u{1b}[41m 0-1 \u{1b}[49m|\n\
+         +---+-----+\n\
+         |1-0|1-1  |\n\
+         +---+-----+\n"
+    );
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn grid_2x2_color_test() {
+    use papergrid::{Color, Symbol};
+
+    let red = Color::new("\u{1b}[31m", "\u{1b}[39m");
+    let blue = Color::new("\u{1b}[34m", "\u{1b}[39m");
+
+    let mut grid = util::new_grid::<2, 2>();
+    grid.set(
+        Entity::Cell(0, 0),
+        Settings::new()
+            .text("0-0\n0")
+            .color(blue)
+            .border(Border::default().left(Symbol::colored('*', red))),
+    );
+
+    assert_eq!(
+        grid.to_string(),
+        "+---+---+\n\
+         \u{1b}[31m*\u{1b}[39m\u{1b}[34m0-0\u{1b}[39m|0-1|\n\
+         \u{1b}[31m*\u{1b}[39m\u{1b}[34m0\u{1b}[39m  |   |\n\
+         +---+---+\n\
+         |1-0|1-1|\n\
+         +---+---+\n"
+    );
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn grid_2x2_color_is_kept_on_text_change_test() {
+    use papergrid::Color;
+
+    let blue = Color::new("\u{1b}[34m", "\u{1b}[39m");
+
+    let mut grid = util::new_grid::<2, 2>();
+    grid.set(Entity::Cell(0, 0), Settings::new().color(blue.clone()));
+    grid.set(Entity::Cell(0, 0), Settings::new().color(blue));
+    grid.set(Entity::Cell(0, 0), Settings::new().text("0-0\n0"));
+
+    assert_eq!(grid.get_cell_content(0, 0), "0-0\n0");
+    assert_eq!(
+        grid.to_string(),
+        "+---+---+\n\
+         |\u{1b}[34m0-0\u{1b}[39m|0-1|\n\
+         |\u{1b}[34m0\u{1b}[39m  |   |\n\
+         +---+---+\n\
+         |1-0|1-1|\n\
+         +---+---+\n"
+    );
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn grid_2x2_combining_mark_border_test() {
+    let e_acute = Symbol::ansi("e\u{301}".to_owned()).unwrap();
+
+    let mut grid = util::new_grid::<2, 2>();
+    grid.set(
+        Entity::Cell(0, 0),
+        Settings::new().border(Border::default().top(e_acute.clone())),
+    );
+
+    assert_eq!(e_acute.width(), 1);
+    assert!(!e_acute.same_glyph(&Symbol::from('e')));
+    assert_eq!(
+        grid.to_string(),
+        "+e\u{301}e\u{301}e\u{301}+---+\n\
+         |0-0|0-1|\n\
+         +---+---+\n\
+         |1-0|1-1|\n\
+         +---+---+\n"
+    );
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn grid_2x2_map_borders_color_test() {
+    let mut grid = util::new_grid::<2, 2>();
+    grid.set_borders(Borders::modern());
+    grid.set(
+        Entity::Cell(0, 0),
+        Settings::new().border(Border::default().left('*')),
+    );
+    grid.map_borders(|symbol| Symbol::ansi(format!("\u{1b}[31m{}\u{1b}[39m", symbol)).unwrap());
+
+    let red = |s: &str| {
+        s.chars()
+            .map(|c| format!("\u{1b}[31m{}\u{1b}[39m", c))
+            .collect::<String>()
+    };
+
+    assert_eq!(
+        grid.to_string(),
+        format!(
+            "{}\n{}0-0{}0-1{}\n{}\n{}1-0{}1-1{}\n{}\n",
+            red("┌───┬───┐"),
+            red("*"),
+            red("│"),
+            red("│"),
+            red("├───┼───┤"),
+            red("│"),
+            red("│"),
+            red("│"),
+            red("└───┴───┘"),
+        )
+    );
+}
+
 #[test]
 fn when_border_is_not_complet_default_char_is_used_test() {
     let mut grid = util::new_grid::<2, 2>();
@@ -383,3 +524,381 @@ fn when_1_vertical_is_set_second_must_use_default_test() {
          1-0*1-1\n"
     );
 }
+
+#[test]
+fn has_vertical_and_horizontal_psql_like_test() {
+    let mut grid = util::new_grid::<3, 3>();
+    grid.set_borders(Borders {
+        vertical_intersection: Some('|'.into()),
+        ..Default::default()
+    });
+    grid.set_split_line(
+        1,
+        papergrid::Line {
+            horizontal: Some('-'.into()),
+            intersection: Some('+'.into()),
+            ..Default::default()
+        },
+    );
+
+    assert_eq!(
+        grid.to_string(),
+        "0-0|0-1|0-2\n\
+         ---+---+---\n\
+         1-0|1-1|1-2\n\
+         2-0|2-1|2-2\n"
+    );
+
+    assert!(!grid.has_vertical(0));
+    assert!(grid.has_vertical(1));
+    assert!(grid.has_vertical(2));
+    assert!(!grid.has_vertical(3));
+
+    assert!(!grid.has_horizontal(0));
+    assert!(grid.has_horizontal(1));
+    assert!(!grid.has_horizontal(2));
+    assert!(!grid.has_horizontal(3));
+}
+
+#[test]
+fn remove_border_side_test() {
+    let mut grid = util::new_grid::<2, 2>();
+    grid.set(
+        Entity::Cell(0, 0),
+        Settings::new().border(Border::new('*', '*', '|', '|', '#', '#', '#', '#')),
+    );
+
+    assert_eq!(
+        grid.to_string(),
+        "#***#---+\n\
+         |0-0|0-1|\n\
+         #***#---+\n\
+         |1-0|1-1|\n\
+         +---+---+\n"
+    );
+
+    grid.remove_border_side(Entity::Cell(0, 0), Side::Top);
+
+    assert_eq!(
+        grid.to_string(),
+        "#---#---+\n\
+         |0-0|0-1|\n\
+         #***#---+\n\
+         |1-0|1-1|\n\
+         +---+---+\n"
+    );
+
+    grid.remove_border_side(Entity::Cell(0, 0), Side::TopLeftCorner);
+
+    assert_eq!(
+        grid.to_string(),
+        "+---#---+\n\
+         |0-0|0-1|\n\
+         #***#---+\n\
+         |1-0|1-1|\n\
+         +---+---+\n"
+    );
+}
+
+#[test]
+fn borders_presets_test() {
+    let render = |borders: Borders| {
+        let mut grid = util::new_grid::<2, 2>();
+        grid.set_borders(borders);
+        grid.to_string()
+    };
+
+    assert_eq!(
+        render(Borders::ascii()),
+        util::new_grid::<2, 2>().to_string()
+    );
+    assert_eq!(
+        render(Borders::modern()),
+        "┌───┬───┐\n\
+         │0-0│0-1│\n\
+         ├───┼───┤\n\
+         │1-0│1-1│\n\
+         └───┴───┘\n"
+    );
+    assert_eq!(
+        render(Borders::rounded()),
+        "╭───┬───╮\n\
+         │0-0│0-1│\n\
+         │1-0│1-1│\n\
+         ╰───┴───╯\n"
+    );
+    assert_eq!(
+        render(Borders::extended()),
+        "╔═══╦═══╗\n\
+         ║0-0║0-1║\n\
+         ╠═══╬═══╣\n\
+         ║1-0║1-1║\n\
+         ╚═══╩═══╝\n"
+    );
+    assert_eq!(render(Borders::psql()), "0-0|0-1\n1-0|1-1\n");
+    assert_eq!(
+        render(Borders::github_markdown()),
+        "|0-0|0-1|\n\
+         |1-0|1-1|\n"
+    );
+}
+
+#[test]
+fn span_hides_internal_intersections_test() {
+    let mut grid = util::new_grid::<3, 2>();
+    grid.set_borders(Borders::modern());
+    grid.set(Entity::Cell(0, 0), Settings::new().span(2));
+    grid.set(Entity::Cell(1, 0), Settings::new().span(2));
+
+    assert_eq!(
+        grid.to_string(),
+        "┌───────┐\n\
+         │0-0    │\n\
+         ├───────┤\n\
+         │1-0    │\n\
+         ├───┼───┤\n\
+         │2-0│2-1│\n\
+         └───┴───┘\n"
+    );
+}
+
+#[test]
+fn span_hides_internal_verticals_on_each_line_test() {
+    let mut grid = util::new_grid::<2, 3>();
+    grid.set_borders(Borders::modern());
+    grid.set(Entity::Cell(0, 0), Settings::new().text("a\nb\nc").span(3));
+    grid.set(
+        Entity::Cell(0, 1),
+        Settings::new().border(Border::default().left('*')),
+    );
+    grid.set(
+        Entity::Column(2),
+        Settings::new().border(Border::default().left('!')),
+    );
+    grid.set_row_spacing(0, 1);
+
+    assert_eq!(
+        grid.to_string(),
+        "┌───────────┐\n\
+         │a          │\n\
+         │b          │\n\
+         │c          │\n\
+         │           │\n\
+         ├───┼───┼───┤\n\
+         │1-0│1-1!1-2│\n\
+         └───┴───┴───┘\n"
+    );
+}
+
+#[test]
+fn empty_border_test() {
+    let mut grid = util::new_grid::<2, 2>();
+    grid.set(
+        Entity::Column(0),
+        Settings::new().border(Border::empty().right('|')),
+    );
+
+    assert_eq!(
+        grid.to_string(),
+        concat!(
+            "    ---+\n",
+            "0-0|0-1|\n",
+            "    ---+\n",
+            "1-0|1-1|\n",
+            "    ---+\n",
+        )
+    );
+
+    grid.set(Entity::Cell(0, 1), Settings::new().border(Border::empty()));
+
+    assert_eq!(
+        grid.to_string(),
+        concat!("0-0 0-1 \n", "1-0|1-1|\n", "    ---+\n")
+    );
+}
+
+#[test]
+fn split_line_with_cell_border_precedence_test() {
+    let mut grid = util::new_grid::<2, 2>();
+    grid.set_split_line(
+        1,
+        papergrid::Line {
+            horizontal: Some('='.into()),
+            intersection: Some('#'.into()),
+            left: Some('L'.into()),
+            right: Some('R'.into()),
+        },
+    );
+    grid.set(
+        Entity::Cell(0, 1),
+        Settings::new().border(Border::default().bottom('*').bottom_right_corner('@')),
+    );
+
+    assert_eq!(
+        grid.to_string(),
+        "+---+---+\n\
+         |0-0|0-1|\n\
+         L===#***@\n\
+         |1-0|1-1|\n\
+         +---+---+\n"
+    );
+
+    // the intersection is shared so each touching cell reports the same corner
+    assert_eq!(grid.get_border(0, 0).right_bottom_corner, Some('#'.into()));
+    assert_eq!(grid.get_border(0, 1).left_bottom_corner, Some('#'.into()));
+    assert_eq!(grid.get_border(1, 0).right_top_corner, Some('#'.into()));
+    assert_eq!(grid.get_border(1, 1).left_top_corner, Some('#'.into()));
+
+    assert_eq!(grid.get_border(0, 1).right_bottom_corner, Some('@'.into()));
+    assert_eq!(grid.get_border(1, 1).right_top_corner, Some('@'.into()));
+    assert_eq!(grid.get_border(1, 0).left_top_corner, Some('L'.into()));
+
+    assert_eq!(grid.get_border(1, 0).top, Some('='.into()));
+    assert_eq!(grid.get_border(1, 1).top, Some('*'.into()));
+}
+
+#[test]
+fn split_line_with_cell_border_corner_test() {
+    let mut grid = util::new_grid::<2, 2>();
+    grid.set(
+        Entity::Cell(1, 1),
+        Settings::new().border(Border::default().top_left_corner('@')),
+    );
+    grid.set_split_line(
+        1,
+        papergrid::Line {
+            horizontal: Some('='.into()),
+            intersection: Some('#'.into()),
+            ..Default::default()
+        },
+    );
+
+    assert_eq!(
+        grid.to_string(),
+        "+---+---+\n\
+         |0-0|0-1|\n\
+         +===@===+\n\
+         |1-0|1-1|\n\
+         +---+---+\n"
+    );
+
+    assert_eq!(grid.get_border(0, 0).right_bottom_corner, Some('@'.into()));
+    assert_eq!(grid.get_border(0, 0).left_bottom_corner, Some('+'.into()));
+}
+
+#[test]
+fn intersection_resolver_test() {
+    let is_heavy =
+        |c: &Option<Symbol>| *c == Some(Symbol::from('━')) || *c == Some(Symbol::from('┃'));
+
+    let mut grid = util::new_grid::<2, 2>();
+    grid.set_borders(Borders {
+        top: Some(Symbol::from('─')),
+        bottom: Some(Symbol::from('─')),
+        horizontal: Some(Symbol::from('━')),
+        vertical_left: Some(Symbol::from('│')),
+        vertical_intersection: Some(Symbol::from('┃')),
+        vertical_right: Some(Symbol::from('│')),
+        ..Default::default()
+    });
+    grid.set_intersection_resolver(move |sides| {
+        let c = match (
+            sides.up.is_some(),
+            sides.down.is_some(),
+            sides.left.is_some(),
+            sides.right.is_some(),
+        ) {
+            (false, true, false, true) => '┌',
+            (false, true, true, false) => '┐',
+            (true, false, false, true) => '└',
+            (true, false, true, false) => '┘',
+            (false, true, true, true) if is_heavy(&sides.down) => '┰',
+            (true, false, true, true) if is_heavy(&sides.up) => '┸',
+            (true, true, false, true) if is_heavy(&sides.right) => '┝',
+            (true, true, true, false) if is_heavy(&sides.left) => '┥',
+            (true, true, true, true) => '╋',
+            _ => '?',
+        };
+
+        Symbol::from(c)
+    });
+
+    assert_eq!(
+        grid.to_string(),
+        "┌───┰───┐\n\
+         │0-0┃0-1│\n\
+         ┝━━━╋━━━┥\n\
+         │1-0┃1-1│\n\
+         └───┸───┘\n"
+    );
+
+    // an explicit intersection is not resolved
+    grid.set(
+        Entity::Cell(0, 0),
+        Settings::new().border(Border::default().bottom_right_corner('*')),
+    );
+
+    assert_eq!(
+        grid.to_string(),
+        "┌───┰───┐\n\
+         │0-0┃0-1│\n\
+         ┝━━━*━━━┥\n\
+         │1-0┃1-1│\n\
+         └───┸───┘\n"
+    );
+
+    grid.remove_intersection_resolver();
+
+    assert_eq!(
+        grid.to_string(),
+        concat!(
+            " ─── ─── \n",
+            "│0-0┃0-1│\n",
+            " ━━━*━━━ \n",
+            "│1-0┃1-1│\n",
+            " ─── ─── \n",
+        )
+    );
+}
+
+#[test]
+fn grid_2x2_wide_horizontal_border_odd_width_test() {
+    let mut grid = util::new_grid::<2, 2>();
+    grid.set(Entity::Cell(0, 1), Settings::new().text("0-1--"));
+    grid.set(
+        Entity::Global,
+        Settings::new().border(Border::default().top('中').bottom('中')),
+    );
+
+    assert_eq!(
+        grid.to_string(),
+        "+中 +中中 +\n\
+         |0-0|0-1--|\n\
+         +中 +中中 +\n\
+         |1-0|1-1  |\n\
+         +中 +中中 +\n"
+    );
+}
+
+#[test]
+fn grid_2x2_wide_padding_fill_odd_width_test() {
+    let mut grid = util::new_grid::<2, 2>();
+    grid.set(
+        Entity::Cell(0, 0),
+        Settings::new().padding(
+            Indent::new(3, '中'),
+            Indent::default(),
+            Indent::default(),
+            Indent::default(),
+        ),
+    );
+
+    assert_eq!(
+        grid.to_string(),
+        "+------+---+\n\
+         |中 0-0|0-1|\n\
+         +------+---+\n\
+         |1-0   |1-1|\n\
+         +------+---+\n"
+    );
+}
@@ -0,0 +1,226 @@
+use papergrid::{AlignmentHorizontal, Border, Entity, Settings};
+
+mod util;
+
+#[test]
+fn swap_rows_test() {
+    let mut grid = util::new_grid::<3, 2>();
+    grid.set(Entity::Cell(0, 0), Settings::new().text("a long cell"));
+    grid.set(
+        Entity::Row(0),
+        Settings::new().alignment(AlignmentHorizontal::Center),
+    );
+    grid.set(
+        Entity::Row(2),
+        Settings::new().alignment(AlignmentHorizontal::Right),
+    );
+
+    assert_eq!(
+        grid.to_string(),
+        "+-----------+---+\n\
+         |a long cell|0-1|\n\
+         +-----------+---+\n\
+         |1-0        |1-1|\n\
+         +-----------+---+\n\
+         |        2-0|2-1|\n\
+         +-----------+---+\n"
+    );
+
+    grid.swap_rows(0, 2);
+
+    assert_eq!(
+        grid.to_string(),
+        "+-----------+---+\n\
+         |        2-0|2-1|\n\
+         +-----------+---+\n\
+         |1-0        |1-1|\n\
+         +-----------+---+\n\
+         |a long cell|0-1|\n\
+         +-----------+---+\n"
+    );
+
+    assert_eq!(
+        grid.style(Entity::Row(0)).alignment_h,
+        AlignmentHorizontal::Right
+    );
+    assert_eq!(
+        grid.style(Entity::Row(2)).alignment_h,
+        AlignmentHorizontal::Center
+    );
+}
+
+#[test]
+fn swap_rows_keeps_cell_styles_and_spans_test() {
+    let mut grid = util::new_grid::<2, 3>();
+    grid.set(
+        Entity::Cell(0, 0),
+        Settings::new()
+            .span(2)
+            .alignment(AlignmentHorizontal::Right),
+    );
+    grid.set(Entity::Cell(1, 0), Settings::new().text("a long cell"));
+    grid.set(
+        Entity::Cell(0, 2),
+        Settings::new().border(Border::default().left('*').right('*')),
+    );
+
+    grid.swap_rows(1, 0);
+
+    assert_eq!(
+        grid.to_string(),
+        "+-----------+---+---+\n\
+         |a long cell|1-1|1-2|\n\
+         +-----------+---+---+\n\
+         |            0-0*0-2*\n\
+         +---------------+---+\n"
+    );
+}
+
+#[test]
+fn swap_columns_test() {
+    let mut grid = util::new_grid::<2, 3>();
+    grid.set(
+        Entity::Column(0),
+        Settings::new().alignment(AlignmentHorizontal::Right),
+    );
+    grid.set(Entity::Cell(0, 0), Settings::new().text("a long cell"));
+    grid.set(
+        Entity::Cell(1, 2),
+        Settings::new().border(Border::default().top('*')),
+    );
+
+    grid.swap_columns(0, 2);
+
+    assert_eq!(
+        grid.to_string(),
+        "+---+---+-----------+\n\
+         |0-2|0-1|a long cell|\n\
+         +***+---+-----------+\n\
+         |1-2|1-1|        1-0|\n\
+         +---+---+-----------+\n"
+    );
+
+    assert_eq!(
+        grid.style(Entity::Column(2)).alignment_h,
+        AlignmentHorizontal::Right
+    );
+}
+
+#[test]
+fn swap_adjacent_rows_moves_cell_borders_test() {
+    let mut grid = util::new_grid::<3, 2>();
+    grid.set(Entity::Row(1), Settings::new().border(Border::filled('*')));
+
+    grid.swap_rows(1, 2);
+
+    assert_eq!(
+        grid.to_string(),
+        "+---+---+\n\
+         |0-0|0-1|\n\
+         +---+---+\n\
+         |2-0|2-1|\n\
+         *********\n\
+         *1-0*1-1*\n\
+         *********\n"
+    );
+
+    grid.swap_rows(2, 1);
+
+    assert_eq!(
+        grid.to_string(),
+        "+---+---+\n\
+         |0-0|0-1|\n\
+         *********\n\
+         *1-0*1-1*\n\
+         *********\n\
+         |2-0|2-1|\n\
+         +---+---+\n"
+    );
+}
+
+#[test]
+fn swap_rows_moves_cell_borders_test() {
+    let mut grid = util::new_grid::<3, 2>();
+    grid.set(
+        Entity::Cell(0, 1),
+        Settings::new().border(Border::filled('*')),
+    );
+
+    grid.swap_rows(0, 2);
+
+    assert_eq!(
+        grid.to_string(),
+        "+---+---+\n\
+         |2-0|2-1|\n\
+         +---+---+\n\
+         |1-0|1-1|\n\
+         +---*****\n\
+         |0-0*0-1*\n\
+         +---*****\n"
+    );
+}
+
+#[test]
+fn swap_columns_moves_cell_borders_test() {
+    let mut grid = util::new_grid::<2, 3>();
+    grid.set(
+        Entity::Column(0),
+        Settings::new().border(Border::filled('*')),
+    );
+
+    grid.swap_columns(0, 1);
+
+    assert_eq!(
+        grid.to_string(),
+        "+---*****---+\n\
+         |0-1*0-0*0-2|\n\
+         +---*****---+\n\
+         |1-1*1-0*1-2|\n\
+         +---*****---+\n"
+    );
+}
+
+#[test]
+fn swap_columns_moves_spans_test() {
+    let mut grid = util::new_grid::<2, 4>();
+    grid.set(
+        Entity::Cell(0, 0),
+        Settings::new().text("a spanned cell").span(2),
+    );
+
+    grid.swap_columns(0, 2);
+
+    assert_eq!(
+        grid.to_string(),
+        "+---+---+--------------+\n\
+         |0-2|0-1|a spanned cell|\n\
+         +---+---+-------+------+\n\
+         |1-2|1-1|1-0    |1-3   |\n\
+         +---+---+-------+------+\n"
+    );
+
+    grid.swap_columns(2, 3);
+
+    assert_eq!(
+        grid.to_string(),
+        "+---+---+---+--------------+\n\
+         |0-2|0-1|0-3|a spanned cell|\n\
+         +---+---+---+--------------+\n\
+         |1-2|1-1|1-3|1-0           |\n\
+         +---+---+---+--------------+\n"
+    );
+}
+
+#[test]
+#[should_panic]
+fn swap_rows_out_of_bounds_test() {
+    let mut grid = util::new_grid::<2, 2>();
+    grid.swap_rows(0, 2);
+}
+
+#[test]
+#[should_panic]
+fn swap_columns_out_of_bounds_test() {
+    let mut grid = util::new_grid::<2, 2>();
+    grid.swap_columns(3, 0);
+}
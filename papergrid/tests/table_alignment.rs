@@ -0,0 +1,92 @@
+use papergrid::{AlignmentHorizontal, Entity, Grid, Indent, Margin, Settings};
+
+// a grid which is 20 columns wide
+fn grid() -> Grid {
+    let mut grid = Grid::new(1, 2);
+    grid.set(Entity::Cell(0, 0), Settings::new().text("12345678"));
+    grid.set(Entity::Cell(0, 1), Settings::new().text("123456789"));
+    grid
+}
+
+#[test]
+fn table_alignment_center_test() {
+    let mut grid = grid();
+    assert_eq!(grid.total_width(), 20);
+
+    grid.set_table_alignment(AlignmentHorizontal::Center, 40);
+
+    assert_eq!(grid.get_margin().left.size, 10);
+    assert_eq!(grid.get_margin().right.size, 10);
+    assert_eq!(grid.total_width(), 40);
+    assert_eq!(
+        grid.to_string(),
+        concat!(
+            "          +--------+---------+          \n",
+            "          |12345678|123456789|          \n",
+            "          +--------+---------+          \n",
+        )
+    );
+}
+
+#[test]
+fn table_alignment_center_odd_test() {
+    let mut grid = grid();
+
+    grid.set_table_alignment(AlignmentHorizontal::Center, 41);
+
+    assert_eq!(grid.get_margin().left.size, 10);
+    assert_eq!(grid.get_margin().right.size, 11);
+
+    grid.set_table_alignment(AlignmentHorizontal::CenterLeftBias, 41);
+
+    assert_eq!(grid.get_margin().left.size, 11);
+    assert_eq!(grid.get_margin().right.size, 10);
+    assert_eq!(grid.total_width(), 41);
+}
+
+#[test]
+fn table_alignment_left_and_right_test() {
+    let mut grid = grid();
+
+    grid.set_table_alignment(AlignmentHorizontal::Right, 25);
+
+    assert_eq!(grid.get_margin().left.size, 5);
+    assert_eq!(grid.get_margin().right.size, 0);
+
+    grid.set_table_alignment(AlignmentHorizontal::Left, 25);
+
+    assert_eq!(grid.get_margin().left.size, 0);
+    assert_eq!(grid.get_margin().right.size, 5);
+}
+
+#[test]
+fn table_alignment_keeps_margin_fill_test() {
+    let mut grid = grid();
+    grid.margin(Margin {
+        top: Indent::default(),
+        bottom: Indent::default(),
+        left: Indent::new(1, '>'),
+        right: Indent::new(1, '<'),
+    });
+
+    grid.set_table_alignment(AlignmentHorizontal::Center, 24);
+
+    assert_eq!(
+        grid.to_string(),
+        concat!(
+            ">>+--------+---------+<<\n",
+            ">>|12345678|123456789|<<\n",
+            ">>+--------+---------+<<\n",
+        )
+    );
+}
+
+#[test]
+fn table_alignment_narrow_width_test() {
+    let mut grid = grid();
+    grid.set_table_alignment(AlignmentHorizontal::Center, 10);
+
+    assert_eq!(grid.get_margin().left.size, 0);
+    assert_eq!(grid.get_margin().right.size, 0);
+    assert_eq!(grid.total_width(), 20);
+}
@@ -48,6 +48,8 @@
 //! [Table]: crate::Table
 //! [AlignmentStrategy]: crate::formatting_settings::AlignmentStrategy
 
+use std::{cmp, collections::HashMap};
+
 use crate::CellOption;
 use papergrid::{Entity, Grid, Settings};
 
@@ -66,6 +68,17 @@ pub use papergrid::{AlignmentHorizontal, AlignmentVertical};
 pub enum Alignment {
     Horizontal(AlignmentHorizontal),
     Vertical(AlignmentVertical),
+    /// Aligns numbers in a column by a decimal point.
+    ///
+    /// Only numeric cells of the column targeted by the same [Modify] are taken into account,
+    /// so it must be the only option set on the [Modify].
+    /// Not numeric cells are aligned to the right.
+    ///
+    /// Notice that the content of a numeric cell is rewritten,
+    /// it's padded by spaces so the decimal points are lined up.
+    ///
+    /// [Modify]: crate::Modify
+    Decimal,
 }
 
 impl Alignment {
@@ -100,6 +113,36 @@ impl Alignment {
         Self::horizontal(AlignmentHorizontal::Center)
     }
 
+    /// Center_left_bias constructs a horizontal alignment to [AlignmentHorizontal::CenterLeftBias]
+    pub fn center_left_bias() -> Self {
+        Self::horizontal(AlignmentHorizontal::CenterLeftBias)
+    }
+
+    /// Decimal constructs an alignment by a decimal point, see [Alignment::Decimal].
+    ///
+    /// ```
+    /// use tabled::{object::Rows, Alignment, Modify, Style, Table};
+    ///
+    /// let table = Table::new(["1.5", "12.25", "100"])
+    ///     .with(Style::psql())
+    ///     .with(Modify::new(Rows::new(1..)).with(Alignment::decimal()))
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "  &str  \n",
+    ///         "--------\n",
+    ///         "   1.5  \n",
+    ///         "  12.25 \n",
+    ///         " 100    \n",
+    ///     )
+    /// );
+    /// ```
+    pub fn decimal() -> Self {
+        Self::Decimal
+    }
+
     /// Top constructs a vertical alignment to [AlignmentVertical::Top]
     pub fn top() -> Self {
         Self::vertical(AlignmentVertical::Top)
@@ -115,6 +158,11 @@ impl Alignment {
         Self::vertical(AlignmentVertical::Center)
     }
 
+    /// Justify_vertical constructs a vertical alignment to [AlignmentVertical::Justify]
+    pub fn justify_vertical() -> Self {
+        Self::vertical(AlignmentVertical::Justify)
+    }
+
     /// Returns an alignment with the given horizontal alignment.
     fn horizontal(alignment: AlignmentHorizontal) -> Self {
         Self::Horizontal(alignment)
@@ -128,11 +176,81 @@ impl Alignment {
 
 impl CellOption for Alignment {
     fn change_cell(&mut self, grid: &mut Grid, row: usize, column: usize) {
+        self.change_cells(grid, &[(row, column)]);
+    }
+
+    fn change_cells(&mut self, grid: &mut Grid, cells: &[(usize, usize)]) {
         let settings = match &self {
             Self::Horizontal(a) => Settings::new().alignment(*a),
             Self::Vertical(a) => Settings::new().vertical_alignment(*a),
+            Self::Decimal => {
+                align_decimal(grid, cells);
+                return;
+            }
         };
 
-        grid.set(Entity::Cell(row, column), settings);
+        for &(row, column) in cells {
+            grid.set(Entity::Cell(row, column), settings.clone());
+        }
     }
 }
+
+/// Pads numbers so their decimal points are on the same position
+/// as in other given numbers in the column.
+///
+/// Not numeric cells are only aligned to the right.
+fn align_decimal(grid: &mut Grid, cells: &[(usize, usize)]) {
+    // the widths are calculated before any cell is changed
+    let mut widths: HashMap<usize, (usize, usize)> = HashMap::new();
+    for &(row, column) in cells {
+        if let Some((int, fraction)) = split_decimal(grid.get_cell_content(row, column)) {
+            let (int_width, fraction_width) = widths.entry(column).or_default();
+            *int_width = cmp::max(*int_width, int.len());
+            *fraction_width = cmp::max(*fraction_width, fraction.len());
+        }
+    }
+
+    for &(row, column) in cells {
+        let settings = Settings::new().alignment(AlignmentHorizontal::Right);
+        let (int, fraction) = match split_decimal(grid.get_cell_content(row, column)) {
+            Some(number) => number,
+            None => {
+                grid.set(Entity::Cell(row, column), settings);
+                continue;
+            }
+        };
+
+        let (int_width, fraction_width) = widths[&column];
+        let text = format!(
+            "{:>int_width$}{:<fraction_width$}",
+            int,
+            fraction,
+            int_width = int_width,
+            fraction_width = fraction_width
+        );
+
+        // the spaces after a fractional part must not be trimmed
+        let mut formatting = grid.style(Entity::Cell(row, column)).formatting;
+        formatting.horizontal_trim = false;
+
+        grid.set(
+            Entity::Cell(row, column),
+            settings.text(text).formatting(formatting),
+        );
+    }
+}
+
+/// Splits a number into an integer part and a fractional one including a decimal point.
+fn split_decimal(text: &str) -> Option<(&str, &str)> {
+    let text = text.trim();
+    let digits = text.strip_prefix(|c| c == '-' || c == '+').unwrap_or(text);
+    let is_number = digits.chars().any(|c| c.is_ascii_digit())
+        && digits.chars().all(|c| c.is_ascii_digit() || c == '.')
+        && digits.matches('.').count() <= 1;
+    if !is_number {
+        return None;
+    }
+
+    let point = text.find('.').unwrap_or(text.len());
+    Some(text.split_at(point))
+}
@@ -202,6 +202,53 @@ impl Builder {
         self
     }
 
+    /// Sets a [Table] header in place.
+    ///
+    /// It's the same as [Self::set_columns] but doesn't consume the builder,
+    /// which is handy when rows are pushed in a loop.
+    ///
+    /// ```rust
+    /// use tabled::builder::Builder;
+    /// let mut builder = Builder::default();
+    /// builder.set_header(["i", "surname", "lastname"]);
+    /// ```
+    pub fn set_header<H, T>(&mut self, columns: H) -> &mut Self
+    where
+        H: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        let columns: Vec<String> = columns.into_iter().map(Into::into).collect();
+        self.update_size(columns.len());
+        self.columns = Some(columns);
+
+        self
+    }
+
+    /// Adds a row to a [Table] in place.
+    ///
+    /// It's the same as [Self::add_record] but doesn't consume the builder,
+    /// which is handy when rows are pushed in a loop.
+    /// Rows with different length are padded to the longest one on [Self::build].
+    ///
+    /// ```rust
+    /// use tabled::builder::Builder;
+    /// let mut builder = Builder::default();
+    /// for i in 0..3 {
+    ///     builder.push_record([i.to_string()]);
+    /// }
+    /// ```
+    pub fn push_record<R, T>(&mut self, record: R) -> &mut Self
+    where
+        R: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        let row: Vec<String> = record.into_iter().map(Into::into).collect();
+        self.update_size(row.len());
+        self.records.push(row);
+
+        self
+    }
+
     /// Sets a content of cells which are created in case rows has different length.
     ///
     ///
@@ -447,7 +494,10 @@ fn build_grid(
 }
 
 fn create_table_from_grid(grid: Grid) -> Table {
-    let mut table = Table { grid };
+    let mut table = Table {
+        grid,
+        has_header: true,
+    };
 
     // it's crusial to set a global setting rather than a setting for an each cell
     // as it will be hard to override that since how Grid::style method works
@@ -469,7 +519,9 @@ fn default_cell_style() -> Settings {
             tab_width: 4,
             horizontal_trim: true,
             allow_lines_alignement: false,
+            allow_column_alignment: false,
             vertical_trim: false,
+            rtl: false,
         })
 }
 
@@ -0,0 +1,87 @@
+//! This module contains a [Caption] setting which prints a text
+//! above or below a [Table], outside of its frame.
+//!
+//! Unlike a [Panel] it's not a part of the [Table] itself.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{AlignmentHorizontal, Caption, Style, TableIteratorExt};
+//!
+//! let data = [[1, 2, 3], [4, 5, 6]];
+//!
+//! let table = data.table()
+//!     .with(Style::psql())
+//!     .with(Caption::below("Numbers").alignment(AlignmentHorizontal::Right))
+//!     .to_string();
+//!
+//! assert_eq!(
+//!     table,
+//!     concat!(
+//!         " 0 | 1 | 2 \n",
+//!         "---+---+---\n",
+//!         " 1 | 2 | 3 \n",
+//!         " 4 | 5 | 6 \n",
+//!         "    Numbers\n",
+//!     )
+//! )
+//! ```
+//!
+//! [Table]: crate::Table
+//! [Panel]: crate::Panel
+
+use crate::TableOption;
+use papergrid::{AlignmentHorizontal, CaptionPosition, Grid};
+
+/// Caption prints a text above or below a [Table], outside of its frame.
+///
+/// The text is aligned within the width of the [Table] and each of its lines is aligned separately.
+/// By default it's aligned to the left.
+///
+/// [Table]: crate::Table
+#[derive(Debug)]
+pub struct Caption<S: AsRef<str>> {
+    text: S,
+    position: CaptionPosition,
+    alignment: AlignmentHorizontal,
+}
+
+impl<S: AsRef<str>> Caption<S> {
+    /// Creates a [Caption] printed above a [Table].
+    ///
+    /// [Table]: crate::Table
+    pub fn above(text: S) -> Self {
+        Self::new(text, CaptionPosition::Above)
+    }
+
+    /// Creates a [Caption] printed below a [Table].
+    ///
+    /// [Table]: crate::Table
+    pub fn below(text: S) -> Self {
+        Self::new(text, CaptionPosition::Below)
+    }
+
+    /// Sets an alignment of the [Caption].
+    pub fn alignment(mut self, alignment: AlignmentHorizontal) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    fn new(text: S, position: CaptionPosition) -> Self {
+        Self {
+            text,
+            position,
+            alignment: AlignmentHorizontal::Left,
+        }
+    }
+}
+
+impl<S: AsRef<str>> TableOption for Caption<S> {
+    fn change(&mut self, grid: &mut Grid) {
+        grid.set_caption(papergrid::Caption {
+            text: self.text.as_ref().to_owned(),
+            position: self.position,
+            alignment: self.alignment,
+        });
+    }
+}
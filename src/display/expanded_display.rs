@@ -12,6 +12,7 @@ use crate::Tabled;
 /// Because of that `colors` may not be rendered.
 pub struct ExpandedDisplay {
     format_record_splitter: Option<fn(usize) -> String>,
+    #[allow(clippy::type_complexity)]
     format_value: Option<Box<dyn Fn(&str) -> String>>,
     fields: Vec<String>,
     records: Vec<Vec<String>>,
@@ -9,6 +9,7 @@
 use std::ops::{RangeBounds, RangeFull};
 
 use crate::TableOption;
+use papergrid::{Entity, Settings};
 
 /// Returns a new [Table] that reflects a segment of the referenced [Table]
 ///
@@ -156,3 +157,50 @@ where
         *grid = grid.extract(self.rows.clone(), self.columns.clone());
     }
 }
+
+/// Head keeps a header and the first `N` rows of a [Table].
+///
+/// If any rows were removed a row with a single `…` cell is added at the end.
+///
+/// Like other options which change a [Table] layout it's better to be applied before a [Style].
+///
+/// ```rust
+/// use tabled::{Head, Style, TableIteratorExt};
+///
+/// let data = [1, 2, 3];
+/// let table = data.table().with(Head(1)).with(Style::psql()).to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         " i32 \n",
+///         "-----\n",
+///         "  1  \n",
+///         "  …  \n",
+///     )
+/// );
+/// ```
+///
+/// [Table]: crate::Table
+/// [Style]: crate::Style
+#[derive(Debug, Clone, Copy)]
+pub struct Head(pub usize);
+
+impl TableOption for Head {
+    fn change(&mut self, grid: &mut papergrid::Grid) {
+        let count_rows = self.0 + 1;
+        if count_rows >= grid.count_rows() || grid.count_columns() == 0 {
+            return;
+        }
+
+        // the next row is kept to be reused as an ellipsis row so its style is preserved
+        let mut head = grid.extract(..count_rows + 1, ..);
+        head.set(Entity::Row(count_rows), Settings::new().text(""));
+        head.set(
+            Entity::Cell(count_rows, 0),
+            Settings::new().text("…").span(head.count_columns()),
+        );
+
+        *grid = head;
+    }
+}
@@ -167,3 +167,77 @@ where
         grid.set(Entity::Cell(row, column), Settings::new().text(content))
     }
 }
+
+/// Colorize wraps a cell content in an ANSI sequence chosen by a function.
+///
+/// The function gets a content of a cell and returns a sequence to be used,
+/// or `None` if the cell must be left as it is.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{Table, Colorize, object::Segment, Modify, Style};
+///
+/// let data = [[1, -2], [-3, 4]];
+///
+/// let table = Table::new(&data)
+///     .with(Style::psql())
+///     .with(Modify::new(Segment::all()).with(Colorize::new(|s| {
+///         if s.starts_with('-') {
+///             Some(String::from("\u{1b}[31m"))
+///         } else {
+///             None
+///         }
+///     })))
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         " 0  | 1  \n",
+///         "----+----\n",
+///         " 1  | \u{1b}[31m-2\u{1b}[0m \n",
+///         " \u{1b}[31m-3\u{1b}[0m | 4  \n",
+///     )
+/// );
+/// ```
+#[cfg(feature = "color")]
+pub struct Colorize<F> {
+    f: F,
+}
+
+#[cfg(feature = "color")]
+impl<F> Colorize<F>
+where
+    F: Fn(&str) -> Option<String>,
+{
+    /// Creates a new [Colorize] instance.
+    pub fn new(f: F) -> Self {
+        Self { f }
+    }
+}
+
+#[cfg(feature = "color")]
+impl<F> CellOption for Colorize<F>
+where
+    F: Fn(&str) -> Option<String>,
+{
+    fn change_cell(&mut self, grid: &mut Grid, row: usize, column: usize) {
+        const RESET: &str = "\u{1b}[0m";
+
+        let content = grid.get_cell_content(row, column);
+        let color = match (self.f)(content) {
+            Some(color) => color,
+            None => return,
+        };
+
+        // each line is wrapped separately so the sequence doesn't leak onto borders.
+        let content = content
+            .lines()
+            .map(|line| format!("{}{}{}", color, line, RESET))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        grid.set(Entity::Cell(row, column), Settings::new().text(content))
+    }
+}
@@ -6,7 +6,7 @@
 //!
 //! [Alignment]: crate::Alignment
 
-use papergrid::{Entity, Grid, Settings};
+use papergrid::{AnsiPart, Entity, Grid, Settings};
 
 use crate::CellOption;
 
@@ -16,6 +16,9 @@ use crate::CellOption;
 ///
 /// Default value is 4 (basically 1 '\t' equals 4 spaces).
 ///
+/// Only a tab width of a targeted cells is changed,
+/// so it can be set per column or a cell without affecting other formatting settings.
+///
 /// IMPORTANT: The tab character might be not present in output,
 /// it might be replaced by spaces.
 #[derive(Debug, Default, Clone)]
@@ -137,14 +140,31 @@ pub enum AlignmentStrategy {
     PerCell,
     /// Apply alignment for each line of a cell content as a whole.
     PerLine,
+    /// Apply alignment for cell content as a whole,
+    /// against the widest line of the column rather than of the cell.
+    ///
+    /// Only the cells with the same strategy are considered,
+    /// so their lines start at the same position.
+    /// Spanned cells are aligned as with [AlignmentStrategy::PerCell].
+    PerColumn,
 }
 
 impl CellOption for AlignmentStrategy {
     fn change_cell(&mut self, grid: &mut Grid, row: usize, column: usize) {
         let mut formatting = grid.style(Entity::Cell(row, column)).formatting;
         match &self {
-            AlignmentStrategy::PerCell => formatting.allow_lines_alignement = false,
-            AlignmentStrategy::PerLine => formatting.allow_lines_alignement = true,
+            AlignmentStrategy::PerCell => {
+                formatting.allow_lines_alignement = false;
+                formatting.allow_column_alignment = false;
+            }
+            AlignmentStrategy::PerLine => {
+                formatting.allow_lines_alignement = true;
+                formatting.allow_column_alignment = false;
+            }
+            AlignmentStrategy::PerColumn => {
+                formatting.allow_lines_alignement = false;
+                formatting.allow_column_alignment = true;
+            }
         }
 
         grid.set(
@@ -225,6 +245,13 @@ pub enum TrimStrategy {
     Horizontal,
     /// Allow horizontal and vertical trim.
     Both,
+    /// Allow horizontal and vertical trim,
+    /// and collapse runs of whitespace inside each line into a single space.
+    ///
+    /// Unlike the other strategies it changes the cell content,
+    /// so the lines get trimmed regardless of [AlignmentStrategy].
+    /// ANSI escape sequences are kept untouched.
+    Collapse,
     /// Doesn't allow any trim.
     None,
 }
@@ -244,6 +271,13 @@ impl CellOption for TrimStrategy {
                 formatting.vertical_trim = true;
                 formatting.horizontal_trim = true;
             }
+            TrimStrategy::Collapse => {
+                formatting.vertical_trim = true;
+                formatting.horizontal_trim = true;
+
+                let content = collapse_whitespace(grid.get_cell_content(row, column));
+                grid.set(Entity::Cell(row, column), Settings::new().text(content));
+            }
             TrimStrategy::None => {
                 formatting.vertical_trim = false;
                 formatting.horizontal_trim = false;
@@ -256,3 +290,46 @@ impl CellOption for TrimStrategy {
         )
     }
 }
+
+/// Trims each line and replaces runs of whitespace inside it by a single space.
+///
+/// ANSI escape sequences are copied as is and don't break a whitespace run.
+fn collapse_whitespace(text: &str) -> String {
+    let mut buf = String::with_capacity(text.len());
+    let mut is_line_start = true;
+    let mut has_space = false;
+
+    for part in papergrid::ansi_parts(text) {
+        let text = match part {
+            AnsiPart::Escape(escape) => {
+                buf.push_str(escape);
+                continue;
+            }
+            AnsiPart::Text(text) => text,
+        };
+
+        for c in text.chars() {
+            match c {
+                '\n' => {
+                    buf.push(c);
+                    is_line_start = true;
+                    has_space = false;
+                }
+                c if c.is_whitespace() => {
+                    has_space = !is_line_start;
+                }
+                c => {
+                    if has_space {
+                        buf.push(' ');
+                        has_space = false;
+                    }
+
+                    buf.push(c);
+                    is_line_start = false;
+                }
+            }
+        }
+    }
+
+    buf
+}
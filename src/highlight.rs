@@ -104,6 +104,9 @@ where
 {
     /// Build a new instance of [Highlight]
     ///
+    /// Highlighting is additive: only the sides set in the [Border] are changed,
+    /// the ones left as `None` keep whatever was set before.
+    ///
     /// BE AWARE: if target exceeds boundaries it may panic.
     pub fn new(target: O, border: Border) -> Self {
         Self { target, border }
@@ -156,15 +156,21 @@
 )]
 
 mod alignment;
+mod caption;
 mod concat;
 mod disable;
 mod extract;
 mod formating;
 mod highlight;
 mod margin;
+mod merge;
 mod padding;
 mod panel;
+mod repeat_header;
 mod rotate;
+mod row_number;
+mod sort;
+mod spacing;
 mod span;
 mod table;
 
@@ -182,8 +188,9 @@ pub use tabled_derive::Tabled;
 pub use papergrid;
 
 pub use crate::{
-    alignment::*, concat::*, disable::*, extract::*, formating::*, highlight::*, margin::*,
-    padding::*, panel::*, rotate::*, span::*, style::Style, table::*, width::Width,
+    alignment::*, caption::*, concat::*, disable::*, extract::*, formating::*, highlight::*,
+    margin::*, merge::*, padding::*, panel::*, repeat_header::*, rotate::*, row_number::*, sort::*,
+    spacing::*, span::*, style::Style, table::*, width::Width,
 };
 
 // todo: change return type to impl Iterator<Cow<str>>?
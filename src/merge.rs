@@ -0,0 +1,73 @@
+//! This module contains a [MergeDuplicates] setting which merges
+//! adjacent cells with the same content.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{builder::Builder, MergeDuplicates, Style};
+//!
+//! let table = Builder::default()
+//!     .add_record(["Category", "Category", "Item"])
+//!     .add_record(["fruit", "apple", "1"])
+//!     .build()
+//!     .with(Style::ascii())
+//!     .with(MergeDuplicates::Row(0))
+//!     .to_string();
+//!
+//! assert_eq!(
+//!     table,
+//!     concat!(
+//!         "+---------------+------+\n",
+//!         "|   Category    | Item |\n",
+//!         "+-------+-------+------+\n",
+//!         "| fruit | apple |  1   |\n",
+//!         "+-------+-------+------+\n",
+//!     )
+//! )
+//! ```
+
+#[allow(unused)]
+use crate::Table;
+use crate::TableOption;
+use papergrid::{Entity, Grid, Settings};
+
+/// MergeDuplicates merges runs of adjacent cells with equal content in a [Table].
+///
+/// Cells in a row are merged by a column span.
+/// Cells in a column can't be merged as there are no row spans.
+#[derive(Debug)]
+pub enum MergeDuplicates {
+    /// A row index of the grid.
+    /// Equal cells going one after another from left to right are merged.
+    Row(usize),
+}
+
+impl TableOption for MergeDuplicates {
+    fn change(&mut self, grid: &mut Grid) {
+        match *self {
+            Self::Row(row) => merge_row(grid, row),
+        }
+    }
+}
+
+fn merge_row(grid: &mut Grid, row: usize) {
+    if row >= grid.count_rows() {
+        return;
+    }
+
+    let mut start = 0;
+    while start < grid.count_columns() {
+        let mut end = start + 1;
+        while end < grid.count_columns()
+            && grid.get_cell_content(row, end) == grid.get_cell_content(row, start)
+        {
+            end += 1;
+        }
+
+        if end - start > 1 {
+            grid.set(Entity::Cell(row, start), Settings::new().span(end - start));
+        }
+
+        start = end;
+    }
+}
@@ -89,8 +89,7 @@ where
 
 /// Intersection struct used for chaining [Object]'s.
 ///
-/// Returns cells which are present in 2 sets.
-/// But not in one of them
+/// Returns cells which are present in both sets.
 pub struct IntersectionCombination<L, R> {
     lhs: L,
     rhs: R,
@@ -817,13 +816,8 @@ where
     type Item = (usize, usize);
 
     fn next(&mut self) -> Option<Self::Item> {
-        for p in self.lhs.by_ref() {
-            if !self.seen.contains(&p) {
-                return Some(p);
-            }
-        }
-
-        None
+        let seen = &self.seen;
+        self.lhs.find(|p| !seen.contains(p))
     }
 }
 
@@ -861,13 +855,8 @@ where
     type Item = (usize, usize);
 
     fn next(&mut self) -> Option<Self::Item> {
-        for p in self.lhs.by_ref() {
-            if self.seen.contains(&p) {
-                return Some(p);
-            }
-        }
-
-        None
+        let seen = &self.seen;
+        self.lhs.find(|p| seen.contains(p))
     }
 }
 
@@ -899,13 +888,8 @@ impl Iterator for InversionIter {
     type Item = (usize, usize);
 
     fn next(&mut self) -> Option<Self::Item> {
-        for p in self.all.by_ref() {
-            if !self.seen.contains(&p) {
-                return Some(p);
-            }
-        }
-
-        None
+        let seen = &self.seen;
+        self.all.find(|p| !seen.contains(p))
     }
 }
 
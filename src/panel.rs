@@ -20,7 +20,7 @@
 //! assert_eq!(
 //!     table,
 //!     concat!(
-//!         "+-----+----+----+\n",
+//!         "+---------------+\n",
 //!         "|Tabled Releases|\n",
 //!         "+-----+----+----+\n",
 //!         "|  0  | 1  | 2  |\n",
@@ -36,8 +36,10 @@
 //! [Table]: crate::Table
 //! [Span]: crate::Span
 
+use std::{cmp, ops::Range};
+
 use crate::TableOption;
-use papergrid::{Entity, Grid, Settings};
+use papergrid::{AlignmentHorizontal, Entity, Grid, Settings};
 
 /// Panel allows to add a Row which has 1 continues Cell to a [Table].
 ///
@@ -47,29 +49,77 @@ use papergrid::{Entity, Grid, Settings};
 #[derive(Debug)]
 pub struct Panel<S: AsRef<str>>(pub S, pub usize);
 
+impl<S: AsRef<str>> Panel<S> {
+    /// Spanning creates a [SpanningPanel],
+    /// which is a panel spread only over a given range of columns.
+    pub fn spanning(text: S, row: usize, columns: Range<usize>) -> SpanningPanel<S> {
+        SpanningPanel { text, row, columns }
+    }
+}
+
 impl<S: AsRef<str>> TableOption for Panel<S> {
     fn change(&mut self, grid: &mut Grid) {
-        let mut new_grid = Grid::new(grid.count_rows() + 1, grid.count_columns());
-        new_grid.set_borders(grid.get_borders().clone());
-        for row in 0..grid.count_rows() {
-            for column in 0..grid.count_columns() {
-                let cell_settings = grid.get_settings(row, column);
-                if row >= self.1 {
-                    new_grid.set(Entity::Cell(row + 1, column), cell_settings);
-                } else {
-                    new_grid.set(Entity::Cell(row, column), cell_settings);
-                }
-            }
-        }
+        insert_row(grid, self.1);
 
-        new_grid.set(
+        grid.set(
             Entity::Cell(self.1, 0),
             Settings::new()
                 .text(self.0.as_ref().to_owned())
-                .span(new_grid.count_columns()),
+                .span(grid.count_columns()),
         );
+    }
+}
+
+/// SpanningPanel inserts a row with a cell spread over a range of columns.
+/// The rest of the row's cells are left empty.
+///
+/// The range is cut to the count of columns.
+///
+/// ```
+/// use tabled::{Panel, Style, TableIteratorExt};
+///
+/// let data = [[1, 2, 3]];
+///
+/// let table = data.table()
+///     .with(Panel::spanning("1..3", 0, 1..3))
+///     .with(Style::modern())
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         "┌───┬───────┐\n",
+///         "│   │1..3   │\n",
+///         "├───┼───┼───┤\n",
+///         "│ 0 │ 1 │ 2 │\n",
+///         "├───┼───┼───┤\n",
+///         "│ 1 │ 2 │ 3 │\n",
+///         "└───┴───┴───┘\n",
+///     )
+/// );
+/// ```
+#[derive(Debug)]
+pub struct SpanningPanel<S: AsRef<str>> {
+    text: S,
+    row: usize,
+    columns: Range<usize>,
+}
+
+impl<S: AsRef<str>> TableOption for SpanningPanel<S> {
+    fn change(&mut self, grid: &mut Grid) {
+        insert_row(grid, self.row);
+
+        let end = cmp::min(self.columns.end, grid.count_columns());
+        if self.columns.start >= end {
+            return;
+        }
 
-        *grid = new_grid;
+        grid.set(
+            Entity::Cell(self.row, self.columns.start),
+            Settings::new()
+                .text(self.text.as_ref().to_owned())
+                .span(end - self.columns.start),
+        );
     }
 }
 
@@ -94,3 +144,104 @@ impl<S: AsRef<str>> TableOption for Footer<S> {
         Panel(self.0.as_ref(), grid.count_rows()).change(grid)
     }
 }
+
+/// EmptyMessage renders a [Panel] with a centered message at the bottom
+/// if a [Table] has no data rows.
+///
+/// By default the first row is considered a header, as [Table::new] builds it,
+/// so the message is shown if there's only a header or nothing at all.
+/// For a [Table] without a header use [EmptyMessage::without_header].
+///
+/// ```
+/// use tabled::{EmptyMessage, Style, Table};
+///
+/// let table = Table::new(Vec::<usize>::new())
+///     .with(EmptyMessage::new("no data"))
+///     .with(Style::psql())
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         "  usize  \n",
+///         "---------\n",
+///         " no data \n",
+///     )
+/// );
+/// ```
+///
+/// [Table]: crate::Table
+/// [Table::new]: crate::Table::new
+#[derive(Debug)]
+pub struct EmptyMessage<S: AsRef<str>> {
+    message: S,
+    has_header: bool,
+}
+
+impl<S: AsRef<str>> EmptyMessage<S> {
+    /// Creates a new [EmptyMessage] for a [Table] which first row is a header.
+    ///
+    /// [Table]: crate::Table
+    pub fn new(message: S) -> Self {
+        Self {
+            message,
+            has_header: true,
+        }
+    }
+
+    /// Makes the first row be considered a data row,
+    /// so the message is shown only if a [Table] has no rows at all.
+    ///
+    /// [Table]: crate::Table
+    pub fn without_header(mut self) -> Self {
+        self.has_header = false;
+        self
+    }
+}
+
+impl<S: AsRef<str>> TableOption for EmptyMessage<S> {
+    fn change(&mut self, grid: &mut Grid) {
+        let count_header_rows = if self.has_header { 1 } else { 0 };
+        if grid.count_rows() > count_header_rows {
+            return;
+        }
+
+        // the message is added to the grid as it is, so its theme, margin and styles are kept
+        if grid.count_columns() == 0 {
+            grid.insert_column(0);
+        }
+
+        let row = grid.count_rows();
+        grid.insert_row(row);
+
+        let mut settings = Settings::new()
+            .text(self.message.as_ref())
+            .span(grid.count_columns())
+            .alignment(AlignmentHorizontal::Center);
+        if row > 0 {
+            // use the same padding as the header has
+            let padding = grid.style(Entity::Cell(0, 0)).padding;
+            settings = settings.padding(padding.left, padding.right, padding.top, padding.bottom);
+        }
+
+        grid.set(Entity::Cell(row, 0), settings);
+    }
+}
+
+/// Inserts an empty row at the given index, moving the rows below it down.
+fn insert_row(grid: &mut Grid, index: usize) {
+    let mut new_grid = Grid::new(grid.count_rows() + 1, grid.count_columns());
+    new_grid.set_borders(grid.get_borders().clone());
+    for row in 0..grid.count_rows() {
+        for column in 0..grid.count_columns() {
+            let cell_settings = grid.get_settings(row, column);
+            if row >= index {
+                new_grid.set(Entity::Cell(row + 1, column), cell_settings);
+            } else {
+                new_grid.set(Entity::Cell(row, column), cell_settings);
+            }
+        }
+    }
+
+    *grid = new_grid;
+}
@@ -0,0 +1,68 @@
+//! This module contains a [RepeatHeader] setting which repeats
+//! a header of a [Table] in between its rows.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{RepeatHeader, Style, TableIteratorExt};
+//!
+//! let data = [1, 2, 3];
+//!
+//! let table = data.table()
+//!     .with(Style::psql())
+//!     .with(RepeatHeader(2))
+//!     .to_string();
+//!
+//! assert_eq!(
+//!     table,
+//!     concat!(
+//!         " i32 \n",
+//!         "-----\n",
+//!         "  1  \n",
+//!         "  2  \n",
+//!         " i32 \n",
+//!         "  3  \n",
+//!     )
+//! )
+//! ```
+
+#[allow(unused)]
+use crate::Table;
+use crate::TableOption;
+use papergrid::{Border, Entity, Grid};
+
+/// RepeatHeader inserts a copy of the first row after every `n` rows following it.
+///
+/// A copy has the same content and style as the original header,
+/// but it doesn't inherit its spans.
+///
+/// The header is not repeated after the last row, and `0` doesn't change the [Table].
+#[derive(Debug)]
+pub struct RepeatHeader(pub usize);
+
+impl TableOption for RepeatHeader {
+    fn change(&mut self, grid: &mut Grid) {
+        let n = self.0;
+        if n == 0 || grid.count_rows() == 0 {
+            return;
+        }
+
+        let header = (0..grid.count_columns())
+            .map(|column| {
+                // an empty border and a default span keep the ones of the inserted row
+                grid.get_settings(0, column)
+                    .border(Border::default())
+                    .span(1)
+            })
+            .collect::<Vec<_>>();
+
+        let count_rows = grid.count_rows();
+        let positions = (1 + n..count_rows).step_by(n).collect::<Vec<_>>();
+        for &row in positions.iter().rev() {
+            grid.insert_row(row);
+            for (column, settings) in header.iter().enumerate() {
+                grid.set(Entity::Cell(row, column), settings.clone());
+            }
+        }
+    }
+}
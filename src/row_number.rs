@@ -0,0 +1,100 @@
+//! This module contains a [RowNumber] setting which adds an index column to a [Table].
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{RowNumber, Style, TableIteratorExt};
+//!
+//! let data = ["Hello", "World"];
+//!
+//! let table = data.table()
+//!     .with(RowNumber::new().header("#"))
+//!     .with(Style::psql())
+//!     .to_string();
+//!
+//! assert_eq!(
+//!     table,
+//!     concat!(
+//!         " # | &str  \n",
+//!         "---+-------\n",
+//!         " 1 | Hello \n",
+//!         " 2 | World \n",
+//!     )
+//! );
+//! ```
+//!
+//! [Table]: crate::Table
+
+use crate::TableOption;
+use papergrid::{Border, Entity, Grid, Settings};
+
+/// RowNumber inserts a leftmost column with a number of each row on a [Table].
+///
+/// The first row is considered to be a header so it's not numbered.
+///
+/// [Table]: crate::Table
+#[derive(Debug, Clone)]
+pub struct RowNumber {
+    header: String,
+    start: usize,
+}
+
+impl RowNumber {
+    /// Creates a [RowNumber] with an empty header which starts numbering from 1.
+    pub fn new() -> Self {
+        Self {
+            header: String::new(),
+            start: 1,
+        }
+    }
+
+    /// Sets a header of the index column.
+    pub fn header(mut self, header: impl Into<String>) -> Self {
+        self.header = header.into();
+        self
+    }
+
+    /// Sets a number of the first row.
+    ///
+    /// Use `0` for 0-based numbering.
+    pub fn start(mut self, start: usize) -> Self {
+        self.start = start;
+        self
+    }
+}
+
+impl Default for RowNumber {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TableOption for RowNumber {
+    fn change(&mut self, grid: &mut Grid) {
+        let count_rows = grid.count_rows();
+        let count_columns = grid.count_columns();
+        if count_rows == 0 {
+            return;
+        }
+
+        grid.insert_column(0);
+
+        for row in 0..count_rows {
+            let text = if row == 0 {
+                self.header.clone()
+            } else {
+                (self.start + row - 1).to_string()
+            };
+
+            // the index column is styled like the first column was
+            let settings = if count_columns > 0 {
+                // an empty border and a default span keep the ones of the inserted column
+                grid.get_settings(row, 1).border(Border::default()).span(1)
+            } else {
+                Settings::new()
+            };
+
+            grid.set(Entity::Cell(row, 0), settings.text(text));
+        }
+    }
+}
@@ -0,0 +1,90 @@
+//! This module contains a [Sort] setting which reorders rows of a [Table].
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{Sort, Style, TableIteratorExt};
+//!
+//! let data = [["b", "2"], ["c", "10"], ["a", "1"]];
+//!
+//! let table = data.table()
+//!     .with(Sort::by(1, |s| s.parse::<usize>().unwrap_or(0)))
+//!     .with(Style::psql())
+//!     .to_string();
+//!
+//! assert_eq!(
+//!     table,
+//!     concat!(
+//!         " 0 | 1  \n",
+//!         "---+----\n",
+//!         " a | 1  \n",
+//!         " b | 2  \n",
+//!         " c | 10 \n",
+//!     )
+//! );
+//! ```
+//!
+//! [Table]: crate::Table
+
+use crate::TableOption;
+use papergrid::Grid;
+
+/// Sort reorders data rows of a [Table] by a key derived from a column content.
+///
+/// The first row is considered to be a header so it's kept in place.
+/// The sort is stable so rows with equal keys preserve their order.
+///
+/// [Table]: crate::Table
+#[derive(Debug)]
+pub struct Sort<F> {
+    column: usize,
+    key: F,
+}
+
+impl<F> Sort<F> {
+    /// Creates a [Sort] which orders rows by a key which `key` builds from a content of a `column`.
+    ///
+    /// Rows are sorted in ascending order of the key.
+    pub fn by<K>(column: usize, key: F) -> Self
+    where
+        F: Fn(&str) -> K,
+        K: Ord,
+    {
+        Self { column, key }
+    }
+}
+
+impl<F, K> TableOption for Sort<F>
+where
+    F: Fn(&str) -> K,
+    K: Ord,
+{
+    fn change(&mut self, grid: &mut Grid) {
+        let count_rows = grid.count_rows();
+        if self.column >= grid.count_columns() || count_rows < 3 {
+            return;
+        }
+
+        let mut keys = (1..count_rows)
+            .map(|row| (row, (self.key)(grid.get_cell_content(row, self.column))))
+            .collect::<Vec<_>>();
+        keys.sort_by(|a, b| a.1.cmp(&b.1));
+
+        // current[i] is an original index of a row which is now placed at i,
+        // and position[row] is where an original row is placed now.
+        let mut current = (0..count_rows).collect::<Vec<_>>();
+        let mut position = current.clone();
+        for (i, (row, _)) in keys.into_iter().enumerate() {
+            let target = i + 1;
+            let from = position[row];
+            if from != target {
+                grid.swap_rows(target, from);
+
+                let displaced = current[target];
+                current.swap(target, from);
+                position[row] = target;
+                position[displaced] = from;
+            }
+        }
+    }
+}
@@ -0,0 +1,48 @@
+//! This module contains a [Spacing] setting which adds blank lines between rows of a [Table].
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{Spacing, Style, TableIteratorExt};
+//!
+//! let data = ["Hello", "World"];
+//!
+//! let table = data.table()
+//!     .with(Style::psql())
+//!     .with(Spacing(1))
+//!     .to_string();
+//!
+//! assert_eq!(
+//!     table,
+//!     concat!(
+//!         " &str  \n",
+//!         "-------\n",
+//!         " Hello \n",
+//!         "       \n",
+//!         " World \n",
+//!     )
+//! );
+//! ```
+//!
+//! [Table]: crate::Table
+
+use crate::TableOption;
+use papergrid::Grid;
+
+/// Spacing sets an amount of blank lines printed after each data row of a [Table] except the last one.
+///
+/// The lines are not real rows, they are printed between a row content and the next split line.
+///
+/// The first row is considered to be a header so it's not affected.
+///
+/// [Table]: crate::Table
+#[derive(Debug, Clone, Copy)]
+pub struct Spacing(pub usize);
+
+impl TableOption for Spacing {
+    fn change(&mut self, grid: &mut Grid) {
+        for row in 1..grid.count_rows().saturating_sub(1) {
+            grid.set_row_spacing(row, self.0);
+        }
+    }
+}
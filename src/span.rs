@@ -16,13 +16,13 @@
 //! assert_eq!(
 //!     table,
 //!     concat!(
-//!         "+---+---+---+\n",
+//!         "+---+-------+\n",
 //!         "| 0 |   1   |\n",
 //!         "+---+---+---+\n",
 //!         "| 1 | 2 | 3 |\n",
 //!         "+---+---+---+\n",
 //!         "|   4   | 6 |\n",
-//!         "+---+---+---+\n",
+//!         "+-------+---+\n",
 //!     )
 //! )
 //! ```
@@ -30,8 +30,6 @@
 use crate::CellOption;
 use papergrid::{Entity, Grid, Settings};
 
-pub use papergrid::{AlignmentHorizontal, AlignmentVertical};
-
 /// Span represent a horizontal/column span setting for any cell on a [Table].
 ///
 /// ```rust,no_run
@@ -49,9 +47,41 @@ pub struct Span {
 
 impl Span {
     /// New constructs a horizontal/column [Span].
+    ///
+    /// A span of size `0` merges the cell into the closest visible cell on its left,
+    /// the same as [Span::merge_left] does.
     pub fn column(size: usize) -> Self {
         Self { size }
     }
+
+    /// Merge left constructs a [Span] which merges a cell into a cell on its left.
+    ///
+    /// The closest visible cell to the left gets its span extended so it covers the cell,
+    /// and the content of the merged cell is hidden.
+    /// A cell in the first column has nothing to merge into so it's left untouched.
+    ///
+    /// ```
+    /// use tabled::{object::Cell, Modify, Span, Style, TableIteratorExt};
+    ///
+    /// let data = [[1, 2, 3]];
+    ///
+    /// let table = data.table()
+    ///     .with(Style::psql())
+    ///     .with(Modify::new(Cell(1, 2)).with(Span::merge_left()))
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         " 0 | 1 | 2 \n",
+    ///         "---+---+---\n",
+    ///         " 1 |   2   \n",
+    ///     )
+    /// );
+    /// ```
+    pub fn merge_left() -> Self {
+        Self::column(0)
+    }
 }
 
 impl CellOption for Span {
@@ -84,7 +84,7 @@
 
 use std::{borrow::Cow, marker::PhantomData};
 
-use crate::{CellOption, TableOption};
+use crate::{object, CellOption, Highlight, TableOption};
 use papergrid::{Borders, Entity, Grid, Settings};
 
 /// Style is represents a theme of a [Table].
@@ -264,6 +264,25 @@ impl Style {
         CustomStyle::new(Self::EXTENDED)
     }
 
+    /// Sharp style looks like the following table.
+    ///
+    /// It has only an outer frame and a line under the header.
+    ///
+    /// Beware: It uses UTF8 characters.
+    ///
+    /// ```text
+    ///     ┌─────────────────────────────────────────────┐
+    ///     │ id  destribution            link            │
+    ///     ├─────────────────────────────────────────────┤
+    ///     │ 0      Fedora      https://getfedora.org/   │
+    ///     │ 2     OpenSUSE    https://www.opensuse.org/ │
+    ///     │ 3   Endeavouros   https://endeavouros.com/  │
+    ///     └─────────────────────────────────────────────┘
+    /// ```
+    pub const fn sharp() -> CustomStyle<On, On, On, On, (), (), On> {
+        CustomStyle::new(Self::SHARP)
+    }
+
     /// ReStructuredText style looks like the following table
     ///
     /// ```text
@@ -279,6 +298,77 @@ impl Style {
         CustomStyle::new(Self::RE_STRUCTURED_TEXT)
     }
 
+    /// From symbols constructs a style from a fully specified [Borders].
+    ///
+    /// The result can be stored and reused across tables.
+    ///
+    /// ```
+    /// use tabled::{papergrid::Borders, Style, Table};
+    ///
+    /// let style = Style::from_symbols(Borders {
+    ///     top: Some('.'.into()),
+    ///     bottom: Some('\''.into()),
+    ///     vertical_left: Some(':'.into()),
+    ///     vertical_right: Some(':'.into()),
+    ///     top_left: Some('.'.into()),
+    ///     top_right: Some('.'.into()),
+    ///     bottom_left: Some('\''.into()),
+    ///     bottom_right: Some('\''.into()),
+    ///     ..Default::default()
+    /// });
+    ///
+    /// let table = Table::new(["Hello"]).with(style.clone()).to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         ".........\n",
+    ///         ": &str  :\n",
+    ///         ": Hello :\n",
+    ///         "\'\'\'\'\'\'\'\'\'\n",
+    ///     )
+    /// );
+    /// ```
+    pub fn from_symbols(borders: Borders) -> StyleSettings {
+        let frame = Frame {
+            top: Line {
+                main: borders.top,
+                intersection: borders.top_intersection,
+            },
+            bottom: Line {
+                main: borders.bottom,
+                intersection: borders.bottom_intersection,
+            },
+            left: Line {
+                main: borders.vertical_left,
+                intersection: borders.horizontal_left,
+            },
+            right: Line {
+                main: borders.vertical_right,
+                intersection: borders.horizontal_right,
+            },
+            corner_top_left: borders.top_left,
+            corner_top_right: borders.top_right,
+            corner_bottom_left: borders.bottom_left,
+            corner_bottom_right: borders.bottom_right,
+        };
+
+        let horizontal = Line {
+            main: borders.horizontal,
+            intersection: borders.intersection,
+        };
+
+        StyleSettings {
+            frame,
+            horizontal,
+            header: Line::empty(),
+            vertical: borders.vertical_intersection,
+            frame_border: None,
+            header_left: None,
+            header_right: None,
+        }
+    }
+
     const EMPTY: StyleSettings =
         StyleSettings::new(Frame::empty(), Line::empty(), Line::empty(), None);
 
@@ -356,6 +446,19 @@ impl Style {
         Some('║'),
     );
 
+    const SHARP: StyleSettings = StyleSettings::new(
+        Frame::full(
+            Line::solid('─'),
+            Line::solid('─'),
+            Line::new('│', '├'),
+            Line::new('│', '┤'),
+            ('┌', '┐', '└', '┘'),
+        ),
+        Line::empty(),
+        Line::solid('─'),
+        None,
+    );
+
     const DOTS: StyleSettings = StyleSettings::new(
         Frame::full(
             Line::new('.', '.'),
@@ -391,6 +494,9 @@ pub struct StyleSettings {
     horizontal: Line,
     header: Line,
     vertical: Option<Symbol>,
+    frame_border: Option<Border>,
+    header_left: Option<Symbol>,
+    header_right: Option<Symbol>,
 }
 
 impl StyleSettings {
@@ -400,6 +506,9 @@ impl StyleSettings {
             horizontal,
             header,
             vertical: char_to_symbol(vertical),
+            frame_border: None,
+            header_left: None,
+            header_right: None,
         }
     }
 
@@ -439,6 +548,20 @@ impl StyleSettings {
 
         self.vertical = map_symbol(self.vertical, &f);
 
+        if let Some(border) = self.frame_border.as_mut() {
+            border.top = map_symbol(border.top.take(), &f);
+            border.bottom = map_symbol(border.bottom.take(), &f);
+            border.left = map_symbol(border.left.take(), &f);
+            border.right = map_symbol(border.right.take(), &f);
+            border.left_top_corner = map_symbol(border.left_top_corner.take(), &f);
+            border.left_bottom_corner = map_symbol(border.left_bottom_corner.take(), &f);
+            border.right_top_corner = map_symbol(border.right_top_corner.take(), &f);
+            border.right_bottom_corner = map_symbol(border.right_bottom_corner.take(), &f);
+        }
+
+        self.header_left = map_symbol(self.header_left, &f);
+        self.header_right = map_symbol(self.header_right, &f);
+
         self
     }
 }
@@ -459,6 +582,14 @@ impl Line {
         }
     }
 
+    /// Create a new line without intersections.
+    const fn solid(main: char) -> Self {
+        Self {
+            main: Some(Symbol::from_char(main)),
+            intersection: None,
+        }
+    }
+
     /// A line which doesn't exists.
     const fn empty() -> Self {
         Self {
@@ -559,14 +690,18 @@ impl TableOption for StyleSettings {
         grid.set_borders(borders);
 
         if grid.count_rows() > 1 {
-            grid.set_split_line(
-                1,
-                papergrid::Line {
-                    horizontal: self.header.main.clone(),
-                    intersection: self.header.intersection.clone(),
-                    ..Default::default()
-                },
-            );
+            let line = papergrid::Line {
+                horizontal: self.header.main.clone(),
+                intersection: self.header.intersection.clone(),
+                left: self.header_left.clone(),
+                right: self.header_right.clone(),
+            };
+
+            grid.set_split_line(1, line);
+        }
+
+        if let Some(border) = &self.frame_border {
+            Highlight::new(object::Frame, border.clone()).change(grid);
         }
     }
 }
@@ -650,6 +785,35 @@ impl<Top, Bottom, Left, Rright, Horizontal, Vertical, Header>
         }
     }
 
+    /// Sets a border of the outer frame of a table.
+    ///
+    /// Only the frame sides and corners are changed, inner lines are kept as they are.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::{Table, style::{Style, Border}};
+    /// let data = [["10:52:19", "Hello"], ["10:52:20", "World"]];
+    /// let table = Table::new(data)
+    ///     .with(Style::psql().set_frame(Border::new('*', '*', '*', '*', '*', '*', '*', '*')));
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     concat!(
+    ///         "********************\n",
+    ///         "*    0     |   1   *\n",
+    ///         "*----------+-------*\n",
+    ///         "* 10:52:19 | Hello *\n",
+    ///         "* 10:52:20 | World *\n",
+    ///         "********************\n",
+    ///     )
+    /// );
+    /// ```
+    pub fn set_frame(mut self, border: Border) -> Self {
+        self.inner.frame_border = Some(border);
+        self
+    }
+
     /// This function runs a function for each border character and changes it accordingly.
     ///
     /// It may be useful when you wan't to colorize the borders.
@@ -873,6 +1037,8 @@ impl<T, B, L, R, IH, IV, H> CustomStyle<T, B, L, R, IH, IV, H> {
         let c = c.into();
         let mut style = self.inner;
         style.header.main = Some(c.clone());
+        style.header_left = None;
+        style.header_right = None;
 
         if style.vertical.is_some() {
             style.header.intersection = Some(c);
@@ -880,6 +1046,47 @@ impl<T, B, L, R, IH, IV, H> CustomStyle<T, B, L, R, IH, IV, H> {
 
         CustomStyle::new(style)
     }
+
+    /// Sets a line right under the header, separately from the other split lines.
+    ///
+    /// Unlike [CustomStyle::header] it also controls the left and right ends of the line,
+    /// which otherwise are taken from the frame.
+    ///
+    /// The ends and the intersection are used only if the style has
+    /// the corresponding borders and vertical lines.
+    ///
+    /// ```
+    /// use tabled::{papergrid::Line, Style, Table};
+    ///
+    /// let style = Style::modern().header_line(Line {
+    ///     horizontal: Some('═'.into()),
+    ///     intersection: Some('╪'.into()),
+    ///     left: Some('╞'.into()),
+    ///     right: Some('╡'.into()),
+    /// });
+    ///
+    /// let table = Table::new(["Hello"]).with(style).to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "┌───────┐\n",
+    ///         "│ &str  │\n",
+    ///         "╞═══════╡\n",
+    ///         "│ Hello │\n",
+    ///         "└───────┘\n",
+    ///     )
+    /// );
+    /// ```
+    pub fn header_line(self, line: papergrid::Line) -> CustomStyle<T, B, L, R, IH, IV, On> {
+        let mut style = self.inner;
+        style.header.main = line.horizontal;
+        style.header.intersection = line.intersection.filter(|_| style.vertical.is_some());
+        style.header_left = line.left.filter(|_| !style.frame.left.is_empty());
+        style.header_right = line.right.filter(|_| !style.frame.right.is_empty());
+
+        CustomStyle::new(style)
+    }
 }
 
 impl<B, R, IH, IV, H> CustomStyle<On, B, On, R, IH, IV, H> {
@@ -1037,6 +1244,7 @@ impl<T, B, R, IH, IV, H> CustomStyle<T, B, On, R, IH, IV, H> {
         self.inner.frame.left = Line::empty();
         self.inner.frame.corner_top_left = None;
         self.inner.frame.corner_bottom_left = None;
+        self.inner.header_left = None;
 
         CustomStyle::new(self.inner)
     }
@@ -1048,6 +1256,7 @@ impl<T, B, L, IH, IV, H> CustomStyle<T, B, L, On, IH, IV, H> {
         self.inner.frame.right = Line::empty();
         self.inner.frame.corner_top_right = None;
         self.inner.frame.corner_bottom_right = None;
+        self.inner.header_right = None;
 
         CustomStyle::new(self.inner)
     }
@@ -1081,6 +1290,8 @@ impl<T, B, L, R, IH, IV> CustomStyle<T, B, L, R, IH, IV, On> {
     /// Removes 1st horizontal split line.
     pub fn header_off(mut self) -> CustomStyle<T, B, L, R, IH, IV, ()> {
         self.inner.header = Line::empty();
+        self.inner.header_left = None;
+        self.inner.header_right = None;
         CustomStyle::new(self.inner)
     }
 }
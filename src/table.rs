@@ -6,9 +6,19 @@
 
 use std::{fmt, iter::FromIterator};
 
-use papergrid::Grid;
+use papergrid::{Entity, Grid, Settings};
 
-use crate::{builder::Builder, object::Object, Tabled};
+use crate::{
+    builder::Builder,
+    object::{Cell, Object},
+    style::Border,
+    width::PriorityMax,
+    Disable, Highlight, Tabled, Width,
+};
+
+/// A width used by [Table::fit_to_terminal] when the output is not a terminal.
+#[cfg(feature = "terminal")]
+pub const DEFAULT_TERMINAL_WIDTH: usize = 80;
 
 /// A trait which is responsilbe for configuration of a [Table].
 pub trait TableOption {
@@ -34,6 +44,19 @@ where
 pub trait CellOption {
     /// Modification function of a single cell.
     fn change_cell(&mut self, grid: &mut Grid, row: usize, column: usize);
+
+    /// Modification function of all cells targeted by a [Modify] at once.
+    ///
+    /// It's useful for options which depend on other targeted cells.
+    /// By default it calls [CellOption::change_cell] for each cell.
+    ///
+    /// When several options are set on a [Modify] they're applied cell by cell,
+    /// so this function is called only for a single option.
+    fn change_cells(&mut self, grid: &mut Grid, cells: &[(usize, usize)]) {
+        for &(row, column) in cells {
+            self.change_cell(grid, row, column);
+        }
+    }
 }
 
 /// Table structure provides an interface for building a table for types that implements [Tabled].
@@ -70,6 +93,7 @@ pub trait CellOption {
 #[derive(Clone)]
 pub struct Table {
     pub(crate) grid: Grid,
+    pub(crate) has_header: bool,
 }
 
 impl Table {
@@ -136,6 +160,279 @@ impl Table {
         (self.grid.count_rows(), self.grid.count_columns())
     }
 
+    /// Returns a width of each column driven only by its content, including padding.
+    ///
+    /// It can be used to check whether a table fits a given width
+    /// before applying [Width] settings.
+    /// The widths are taken from the current content, and as [Width] settings change it,
+    /// they must be read before the settings are applied.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::Table;
+    ///
+    /// let table = Table::new(["Hello", "World!"]);
+    ///
+    /// assert_eq!(table.natural_column_widths(), vec![8]);
+    /// ```
+    ///
+    /// [Width]: crate::Width
+    pub fn natural_column_widths(&self) -> Vec<usize> {
+        self.grid.natural_column_widths()
+    }
+
+    /// Returns a mutable reference to a content of a data cell
+    /// located by a row index (not counting the header) and a header name.
+    ///
+    /// `None` is returned if there's no such header or row,
+    /// or if the table has no header at all.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::Table;
+    ///
+    /// let mut table = Table::new(["Hello", "World"]);
+    /// *table.cell_mut(1, "&str").unwrap() = String::from("Rust");
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+-------+\n\
+    ///      | &str  |\n\
+    ///      +-------+\n\
+    ///      | Hello |\n\
+    ///      +-------+\n\
+    ///      | Rust  |\n\
+    ///      +-------+\n"
+    /// );
+    /// ```
+    pub fn cell_mut(&mut self, row: usize, header: &str) -> Option<&mut String> {
+        if !self.has_header {
+            return None;
+        }
+
+        let row = row + 1;
+        if row >= self.grid.count_rows() {
+            return None;
+        }
+
+        let column = (0..self.grid.count_columns())
+            .find(|&col| self.grid.get_cell_content(0, col) == header)?;
+
+        Some(self.grid.get_cell_content_mut(row, column))
+    }
+
+    /// Reduces the amount of columns to `count`.
+    ///
+    /// The content of the overflowing columns is joined by `joiner`
+    /// into the last kept column, header row included.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::{builder::Builder, Style};
+    ///
+    /// let table = Builder::default()
+    ///     .set_columns(["a", "b", "c"])
+    ///     .add_record(["1", "2", "3"])
+    ///     .build()
+    ///     .cap_columns(2, " ")
+    ///     .with(Style::psql())
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         " a | b c \n",
+    ///         "---+-----\n",
+    ///         " 1 | 2 3 \n",
+    ///     )
+    /// );
+    /// ```
+    pub fn cap_columns(mut self, count: usize, joiner: &str) -> Self {
+        let count_columns = self.grid.count_columns();
+        if count == 0 || count_columns <= count {
+            return self;
+        }
+
+        let last = count - 1;
+        for row in 0..self.grid.count_rows() {
+            let text = (last..count_columns)
+                .map(|col| self.grid.get_cell_content(row, col))
+                .collect::<Vec<_>>()
+                .join(joiner);
+
+            self.grid
+                .set(Entity::Cell(row, last), Settings::new().text(text));
+        }
+
+        self.with(Disable::Column(count..))
+    }
+
+    /// Removes the header row, leaving only data rows.
+    /// Calling it on a table which has no header already does nothing.
+    ///
+    /// Unlike [Disable::Row] it keeps the styling of the rest of the table,
+    /// while the split line under the header is dropped along with it.
+    ///
+    /// Be aware that a [Style] applied afterwards treats the first data row as a header.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::{Style, Table};
+    ///
+    /// let table = Table::new(["Hello", "World"])
+    ///     .with(Style::modern())
+    ///     .without_header()
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "┌───────┐\n",
+    ///         "│ Hello │\n",
+    ///         "├───────┤\n",
+    ///         "│ World │\n",
+    ///         "└───────┘\n",
+    ///     )
+    /// );
+    /// ```
+    ///
+    /// [Style]: crate::Style
+    pub fn without_header(mut self) -> Self {
+        if self.has_header && self.grid.count_rows() > 0 {
+            self.grid.remove_row(0);
+        }
+
+        self.has_header = false;
+
+        self
+    }
+
+    /// Returns the contents of the cells row by row, including the header as the first record.
+    ///
+    /// Styles are not applied and spans are ignored,
+    /// so each cell gives its own text even if it's covered by a span.
+    ///
+    /// It's an inverse of building a [Table] from records by a [Builder].
+    ///
+    /// ```
+    /// use tabled::Table;
+    ///
+    /// let records = Table::new([1, 2]).into_records();
+    ///
+    /// assert_eq!(records, vec![vec!["i32"], vec!["1"], vec!["2"]]);
+    /// ```
+    pub fn into_records(self) -> Vec<Vec<String>> {
+        (0..self.grid.count_rows())
+            .map(|row| {
+                (0..self.grid.count_columns())
+                    .map(|col| self.grid.get_cell_content(row, col).to_owned())
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Wraps and widens the columns so the [Table] takes exactly `width` columns.
+    ///
+    /// The biggest columns are wrapped first.
+    /// If the [Table] can't be made that narrow it stays wider.
+    ///
+    /// ```
+    /// use tabled::Table;
+    ///
+    /// let mut table = Table::new(["Hello World"]);
+    /// table.fit_to_width(10);
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     concat!(
+    ///         "+--------+\n",
+    ///         "|  &str  |\n",
+    ///         "+--------+\n",
+    ///         "| Hello  |\n",
+    ///         "| World  |\n",
+    ///         "+--------+\n",
+    ///     )
+    /// );
+    /// ```
+    pub fn fit_to_width(&mut self, width: usize) {
+        Width::wrap(width)
+            .priority::<PriorityMax>()
+            .change(&mut self.grid);
+        Width::increase(width).change(&mut self.grid);
+    }
+
+    /// Fits the [Table] to the width of a terminal, see [Table::fit_to_width].
+    ///
+    /// If the output is not a terminal [DEFAULT_TERMINAL_WIDTH] is used.
+    #[cfg(feature = "terminal")]
+    pub fn fit_to_terminal(&mut self) {
+        self.fit_to_terminal_or(DEFAULT_TERMINAL_WIDTH)
+    }
+
+    /// Fits the [Table] to the width of a terminal, see [Table::fit_to_width].
+    ///
+    /// If the output is not a terminal a given `default` width is used.
+    #[cfg(feature = "terminal")]
+    pub fn fit_to_terminal_or(&mut self, default: usize) {
+        let width = terminal_size::terminal_size()
+            .map_or(default, |(terminal_size::Width(width), _)| width as usize);
+
+        self.fit_to_width(width)
+    }
+
+    /// Highlights the cells which content differs from the same cell of a `baseline` table.
+    ///
+    /// Each differing cell gets its own [Highlight] with the given border.
+    /// An error is returned if the tables have different shapes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::{style::{Border, Style}, Table};
+    ///
+    /// let baseline = Table::new([1, 2]).with(Style::psql());
+    /// let table = Table::new([1, 3])
+    ///     .with(Style::psql())
+    ///     .highlight_diff(&baseline, Border::default().left('>').right('<'))
+    ///     .unwrap();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     concat!(
+    ///         "  i32  \n",
+    ///         " ----- \n",
+    ///         "   1   \n",
+    ///         ">  3  <\n",
+    ///     )
+    /// );
+    /// ```
+    pub fn highlight_diff(self, baseline: &Table, border: Border) -> Result<Self, ShapeMismatch> {
+        if self.shape() != baseline.shape() {
+            return Err(ShapeMismatch {
+                expected: baseline.shape(),
+                got: self.shape(),
+            });
+        }
+
+        let (count_rows, count_columns) = self.shape();
+        let mut table = self;
+        for row in 0..count_rows {
+            for col in 0..count_columns {
+                let is_changed = table.grid.get_cell_content(row, col)
+                    != baseline.grid.get_cell_content(row, col);
+                if is_changed {
+                    table = table.with(Highlight::new(Cell(row, col), border.clone()));
+                }
+            }
+        }
+
+        Ok(table)
+    }
+
     /// With is a generic function which applies options to the [Table].
     ///
     /// It applies settings immediately.
@@ -148,6 +445,27 @@ impl Table {
     }
 }
 
+/// An error returned by [Table::highlight_diff] when the tables have different shapes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShapeMismatch {
+    /// A shape of the baseline table.
+    pub expected: (usize, usize),
+    /// A shape of the compared table.
+    pub got: (usize, usize),
+}
+
+impl fmt::Display for ShapeMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "a table shape {:?} doesn't match a baseline shape {:?}",
+            self.got, self.expected
+        )
+    }
+}
+
+impl std::error::Error for ShapeMismatch {}
+
 impl fmt::Display for Table {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.grid)
@@ -236,10 +554,11 @@ where
     S: CellOption,
 {
     fn change(&mut self, grid: &mut Grid) {
-        let cells = self.obj.cells(grid.count_rows(), grid.count_columns());
-        for (row, column) in cells {
-            self.modifiers.change_cell(grid, row, column)
-        }
+        let cells = self
+            .obj
+            .cells(grid.count_rows(), grid.count_columns())
+            .collect::<Vec<_>>();
+        self.modifiers.change_cells(grid, &cells);
     }
 }
 
@@ -3,6 +3,7 @@
 //! - [Truncate] cuts a cell content to limit width.
 //! - [Wrap] split the content via new lines in order to fit max width.
 //! - [Justify] sets columns width to the same value.
+//! - [WidthList] and [MinWidthList] set a width of each column separately.
 //!
 //! To set a a table width a combination of [MaxWidth] and [MinWidth] can be set.
 //!
@@ -32,7 +33,7 @@
 //! );
 //! ```
 
-use std::{borrow::Cow, collections::HashMap, marker::PhantomData};
+use std::{borrow::Cow, cmp, collections::HashMap, marker::PhantomData};
 
 use crate::{CellOption, TableOption};
 use papergrid::{string_width, string_width_multiline, Entity, Grid, Settings};
@@ -122,6 +123,14 @@ impl Width {
     {
         Justify::new(width)
     }
+
+    /// Returns a [WidthList] structure.
+    pub fn list<I>(widths: I) -> WidthList
+    where
+        I: IntoIterator<Item = usize>,
+    {
+        WidthList::new(widths)
+    }
 }
 
 /// Truncate cut the string to a given width if its length exceeds it.
@@ -140,6 +149,8 @@ impl Width {
 pub struct Truncate<'a, W = usize, P = PriorityNone> {
     width: W,
     suffix: Cow<'a, str>,
+    indicators: bool,
+    offset: usize,
     _priority: PhantomData<P>,
 }
 
@@ -152,6 +163,8 @@ where
         Self {
             width,
             suffix: Cow::Borrowed(""),
+            indicators: false,
+            offset: 0,
             _priority: Default::default(),
         }
     }
@@ -164,9 +177,30 @@ impl<W, P> Truncate<'_, W, P> {
         Truncate {
             width: self.width,
             suffix: suffix.into(),
+            indicators: self.indicators,
+            offset: self.offset,
             _priority: Default::default(),
         }
     }
+
+    /// Sets indicators which show that a content was cut.
+    ///
+    /// A `›` is put at the end of a line if its right part was cut,
+    /// and a `‹` at the beginning if its left part was skipped via [Truncate::offset].
+    ///
+    /// The indicators are not used if a width is too small to fit them.
+    pub fn indicators(mut self) -> Self {
+        self.indicators = true;
+        self
+    }
+
+    /// Sets an amount of columns to be skipped from the left side of a content.
+    ///
+    /// It can be used to scroll a content horizontally.
+    pub fn offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
 }
 
 impl<'a, W, P> Truncate<'a, W, P> {
@@ -175,13 +209,23 @@ impl<'a, W, P> Truncate<'a, W, P> {
     /// - [PriorityNone] which cuts the columns one after another.
     /// - [PriorityMax] cuts the biggest columns first.
     /// - [PriorityMin] cuts the lowest columns first.
+    /// - [PriorityBalanced] cuts the biggest columns first, skipping the ones which can't be cut.
     pub fn priority<PP: ColumnPeaker>(self) -> Truncate<'a, W, PP> {
         Truncate {
             width: self.width,
             suffix: self.suffix,
+            indicators: self.indicators,
+            offset: self.offset,
             _priority: Default::default(),
         }
     }
+
+    /// Shrinks the widest column by one until the table fits, see [PriorityBalanced].
+    ///
+    /// It's the same as `.priority::<PriorityBalanced>()`.
+    pub fn balanced(self) -> Truncate<'a, W, PriorityBalanced> {
+        self.priority()
+    }
 }
 
 impl<W, P> CellOption for Truncate<'_, W, P>
@@ -192,6 +236,45 @@ where
         let width = self.width.width(grid);
 
         let content = grid.get_cell_content_styled(row, column);
+        if self.indicators || self.offset > 0 {
+            let content_width = papergrid::string_width(&content);
+            let cut_left = self.offset > 0 && content_width > 0;
+            let cut_right = content_width > self.offset + width;
+            if !cut_left && !cut_right {
+                return;
+            }
+
+            let count_indicators = usize::from(cut_left) + usize::from(cut_right);
+            let indicators = self.indicators && width > count_indicators;
+
+            let (mut start, mut end) = (self.offset, self.offset + width);
+            if indicators && cut_left {
+                start += 1;
+            }
+
+            if indicators && cut_right {
+                end -= 1;
+            }
+
+            let mut new_content = String::new();
+            if indicators && cut_left {
+                new_content.push('‹');
+            }
+
+            new_content.push_str(&papergrid::cut_str_range(&content, start, end));
+
+            if indicators && cut_right {
+                new_content.push('›');
+            }
+
+            if cut_right {
+                new_content.push_str(self.suffix.as_ref());
+            }
+
+            grid.set(Entity::Cell(row, column), Settings::new().text(new_content));
+            return;
+        }
+
         let striped_content = papergrid::cut_str(&content, width);
         if papergrid::string_width(&striped_content) < papergrid::string_width(&content) {
             let new_content = format!("{}{}", striped_content, self.suffix.as_ref());
@@ -203,6 +286,12 @@ where
 /// Wrap wraps a string to a new line in case it exceeds the provided max boundary.
 /// Otherwise keeps the content of a cell untouched.
 ///
+/// A string is split by grapheme clusters, so for example an emoji ZWJ sequence is never split.
+/// A cluster wider than the boundary is put on its own line as it is, so the line is wider.
+///
+/// If a cell has a max height, only the lines which can be shown are kept,
+/// so the rest of a huge content is not wrapped but dropped.
+///
 /// The function is color aware if a `color` feature is on.
 ///
 /// ## Example
@@ -217,6 +306,9 @@ where
 pub struct Wrap<W = usize, P = PriorityNone> {
     width: W,
     keep_words: bool,
+    hyphen: bool,
+    #[cfg(feature = "linebreak")]
+    unicode_breaks: bool,
     _priority: PhantomData<P>,
 }
 
@@ -229,6 +321,9 @@ where
         Self {
             width,
             keep_words: false,
+            hyphen: false,
+            #[cfg(feature = "linebreak")]
+            unicode_breaks: false,
             _priority: Default::default(),
         }
     }
@@ -241,6 +336,28 @@ where
         self.keep_words = true;
         self
     }
+
+    /// Set the hyphen option.
+    ///
+    /// When a word is split it will be ended by a `-` character.
+    /// The hyphen is not used if a width is lower then 2.
+    pub fn with_hyphen(mut self) -> Self {
+        self.hyphen = true;
+        self
+    }
+
+    /// Set the unicode breaks option.
+    ///
+    /// A string is wrapped only on line break opportunities defined by Unicode (UAX #14),
+    /// so for example CJK text can be wrapped between any characters.
+    /// The [Wrap::keep_words] and [Wrap::with_hyphen] options are ignored when it's set.
+    ///
+    /// It's available only with a `linebreak` feature.
+    #[cfg(feature = "linebreak")]
+    pub fn unicode_breaks(mut self) -> Self {
+        self.unicode_breaks = true;
+        self
+    }
 }
 
 impl<W, P> Wrap<W, P> {
@@ -253,6 +370,9 @@ impl<W, P> Wrap<W, P> {
         Wrap {
             width: self.width,
             keep_words: self.keep_words,
+            hyphen: self.hyphen,
+            #[cfg(feature = "linebreak")]
+            unicode_breaks: self.unicode_breaks,
             _priority: Default::default(),
         }
     }
@@ -265,10 +385,30 @@ where
     fn change_cell(&mut self, grid: &mut Grid, row: usize, column: usize) {
         let width = self.width.width(grid);
         let content = grid.get_cell_content_styled(row, column);
+        let max_height = grid.style(Entity::Cell(row, column)).max_height;
+
+        let wrap = |text: &str| {
+            #[cfg(feature = "linebreak")]
+            if self.unicode_breaks {
+                return papergrid::wrap_text_unicode(text, width);
+            }
+
+            if self.hyphen {
+                papergrid::wrap_text_hyphenated(text, width, self.keep_words)
+            } else {
+                papergrid::wrap_text(text, width, self.keep_words)
+            }
+        };
 
-        let wrapped_content = papergrid::wrap_text(&content, width, self.keep_words);
+        // there's no point in wrapping lines which will be cut anyway.
+        let wrapped_content = match max_height {
+            Some(height) => wrap_text_lazy(&content, width, height, wrap),
+            None => wrap(&content),
+        };
         assert!(
-            width >= papergrid::string_width_multiline(&wrapped_content),
+            wrapped_content
+                .lines()
+                .all(|line| string_width(line) <= width || is_single_cluster(line)),
             "width{:?}\n\n content={:?}\n\n wrap={:?}\n",
             width,
             content,
@@ -331,6 +471,16 @@ where
     }
 }
 
+impl MinWidth {
+    /// Returns a [MinWidthList] structure.
+    pub fn list<I>(widths: I) -> MinWidthList
+    where
+        I: IntoIterator<Item = usize>,
+    {
+        MinWidthList::new(widths)
+    }
+}
+
 impl<W, P> MinWidth<W, P> {
     /// Set's a fill character which will be used to fill the space
     /// when increasing the length of the string to the set boundary.
@@ -387,7 +537,15 @@ where
         }
 
         if width < total_width {
-            truncate_total_width(grid, total_width, width, self.suffix.as_ref(), P::create());
+            let truncate = Truncate {
+                width: 0,
+                suffix: Cow::Borrowed(self.suffix.as_ref()),
+                indicators: self.indicators,
+                offset: self.offset,
+                _priority: PhantomData,
+            };
+
+            truncate_total_width(grid, total_width, width, truncate, P::create());
         }
     }
 }
@@ -414,7 +572,16 @@ where
         }
 
         if width < total_width {
-            wrap_total_width(grid, total_width, width, self.keep_words, P::create());
+            let wrap = Wrap {
+                width: 0,
+                keep_words: self.keep_words,
+                hyphen: self.hyphen,
+                #[cfg(feature = "linebreak")]
+                unicode_breaks: self.unicode_breaks,
+                _priority: PhantomData,
+            };
+
+            wrap_total_width(grid, total_width, width, wrap, P::create());
         }
     }
 }
@@ -474,9 +641,25 @@ where
 ///     .with(Justify::max());
 /// ```
 ///
+/// [PriorityMax] or [PriorityMin] can be set by [Justify::priority].
+/// In such case the table width is set to the width it would have with all columns justified,
+/// like [Width::increase] or [Width::truncate] do for a table,
+/// and the priority decides which columns get the difference.
+///
+/// ```
+/// use tabled::{width::{Justify, PriorityMin}, Style, Table};
+///
+/// let data = ["Hello", "World", "!"];
+///
+/// let table = Table::new(&data)
+///     .with(Style::github_markdown())
+///     .with(Justify::max().priority::<PriorityMin>());
+/// ```
+///
 /// [Padding]: crate::Padding
-pub struct Justify<W> {
+pub struct Justify<W, P = PriorityNone> {
     width: W,
+    _priority: PhantomData<P>,
 }
 
 impl<W> Justify<W>
@@ -489,21 +672,41 @@ where
     ///
     /// [Padding]: crate::Padding
     pub fn new(width: W) -> Self {
-        Self { width }
+        Self {
+            width,
+            _priority: PhantomData,
+        }
     }
 }
 
 impl Justify<Max> {
     /// Creates a new Justify instance with a Max width used as a value.
     pub fn max() -> Self {
-        Self { width: Max }
+        Self::new(Max)
     }
 }
 
 impl Justify<Min> {
     /// Creates a new Justify instance with a Min width used as a value.
     pub fn min() -> Self {
-        Self { width: Min }
+        Self::new(Min)
+    }
+}
+
+impl<W, P> Justify<W, P> {
+    /// Priority defines the logic by which columns are changed.
+    ///
+    /// - [PriorityNone] sets each column to the width exactly, which is the default.
+    /// - [PriorityMax] changes the biggest columns first.
+    /// - [PriorityMin] changes the lowest columns first.
+    ///
+    /// Unlike [PriorityNone] the last two keep the table width the same as if all columns were justified,
+    /// but columns may end up with different widths.
+    pub fn priority<PP: ColumnPeaker>(self) -> Justify<W, PP> {
+        Justify {
+            width: self.width,
+            _priority: PhantomData,
+        }
     }
 }
 
@@ -523,6 +726,150 @@ where
     }
 }
 
+impl<W> TableOption for Justify<W, PriorityMax>
+where
+    W: WidthValue,
+{
+    fn change(&mut self, grid: &mut Grid) {
+        let width = self.width.width(grid);
+        justify_total_width(grid, width, PriorityMax);
+    }
+}
+
+impl<W> TableOption for Justify<W, PriorityMin>
+where
+    W: WidthValue,
+{
+    fn change(&mut self, grid: &mut Grid) {
+        let width = self.width.width(grid);
+        justify_total_width(grid, width, PriorityMin);
+    }
+}
+
+/// WidthList sets a width of each column to a value from a list.
+///
+/// The content is increased and truncated the same way [Justify] does it.
+/// Columns beyond the list length are left untouched.
+///
+/// Be aware that [Padding] is not considered when comparing the width.
+///
+/// ## Example
+///
+/// ```
+/// use tabled::{width::Width, Style, Table};
+///
+/// let data = [["Hello", "World", "!"]];
+///
+/// let table = Table::new(&data)
+///     .with(Style::psql())
+///     .with(Width::list([3, 6]))
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         "  0  |   1    | 2 \n",
+///         "-----+--------+---\n",
+///         " Hel | World  | ! \n",
+///     )
+/// );
+/// ```
+///
+/// [Padding]: crate::Padding
+#[derive(Debug, Clone)]
+pub struct WidthList {
+    widths: Vec<usize>,
+}
+
+impl WidthList {
+    /// Creates a new [WidthList] instance.
+    pub fn new<I>(widths: I) -> Self
+    where
+        I: IntoIterator<Item = usize>,
+    {
+        Self {
+            widths: widths.into_iter().collect(),
+        }
+    }
+}
+
+impl TableOption for WidthList {
+    fn change(&mut self, grid: &mut Grid) {
+        let count_columns = cmp::min(self.widths.len(), grid.count_columns());
+        for (col, &width) in self.widths.iter().enumerate().take(count_columns) {
+            for row in 0..grid.count_rows() {
+                Width::increase(width).change_cell(grid, row, col);
+                Width::truncate(width).change_cell(grid, row, col);
+            }
+        }
+    }
+}
+
+/// MinWidthList increases a width of each column to a value from a list,
+/// as [MinWidth] does for a cell.
+///
+/// Columns beyond the list length are left untouched.
+///
+/// ## Example
+///
+/// ```
+/// use tabled::{width::MinWidth, Style, Table};
+///
+/// let data = [["Hello", "World", "!"]];
+///
+/// let table = Table::new(&data)
+///     .with(Style::psql())
+///     .with(MinWidth::list([3, 6]))
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         "   0   |   1    | 2 \n",
+///         "-------+--------+---\n",
+///         " Hello | World  | ! \n",
+///     )
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct MinWidthList {
+    widths: Vec<usize>,
+    fill: char,
+}
+
+impl MinWidthList {
+    /// Creates a new [MinWidthList] instance.
+    pub fn new<I>(widths: I) -> Self
+    where
+        I: IntoIterator<Item = usize>,
+    {
+        Self {
+            widths: widths.into_iter().collect(),
+            fill: ' ',
+        }
+    }
+
+    /// Set's a fill character which will be used to fill the space
+    /// when increasing the length of the string to the set boundary.
+    pub fn fill_with(mut self, c: char) -> Self {
+        self.fill = c;
+        self
+    }
+}
+
+impl TableOption for MinWidthList {
+    fn change(&mut self, grid: &mut Grid) {
+        let count_columns = cmp::min(self.widths.len(), grid.count_columns());
+        for (col, &width) in self.widths.iter().enumerate().take(count_columns) {
+            for row in 0..grid.count_rows() {
+                MinWidth::new(width)
+                    .fill_with(self.fill)
+                    .change_cell(grid, row, col);
+            }
+        }
+    }
+}
+
 /// A width value which can be obtained on behalf of [Table].
 ///
 /// [Table]: crate::Table
@@ -626,6 +973,35 @@ impl ColumnPeaker for PriorityMax {
     }
 }
 
+/// PriorityBalanced shrinks the widest column which can still be shrunk,
+/// preferring the leftmost one among columns of the same width.
+///
+/// Unlike [PriorityMax] it never peaks a column which is already at its minimum width,
+/// so the width is spread evenly among the widest columns.
+pub struct PriorityBalanced;
+
+impl ColumnPeaker for PriorityBalanced {
+    fn create() -> Self {
+        Self
+    }
+
+    fn peak(&mut self, min_widths: &[usize], widths: &[usize]) -> Option<usize> {
+        let mut peak: Option<usize> = None;
+        for col in 0..widths.len() {
+            let min_width = min_widths.get(col).copied().unwrap_or(0);
+            if widths[col] == 0 || widths[col] <= min_width {
+                continue;
+            }
+
+            if peak.map_or(true, |peak| widths[col] > widths[peak]) {
+                peak = Some(col);
+            }
+        }
+
+        peak
+    }
+}
+
 pub struct PriorityMin;
 
 impl ColumnPeaker for PriorityMin {
@@ -646,6 +1022,40 @@ impl ColumnPeaker for PriorityMin {
     }
 }
 
+/// Checks whether a line is a single grapheme cluster,
+/// which is the only case a wrapped line may be wider than the width.
+fn is_single_cluster(line: &str) -> bool {
+    let width = string_width(line);
+    width == 0 || string_width(&papergrid::cut_str(line, width - 1)) == 0
+}
+
+/// Wraps only a beginning of the text which is enough to fill `max_lines` lines.
+///
+/// One more line is kept in case the text doesn't fit,
+/// so it's still cut with an indicator.
+fn wrap_text_lazy<F>(text: &str, width: usize, max_lines: usize, wrap: F) -> String
+where
+    F: Fn(&str) -> String,
+{
+    if width == 0 {
+        return wrap(text);
+    }
+
+    // A line takes at most `width` columns of the text (plus a wide character which doesn't fit),
+    // and the last lines of a wrapped prefix may differ from the ones of the whole text.
+    let prefix_width = max_lines.saturating_add(2).saturating_mul(width + 2);
+    let prefix = papergrid::cut_str(text, prefix_width);
+    if papergrid::string_width(&prefix) == papergrid::string_width(text) {
+        return wrap(text);
+    }
+
+    wrap(&prefix)
+        .lines()
+        .take(max_lines + 1)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 fn increase_width(s: &str, width: usize, fill_with: char) -> String {
     let has_big_lines = s.lines().any(|line| string_width(line) < width);
     if !has_big_lines {
@@ -705,15 +1115,14 @@ fn truncate_total_width<P: ColumnPeaker>(
     grid: &mut Grid,
     total_width: usize,
     width: usize,
-    suffix: &str,
+    mut truncate: Truncate<'_>,
     priority: P,
 ) {
     let points = decrease_total_width_fn(grid, total_width, width, priority);
 
     for ((row, col), width) in points {
-        Truncate::new(width)
-            .suffix(suffix)
-            .change_cell(grid, row, col);
+        truncate.width = width;
+        truncate.change_cell(grid, row, col);
         MinWidth::new(width).change_cell(grid, row, col);
     }
 }
@@ -722,19 +1131,47 @@ fn wrap_total_width<P: ColumnPeaker>(
     grid: &mut Grid,
     total_width: usize,
     width: usize,
-    keep_words: bool,
+    mut wrap: Wrap,
     priority: P,
 ) {
     let points = decrease_total_width_fn(grid, total_width, width, priority);
 
-    let mut wrap = Wrap::new(0);
-    wrap.keep_words = keep_words;
     for ((row, col), width) in points {
         wrap.width = width;
         wrap.change_cell(grid, row, col);
     }
 }
 
+fn justify_total_width<P: ColumnPeaker>(grid: &mut Grid, width: usize, priority: P) {
+    if grid.count_columns() == 0 || grid.count_rows() == 0 {
+        return;
+    }
+
+    if is_zero_spanned_grid(grid) {
+        return;
+    }
+
+    let widths = grid_widths(grid);
+    let content_width = (0..grid.count_columns())
+        .map(|col| widths.iter().map(|row| row[col]).max().unwrap_or(0))
+        .sum::<usize>();
+
+    let total_width = grid.total_width();
+    let expected_width = total_width.saturating_sub(content_width) + width * grid.count_columns();
+
+    if expected_width > total_width {
+        increase_total_width(grid, total_width, expected_width, priority);
+    } else if expected_width < total_width {
+        truncate_total_width(
+            grid,
+            total_width,
+            expected_width,
+            Truncate::new(0),
+            priority,
+        );
+    }
+}
+
 fn grid_widths(grid: &Grid) -> Vec<Vec<usize>> {
     (0..grid.count_rows())
         .map(|row| {
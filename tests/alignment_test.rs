@@ -1,5 +1,5 @@
 use tabled::{
-    object::{Columns, Rows, Segment},
+    object::{Columns, Object, Rows, Segment},
     Alignment, Modify, Padding, Style, Table,
 };
 
@@ -132,3 +132,74 @@ fn alignment_doesnt_change_padding() {
         )
     );
 }
+
+#[test]
+fn alignment_on_intersection() {
+    let data = create_vector::<3, 3>();
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(Modify::new(Segment::all()).with(Alignment::left()))
+        .with(Modify::new(Rows::single(2).intersect(Columns::new(1..3))).with(Alignment::right()))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            " N | column 0 | column 1 | column 2 "
+            "---+----------+----------+----------"
+            " 0 | 0-0      | 0-1      | 0-2      "
+            " 1 |      1-0 |      1-1 | 1-2      "
+            " 2 | 2-0      | 2-1      | 2-2      "
+        )
+    );
+}
+
+#[test]
+fn decimal_alignment() {
+    let data = [
+        ["price", "note"],
+        ["1.5", "a"],
+        ["12.25", "b"],
+        ["100", "c"],
+        ["-3.125", "d"],
+        ["n/a", "e"],
+    ];
+    let table = Table::new(data)
+        .with(Style::psql())
+        .with(Modify::new(Columns::single(0)).with(Alignment::decimal()))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "   0     |  1   "
+            "---------+------"
+            "   price | note "
+            "   1.5   |  a   "
+            "  12.25  |  b   "
+            " 100     |  c   "
+            "  -3.125 |  d   "
+            "     n/a |  e   "
+        )
+    );
+}
+
+#[test]
+fn decimal_alignment_takes_only_targeted_cells() {
+    let data = [["1.5"], ["12.25"], ["1000.125"]];
+    let table = Table::new(data)
+        .with(Style::psql())
+        .with(Modify::new(Rows::new(1..3)).with(Alignment::decimal()))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "    0     "
+            "----------"
+            "     1.5  "
+            "    12.25 "
+            " 1000.125 "
+        )
+    );
+}
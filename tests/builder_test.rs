@@ -237,6 +237,50 @@ fn builder_extend() {
     );
 }
 
+#[test]
+fn builder_push_record() {
+    let mut builder = Builder::default();
+    builder.set_header(["1", "2"]);
+    for row in [vec!["a", "b", "c"], vec!["d"], vec![]] {
+        builder.push_record(row);
+    }
+
+    let table = builder.build().to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "+---+---+---+"
+            "| 1 | 2 |   |"
+            "+---+---+---+"
+            "| a | b | c |"
+            "+---+---+---+"
+            "| d |   |   |"
+            "+---+---+---+"
+            "|   |   |   |"
+            "+---+---+---+"
+        )
+    );
+
+    let mut builder = Builder::default();
+    builder
+        .push_record([String::from("a")])
+        .push_record(["b", "c"]);
+
+    let table = builder.build().to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "+---+---+"
+            "| a |   |"
+            "+---+---+"
+            "| b | c |"
+            "+---+---+"
+        )
+    );
+}
+
 #[test]
 fn builder_from_vector() {
     let data = vec![
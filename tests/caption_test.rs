@@ -0,0 +1,100 @@
+use tabled::{AlignmentHorizontal, Caption, Margin, Style, Table};
+
+use crate::util::{create_vector, static_table};
+
+mod util;
+
+#[test]
+fn caption_above_center() {
+    let data = create_vector::<2, 2>();
+    let table = Table::new(&data)
+        .with(Style::modern())
+        .with(Caption::above("Caption").alignment(AlignmentHorizontal::Center))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "          Caption          "
+            "┌───┬──────────┬──────────┐"
+            "│ N │ column 0 │ column 1 │"
+            "├───┼──────────┼──────────┤"
+            "│ 0 │   0-0    │   0-1    │"
+            "├───┼──────────┼──────────┤"
+            "│ 1 │   1-0    │   1-1    │"
+            "└───┴──────────┴──────────┘"
+        )
+    );
+}
+
+#[test]
+fn caption_below_center_multiline() {
+    let data = create_vector::<2, 2>();
+    let table = Table::new(&data)
+        .with(Style::modern())
+        .with(Caption::below("Caption\nsecond line").alignment(AlignmentHorizontal::Center))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "┌───┬──────────┬──────────┐"
+            "│ N │ column 0 │ column 1 │"
+            "├───┼──────────┼──────────┤"
+            "│ 0 │   0-0    │   0-1    │"
+            "├───┼──────────┼──────────┤"
+            "│ 1 │   1-0    │   1-1    │"
+            "└───┴──────────┴──────────┘"
+            "          Caption          "
+            "        second line        "
+        )
+    );
+}
+
+#[test]
+fn caption_above_and_below_with_margin() {
+    let data = create_vector::<1, 1>();
+    let table = Table::new(&data)
+        .with(Style::modern())
+        .with(Margin::new(2, 1, 1, 0).set_fill('>', '<', 'v', '^'))
+        .with(Caption::above("top"))
+        .with(Caption::below("bottom").alignment(AlignmentHorizontal::Right))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "vvvvvvvvvvvvvvvvvvv"
+            ">>top             <"
+            ">>┌───┬──────────┐<"
+            ">>│ N │ column 0 │<"
+            ">>├───┼──────────┤<"
+            ">>│ 0 │   0-0    │<"
+            ">>└───┴──────────┘<"
+            ">>          bottom<"
+        )
+    );
+}
+
+#[test]
+fn caption_wider_than_table_is_cut() {
+    let data = create_vector::<1, 1>();
+    let table = Table::new(&data)
+        .with(Style::modern())
+        .with(Caption::above("A very long caption of the table\nshort"))
+        .with(Margin::new(1, 1, 0, 0))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            " A very long capt "
+            " short            "
+            " ┌───┬──────────┐ "
+            " │ N │ column 0 │ "
+            " ├───┼──────────┤ "
+            " │ 0 │   0-0    │ "
+            " └───┴──────────┘ "
+        )
+    );
+}
@@ -16,6 +16,7 @@ mod tupple_structure {
 
     #[test]
     fn skip_tabled() {
+        #[allow(dead_code)]
         #[derive(Tabled)]
         struct St(
             #[tabled(skip)] u8,
@@ -1,4 +1,4 @@
-use tabled::{object::Segment, Alignment, Extract, Format, Modify, Padding, Table};
+use tabled::{object::Segment, Alignment, Extract, Format, Head, Modify, Padding, Style, Table};
 
 use crate::util::{create_vector, static_table};
 
@@ -264,3 +264,54 @@ fn extract_columns_partial_view_test() {
         )
     );
 }
+
+#[test]
+fn head_test() {
+    let data = create_vector::<3, 3>();
+
+    let table = Table::new(&data).with(Head(1)).to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "+---+----------+----------+----------+"
+            "| N | column 0 | column 1 | column 2 |"
+            "+---+----------+----------+----------+"
+            "| 0 |   0-0    |   0-1    |   0-2    |"
+            "+---+----------+----------+----------+"
+            "|                 …                  |"
+            "+------------------------------------+"
+        )
+    );
+
+    let table = Table::new(&data)
+        .with(Head(0))
+        .with(Style::modern())
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "┌───┬──────────┬──────────┬──────────┐"
+            "│ N │ column 0 │ column 1 │ column 2 │"
+            "├───┼──────────┼──────────┼──────────┤"
+            "│                 …                  │"
+            "└────────────────────────────────────┘"
+        )
+    );
+}
+
+#[test]
+fn head_doesnt_change_short_table_test() {
+    let data = create_vector::<3, 3>();
+    let expected = Table::new(&data).with(Style::psql()).to_string();
+
+    for n in [3, 4] {
+        let table = Table::new(&data)
+            .with(Head(n))
+            .with(Style::psql())
+            .to_string();
+
+        assert_eq!(table, expected);
+    }
+}
@@ -262,6 +262,7 @@ mod color {
 
     use super::*;
     use owo_colors::OwoColorize;
+    use tabled::Colorize;
 
     #[test]
     fn color_column_test() {
@@ -287,6 +288,31 @@ mod color {
         );
     }
 
+    #[test]
+    fn colorize_test() {
+        let data = [["1", "-2"], ["3", "-4"]];
+        let table = Table::new(data)
+            .with(Style::psql())
+            .with(Modify::new(Segment::all()).with(Colorize::new(|s| {
+                if s.contains('-') {
+                    Some("\u{1b}[31m".to_owned())
+                } else {
+                    None
+                }
+            })))
+            .to_string();
+
+        assert_eq!(
+            table,
+            static_table!(
+                " 0 | 1  "
+                "---+----"
+                " 1 | \u{1b}[31m-2\u{1b}[0m "
+                " 3 | \u{1b}[31m-4\u{1b}[0m "
+            )
+        );
+    }
+
     #[test]
     fn color_multiline_test() {
         let mut data = create_vector::<3, 3>();
@@ -1,7 +1,7 @@
 use tabled::{
     object::{Cell, Columns, Frame, Object, Rows, Segment},
     style::Border,
-    Highlight, Style, Table, Tabled,
+    Highlight, ShapeMismatch, Style, Table, Tabled,
 };
 
 use crate::util::{create_vector, static_table};
@@ -464,27 +464,123 @@ fn highlingt_several_times() {
     );
 }
 
-// @todo
-//
-// #[test]
-// fn highlingt_empty_border() {
-//     let data = create_vector::<3, 3>();
-//     let table = Table::new(&data)
-//         .with(Style::modern())
-//         .with(Highlight::new(Frame, Border::empty()))
-//         .to_string();
-
-//     let expected = static_table!(
-//         " N │ column 0 │ column 1 │ column 2 "
-//         "───                       ──────────"
-//         " 0     0-0    │   0-1        0-2    "
-//         "─── ──────────┼────────── ──────────"
-//         " 1     1-0    │   1-1        1-2    "
-//         "───                       ──────────"
-//         " 2 │   2-0    │   2-1    │   2-2    "
-//     );
-
-//     println!("{}", table);
-
-//     assert_eq!(table, expected);
-// }
+#[test]
+fn highlingt_empty_border() {
+    let data = create_vector::<3, 3>();
+    let table = Table::new(&data)
+        .with(Style::modern())
+        .with(Highlight::new(Frame, Border::empty()))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            " N │ column 0 │ column 1 │ column 2 "
+            "───┼──────────┼──────────┼──────────"
+            " 0 │   0-0    │   0-1    │   0-2    "
+            "───┼──────────┼──────────┼──────────"
+            " 1 │   1-0    │   1-1    │   1-2    "
+            "───┼──────────┼──────────┼──────────"
+            " 2 │   2-0    │   2-1    │   2-2    "
+        )
+    );
+}
+
+#[test]
+fn highlingt_empty_border_inner_cells() {
+    let data = create_vector::<3, 3>();
+    let table = Table::new(&data)
+        .with(Style::modern())
+        .with(Highlight::new(Frame, Border::empty()))
+        .with(Highlight::new(
+            Cell(1, 1).and(Cell(1, 2)).and(Cell(2, 1)).and(Cell(2, 2)),
+            Border::empty(),
+        ))
+        .to_string();
+
+    let expected = static_table!(
+        " N │ column 0 │ column 1 │ column 2 "
+        "───                       ──────────"
+        " 0     0-0    │   0-1        0-2    "
+        "─── ──────────┼────────── ──────────"
+        " 1     1-0    │   1-1        1-2    "
+        "───                       ──────────"
+        " 2 │   2-0    │   2-1    │   2-2    "
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn highlight_diff_test() {
+    let mut data = create_vector::<3, 3>();
+    data[1][2] = String::from("x-x");
+
+    let baseline = Table::new(create_vector::<3, 3>()).with(Style::psql());
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .highlight_diff(&baseline, Border::filled('*'))
+        .unwrap();
+
+    assert_eq!(
+        table.to_string(),
+        static_table!(
+            " N | column 0 | column 1 | column 2 "
+            "---+----------+----------+----------"
+            " 0 |   0-0    |   0-1    |   0-2    "
+            "              ************          "
+            " 1 |   1-0    *   x-x    *   1-2    "
+            "              ************          "
+            " 2 |   2-0    |   2-1    |   2-2    "
+        )
+    );
+
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .highlight_diff(&Table::new(&data), Border::filled('*'))
+        .unwrap();
+
+    assert_eq!(
+        table.to_string(),
+        Table::new(&data).with(Style::psql()).to_string()
+    );
+}
+
+#[test]
+fn highlight_diff_shape_mismatch_test() {
+    let baseline = Table::new(create_vector::<3, 3>());
+    let result = Table::new(create_vector::<2, 3>()).highlight_diff(&baseline, Border::filled('*'));
+
+    assert_eq!(
+        result.err(),
+        Some(ShapeMismatch {
+            expected: (4, 4),
+            got: (3, 4),
+        })
+    );
+}
+
+#[test]
+fn highlingt_partial_border_is_additive() {
+    let data = create_vector::<3, 3>();
+    let table = Table::new(&data)
+        .with(Style::modern())
+        .with(Highlight::new(Cell(1, 1), Border::default().top('^')))
+        .with(Highlight::new(Cell(1, 1), Border::default().left('<')))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "┌───┬──────────┬──────────┬──────────┐"
+            "│ N │ column 0 │ column 1 │ column 2 │"
+            "├───┼^^^^^^^^^^┼──────────┼──────────┤"
+            "│ 0 <   0-0    │   0-1    │   0-2    │"
+            "├───┼──────────┼──────────┼──────────┤"
+            "│ 1 │   1-0    │   1-1    │   1-2    │"
+            "├───┼──────────┼──────────┼──────────┤"
+            "│ 2 │   2-0    │   2-1    │   2-2    │"
+            "└───┴──────────┴──────────┴──────────┘"
+        )
+    );
+}
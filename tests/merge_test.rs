@@ -0,0 +1,71 @@
+use tabled::{builder::Builder, MergeDuplicates, Style};
+
+use crate::util::static_table;
+
+mod util;
+
+#[test]
+fn merge_duplicates_in_category_row() {
+    let table = Builder::default()
+        .add_record(["Category", "Category", "Item"])
+        .add_record(["fruit", "apple", "1"])
+        .add_record(["vegetable", "carrot", "2"])
+        .build()
+        .with(Style::ascii())
+        .with(MergeDuplicates::Row(0))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "+--------------------+------+"
+            "|      Category      | Item |"
+            "+-----------+--------+------+"
+            "|   fruit   | apple  |  1   |"
+            "+-----------+--------+------+"
+            "| vegetable | carrot |  2   |"
+            "+-----------+--------+------+"
+        )
+    );
+}
+
+#[test]
+fn merge_duplicates_in_row() {
+    let table = Builder::default()
+        .add_record(["a", "b", "c"])
+        .add_record(["1", "1", "2"])
+        .build()
+        .with(Style::ascii())
+        .with(MergeDuplicates::Row(1))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "+---+---+---+"
+            "| a | b | c |"
+            "+---+---+---+"
+            "|   1   | 2 |"
+            "+-------+---+"
+        )
+    );
+}
+
+#[test]
+fn merge_duplicates_out_of_bounds() {
+    let table = Builder::default()
+        .add_record(["1", "1"])
+        .build()
+        .with(Style::ascii())
+        .with(MergeDuplicates::Row(10))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "+---+---+"
+            "| 1 | 1 |"
+            "+---+---+"
+        )
+    );
+}
@@ -1,7 +1,7 @@
 use tabled::{
     object::{Cell, Object, Rows, Segment},
     style::{Border, Style},
-    Alignment, Footer, Header, Highlight, Modify, Panel, Table,
+    Alignment, EmptyMessage, Footer, Header, Highlight, Margin, Modify, Panel, Table,
 };
 
 use crate::util::{create_vector, static_table};
@@ -192,7 +192,7 @@ fn footer() {
 
 #[test]
 fn panel_style_uses_most_left_and_right_cell_styles() {
-    let table = Table::new(&[(0, 1)])
+    let table = Table::new([(0, 1)])
         .with(tabled::Panel("Numbers", 0))
         .with(Style::modern())
         .to_string();
@@ -200,7 +200,7 @@ fn panel_style_uses_most_left_and_right_cell_styles() {
     assert_eq!(
         table,
         static_table!(
-            "┌─────┬─────┐"
+            "┌───────────┐"
             "│Numbers    │"
             "├─────┼─────┤"
             "│ i32 │ i32 │"
@@ -213,7 +213,7 @@ fn panel_style_uses_most_left_and_right_cell_styles() {
 
 #[test]
 fn panel_style_change() {
-    let table = Table::new(&[(0, 1)])
+    let table = Table::new([(0, 1)])
         .with(tabled::Panel("Numbers", 0))
         .with(
             Style::modern()
@@ -239,7 +239,7 @@ fn panel_style_change() {
 
 #[test]
 fn panel_in_single_column() {
-    let table = Table::new(&[(0)])
+    let table = Table::new([(0)])
         .with(tabled::Panel("Numbers", 0))
         .with(Style::modern())
         .to_string();
@@ -257,3 +257,143 @@ fn panel_in_single_column() {
         )
     );
 }
+
+#[test]
+fn empty_message_test() {
+    let data: Vec<Vec<String>> = Vec::new();
+    let table = tabled::builder::Builder::from(data)
+        .build()
+        .with(EmptyMessage::new("no data"))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "+---------+"
+            "| no data |"
+            "+---------+"
+        )
+    );
+
+    let table = tabled::builder::Builder::default()
+        .set_columns(["a long header", "b"])
+        .build()
+        .with(EmptyMessage::new("no data"))
+        .with(Style::modern())
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "┌───────────────┬───┐"
+            "│ a long header │ b │"
+            "├───────────────┼───┤"
+            "│      no data      │"
+            "└───────────────────┘"
+        )
+    );
+}
+
+#[test]
+fn empty_message_is_not_shown_with_data_test() {
+    let data = create_vector::<1, 1>();
+    let expected = Table::new(&data).to_string();
+    let table = Table::new(&data)
+        .with(EmptyMessage::new("no data"))
+        .to_string();
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn empty_message_without_header_test() {
+    let table = tabled::builder::Builder::default()
+        .add_record(["x", "y"])
+        .build();
+    let expected = table.to_string();
+    let table = table
+        .with(EmptyMessage::new("no data").without_header())
+        .to_string();
+
+    assert_eq!(table, expected);
+
+    let data: Vec<Vec<String>> = Vec::new();
+    let table = tabled::builder::Builder::from(data)
+        .build()
+        .with(EmptyMessage::new("no data").without_header())
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "+---------+"
+            "| no data |"
+            "+---------+"
+        )
+    );
+}
+
+#[test]
+fn empty_message_keeps_table_settings_test() {
+    let data: Vec<Vec<String>> = Vec::new();
+    let table = tabled::builder::Builder::from(data)
+        .build()
+        .with(Style::modern())
+        .with(Margin::new(1, 1, 0, 0).set_fill('>', '<', ' ', ' '))
+        .with(EmptyMessage::new("no data").without_header())
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            ">┌─────────┐<"
+            ">│ no data │<"
+            ">└─────────┘<"
+        )
+    );
+}
+
+#[test]
+fn panel_spanning_test() {
+    let data = create_vector::<2, 3>();
+
+    let table = Table::new(&data)
+        .with(Panel::spanning("sub header", 1, 1..3))
+        .with(Style::modern())
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "┌───┬──────────┬──────────┬──────────┐"
+            "│ N │ column 0 │ column 1 │ column 2 │"
+            "├───┼──────────┼──────────┼──────────┤"
+            "│   │sub header           │          │"
+            "├───┼──────────┼──────────┼──────────┤"
+            "│ 0 │   0-0    │   0-1    │   0-2    │"
+            "├───┼──────────┼──────────┼──────────┤"
+            "│ 1 │   1-0    │   1-1    │   1-2    │"
+            "└───┴──────────┴──────────┴──────────┘"
+        )
+    );
+}
+
+#[test]
+fn panel_spanning_out_of_range_test() {
+    let data = create_vector::<1, 2>();
+
+    let table = Table::new(&data)
+        .with(Panel::spanning("end", 2, 2..10))
+        .with(Style::psql())
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            " N | column 0 | column 1 "
+            "---+----------+----------"
+            " 0 |   0-0    |   0-1    "
+            "   |          |end       "
+        )
+    );
+}
@@ -1,6 +1,7 @@
 use tabled::{
+    builder::Builder,
     formatting_settings::{AlignmentStrategy, TabSize, TrimStrategy},
-    object::{Cell, Segment},
+    object::{Cell, Columns, Rows, Segment},
     Alignment, Modify, Span, Style, Table,
 };
 
@@ -204,7 +205,7 @@ fn tab_size_span_test() {
         table.to_string(),
         static_table!(
             "                     N                     | column 2 "
-            "----------------------+-----+--------------+----------"
+            "----------------------------+--------------+----------"
             "     H        ello    World |     0-1      |   0-2    "
             " 123    123    asdasd |        1-0         |   1-2    "
             "          2           | 2-0 | htt    ps:// |   2-2    "
@@ -216,3 +217,122 @@ fn tab_size_span_test() {
         )
     );
 }
+
+#[test]
+fn tab_size_single_column_test() {
+    let mut data = create_vector::<2, 2>();
+    data[0][1] = String::from("a\tb");
+    data[1][1] = String::from("a\tb");
+    data[0][2] = String::from("  a\tb\n c");
+    data[1][2] = String::from("a\tb");
+
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(
+            Modify::new(Columns::single(2))
+                .with(Alignment::right())
+                .with(AlignmentStrategy::PerLine),
+        )
+        .with(Modify::new(Columns::single(2)).with(TabSize(1)))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            " N | column 0 | column 1 "
+            "---+----------+----------"
+            " 0 |  a    b  |      a b "
+            "   |          |        c "
+            " 1 |  a    b  |      a b "
+        )
+    );
+}
+
+#[test]
+fn alignment_per_column() {
+    let table = Builder::default()
+        .set_columns(["a long header"])
+        .add_record(["1\n22"])
+        .add_record(["333\n4"])
+        .add_record(["55"])
+        .build()
+        .with(Style::psql())
+        .with(Modify::new(Segment::all()).with(Alignment::right()))
+        .with(Modify::new(Rows::new(1..)).with(AlignmentStrategy::PerColumn));
+
+    assert_eq!(
+        table.to_string(),
+        static_table!(
+            " a long header "
+            "---------------"
+            "           1   "
+            "           22  "
+            "           333 "
+            "           4   "
+            "           55  "
+        )
+    );
+
+    let table = table.with(Modify::new(Rows::new(1..)).with(AlignmentStrategy::PerCell));
+
+    assert_eq!(
+        table.to_string(),
+        static_table!(
+            " a long header "
+            "---------------"
+            "            1  "
+            "            22 "
+            "           333 "
+            "           4   "
+            "            55 "
+        )
+    );
+}
+
+#[test]
+fn trim_collapse() {
+    let table = Builder::default()
+        .set_columns(["text"])
+        .add_record(["a    b   c"])
+        .add_record(["\n   a \t b\n\n  c    d   \n"])
+        .build()
+        .with(Style::psql())
+        .with(
+            Modify::new(Segment::all())
+                .with(Alignment::left())
+                .with(TrimStrategy::Collapse),
+        );
+
+    assert_eq!(
+        table.to_string(),
+        static_table!(
+            " text  "
+            "-------"
+            " a b c "
+            " a b   "
+            "       "
+            " c d   "
+            "       "
+        )
+    );
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn trim_collapse_colored() {
+    let table = Builder::default()
+        .set_columns(["text"])
+        .add_record(["\u{1b}[31ma    b\u{1b}[0m   \u{1b}[34m c \u{1b}[0m"])
+        .build()
+        .with(Style::psql())
+        .with(Modify::new(Segment::all()).with(TrimStrategy::Collapse));
+
+    assert_eq!(
+        table.to_string(),
+        static_table!(
+            " text  "
+            "-------"
+            " \u{1b}[31ma b\u{1b}[0m\u{1b}[34m c\u{1b}[0m "
+        )
+    );
+}
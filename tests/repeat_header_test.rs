@@ -0,0 +1,57 @@
+use tabled::{object::Rows, Modify, Padding, RepeatHeader, Style, Table};
+
+use crate::util::{create_vector, static_table};
+
+mod util;
+
+#[test]
+fn repeat_header_every_2_rows() {
+    let data = create_vector::<5, 2>();
+    let table = Table::new(&data)
+        .with(Style::ascii())
+        .with(Modify::new(Rows::first()).with(Padding::new(1, 1, 1, 0)))
+        .with(RepeatHeader(2))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "+---+----------+----------+"
+            "|   |          |          |"
+            "| N | column 0 | column 1 |"
+            "+---+----------+----------+"
+            "| 0 |   0-0    |   0-1    |"
+            "+---+----------+----------+"
+            "| 1 |   1-0    |   1-1    |"
+            "+---+----------+----------+"
+            "|   |          |          |"
+            "| N | column 0 | column 1 |"
+            "+---+----------+----------+"
+            "| 2 |   2-0    |   2-1    |"
+            "+---+----------+----------+"
+            "| 3 |   3-0    |   3-1    |"
+            "+---+----------+----------+"
+            "|   |          |          |"
+            "| N | column 0 | column 1 |"
+            "+---+----------+----------+"
+            "| 4 |   4-0    |   4-1    |"
+            "+---+----------+----------+"
+        )
+    );
+}
+
+#[test]
+fn repeat_header_zero_does_nothing() {
+    let data = create_vector::<3, 2>();
+    let table = Table::new(&data).with(RepeatHeader(0)).to_string();
+
+    assert_eq!(table, Table::new(&data).to_string());
+}
+
+#[test]
+fn repeat_header_more_than_rows() {
+    let data = create_vector::<2, 2>();
+    let table = Table::new(&data).with(RepeatHeader(2)).to_string();
+
+    assert_eq!(table, Table::new(&data).to_string());
+}
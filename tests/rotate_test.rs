@@ -163,7 +163,7 @@ fn test_top_rotate() {
 fn rotate_preserve_border_styles_test() {
     let data = [(123, 456, 789), (234, 567, 891), (111, 222, 333)];
 
-    let table = Table::new(&data)
+    let table = Table::new(data)
         .with(Style::ascii())
         .with(Highlight::new(Rows::single(0), Border::default().top('*')))
         .with(Rotate::Left)
@@ -182,7 +182,7 @@ fn rotate_preserve_border_styles_test() {
         ),
     );
 
-    let table = Table::new(&data)
+    let table = Table::new(data)
         .with(Style::ascii())
         .with(Highlight::new(Cell(0, 2), Border::default().bottom('*')))
         .with(Rotate::Left)
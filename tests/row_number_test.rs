@@ -0,0 +1,116 @@
+use tabled::{
+    object::{Cell, Columns},
+    style::{Border, BorderText},
+    Alignment, Highlight, Modify, RowNumber, Style, Table,
+};
+
+use crate::util::{create_vector, static_table};
+
+mod util;
+
+#[test]
+fn row_number() {
+    let data = create_vector::<3, 2>();
+    let table = Table::new(&data).with(RowNumber::new()).to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "+---+---+----------+----------+"
+            "|   | N | column 0 | column 1 |"
+            "+---+---+----------+----------+"
+            "| 1 | 0 |   0-0    |   0-1    |"
+            "+---+---+----------+----------+"
+            "| 2 | 1 |   1-0    |   1-1    |"
+            "+---+---+----------+----------+"
+            "| 3 | 2 |   2-0    |   2-1    |"
+            "+---+---+----------+----------+"
+        )
+    );
+}
+
+#[test]
+fn row_number_zero_based_with_header() {
+    let data = create_vector::<2, 1>();
+    let table = Table::new(&data)
+        .with(RowNumber::new().header("#").start(0))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "+---+---+----------+"
+            "| # | N | column 0 |"
+            "+---+---+----------+"
+            "| 0 | 0 |   0-0    |"
+            "+---+---+----------+"
+            "| 1 | 1 |   1-0    |"
+            "+---+---+----------+"
+        )
+    );
+}
+
+#[test]
+fn row_number_after_style() {
+    let data = create_vector::<2, 1>();
+    let table = Table::new(&data)
+        .with(Style::modern())
+        .with(Modify::new(Columns::single(1)).with(Alignment::left()))
+        .with(RowNumber::new())
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "┌───┬───┬──────────┐"
+            "│   │ N │ column 0 │"
+            "├───┼───┼──────────┤"
+            "│ 1 │ 0 │ 0-0      │"
+            "├───┼───┼──────────┤"
+            "│ 2 │ 1 │ 1-0      │"
+            "└───┴───┴──────────┘"
+        )
+    );
+}
+
+#[test]
+fn row_number_before_style() {
+    let data = create_vector::<2, 1>();
+    let table = Table::new(&data)
+        .with(RowNumber::new())
+        .with(Style::psql())
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "   | N | column 0 "
+            "---+---+----------"
+            " 1 | 0 |   0-0    "
+            " 2 | 1 |   1-0    "
+        )
+    );
+}
+
+#[test]
+fn row_number_keeps_highlight_and_border_text() {
+    let data = create_vector::<2, 1>();
+    let table = Table::new(&data)
+        .with(BorderText::first("-Title"))
+        .with(Highlight::new(Cell(1, 1), Border::filled('*')))
+        .with(RowNumber::new())
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "-Title--+----------+"
+            "|   | N | column 0 |"
+            "+---+---************"
+            "| 1 | 0 *   0-0    *"
+            "+---+---************"
+            "| 2 | 1 |   1-0    |"
+            "+---+---+----------+"
+        )
+    );
+}
@@ -0,0 +1,124 @@
+use tabled::{builder::Builder, object::Rows, style::Border, Highlight, Sort, Style};
+
+use crate::util::static_table;
+
+mod util;
+
+fn semver(s: &str) -> (u64, u64, u64) {
+    let mut parts = s.split('.').map(|p| p.parse().unwrap_or(0));
+    (
+        parts.next().unwrap_or(0),
+        parts.next().unwrap_or(0),
+        parts.next().unwrap_or(0),
+    )
+}
+
+#[test]
+fn sort_by_semver_key() {
+    let table = Builder::default()
+        .add_record(["crate", "version"])
+        .add_record(["a", "0.10.0"])
+        .add_record(["b", "0.9.0"])
+        .add_record(["c", "1.0.0"])
+        .add_record(["d", "0.9.1"])
+        .build()
+        .with(Sort::by(1, semver))
+        .with(Style::psql())
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            " crate | version "
+            "-------+---------"
+            "   b   |  0.9.0  "
+            "   d   |  0.9.1  "
+            "   a   | 0.10.0  "
+            "   c   |  1.0.0  "
+        )
+    );
+}
+
+#[test]
+fn sort_is_stable() {
+    let table = Builder::default()
+        .add_record(["name", "group"])
+        .add_record(["x", "2"])
+        .add_record(["y", "1"])
+        .add_record(["z", "2"])
+        .add_record(["w", "1"])
+        .build()
+        .with(Sort::by(1, |s| s.to_owned()))
+        .with(Style::psql())
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            " name | group "
+            "------+-------"
+            "  y   |   1   "
+            "  w   |   1   "
+            "  x   |   2   "
+            "  z   |   2   "
+        )
+    );
+}
+
+#[test]
+fn sort_by_reversed_key() {
+    let table = Builder::default()
+        .add_record(["n"])
+        .add_record(["1"])
+        .add_record(["3"])
+        .add_record(["2"])
+        .build()
+        .with(Sort::by(0, |s| std::cmp::Reverse(s.to_owned())))
+        .with(Style::psql())
+        .to_string();
+
+    assert_eq!(table, static_table!(" n " "---" " 3 " " 2 " " 1 "));
+}
+
+#[test]
+fn sort_by_out_of_bounds_column_does_nothing() {
+    let table = Builder::default()
+        .add_record(["n"])
+        .add_record(["2"])
+        .add_record(["1"])
+        .build()
+        .with(Sort::by(5, |s| s.to_owned()))
+        .with(Style::psql())
+        .to_string();
+
+    assert_eq!(table, static_table!(" n " "---" " 2 " " 1 "));
+}
+
+#[test]
+fn sort_moves_highlight_with_row() {
+    let table = Builder::default()
+        .add_record(["n"])
+        .add_record(["c"])
+        .add_record(["b"])
+        .add_record(["a"])
+        .build()
+        .with(Style::ascii())
+        .with(Highlight::new(Rows::single(2), Border::filled('*')))
+        .with(Sort::by(0, |s| s.to_owned()))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "+---+"
+            "| n |"
+            "+---+"
+            "| a |"
+            "*****"
+            "* b *"
+            "*****"
+            "| c |"
+            "+---+"
+        )
+    );
+}
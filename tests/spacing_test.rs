@@ -0,0 +1,58 @@
+use crate::util::{create_vector, static_table};
+use tabled::{Spacing, Style, Table};
+
+mod util;
+
+#[test]
+fn spacing_psql_test() {
+    let data = create_vector::<3, 3>();
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(Spacing(1))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            " N | column 0 | column 1 | column 2 "
+            "---+----------+----------+----------"
+            " 0 |   0-0    |   0-1    |   0-2    "
+            "   |          |          |          "
+            " 1 |   1-0    |   1-1    |   1-2    "
+            "   |          |          |          "
+            " 2 |   2-0    |   2-1    |   2-2    "
+        )
+    );
+}
+
+#[test]
+fn spacing_with_split_lines_test() {
+    let data = create_vector::<2, 2>();
+    let table = Table::new(&data)
+        .with(Style::ascii())
+        .with(Spacing(2))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "+---+----------+----------+"
+            "| N | column 0 | column 1 |"
+            "+---+----------+----------+"
+            "| 0 |   0-0    |   0-1    |"
+            "|   |          |          |"
+            "|   |          |          |"
+            "+---+----------+----------+"
+            "| 1 |   1-0    |   1-1    |"
+            "+---+----------+----------+"
+        )
+    );
+}
+
+#[test]
+fn spacing_zero_test() {
+    let data = create_vector::<3, 3>();
+    let table = Table::new(&data).with(Spacing(0)).to_string();
+
+    assert_eq!(table, Table::new(&data).to_string());
+}
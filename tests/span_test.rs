@@ -21,7 +21,7 @@ fn span_column_test() {
             table,
             static_table!(
                 " N | column 1 | column 2 "
-                "-+-+----------+----------"
+                "---+----------+----------"
                 " 0 | 0-1      | 0-2      "
                 " 1 | 1-1      | 1-2      "
                 " 2 | 2-1      | 2-2      "
@@ -39,7 +39,7 @@ fn span_column_test() {
             table,
             static_table!(
                 " N | column 0 | column 2 "
-                "---+-----+----+----------"
+                "---+----------+----------"
                 " 0 | 0-0      | 0-2      "
                 " 1 | 1-0      | 1-2      "
                 " 2 | 2-0      | 2-2      "
@@ -53,7 +53,7 @@ fn span_column_test() {
             .with(Modify::new(Columns::single(0)).with(Span::column(data.len() + 1)))
             .to_string();
 
-        assert_eq!(table, static_table!(" N " "+++" " 0 " " 1 " " 2 "));
+        assert_eq!(table, static_table!(" N " "---" " 0 " " 1 " " 2 "));
     }
 }
 
@@ -457,15 +457,15 @@ fn spaned_columns_with_colision() {
     assert_eq!(
         table,
         static_table!(
-            "┌───────────────┬───────────────┬───────────────┬───────────────┬───────────────┐"
+            "┌───────────────────────────────────────────────────────────────────────────────┐"
             "│                              span all 5 columns                               │"
-            "├───────────────┼───────────────┼───────────────┼───────────────┼───────────────┤"
+            "├───────────────────────────────────────────────────────────────┼───────────────┤"
             "│                        span 4 columns                         │ just 1 column │"
-            "├───────────────┼───────────────┼───────────────┼───────────────┼───────────────┤"
+            "├───────────────────────────────────────────────┼───────────────┼───────────────┤"
             "│                span 3 columns                 │        span 2 columns         │"
-            "├───────────────┼───────────────┼───────────────┼───────────────┼───────────────┤"
+            "├───────────────────────────────┼───────────────┼───────────────────────────────┤"
             "│        span 3 columns         │                span 3 columns                 │"
-            "├───────────────┼───────────────┼───────────────┼───────────────┼───────────────┤"
+            "├───────────────┼───────────────┼───────────────────────────────────────────────┤"
             "│ just 1 column │                        span 4 columns                         │"
             "├───────────────┼───────────────┼───────────────┼───────────────┼───────────────┤"
             "│ just 1 column │ just 1 column │ just 1 column │ just 1 column │ just 1 column │"
@@ -606,9 +606,9 @@ fn span_with_panel_test() {
     assert_eq!(
         table,
         static_table!(
-            "+-----+----+----+"
+            "+---------------+"
             "|Tabled Releases|"
-            "+-----+----+----+"
+            "+----------+----+"
             "|    0     | 2  |"
             "+-----+----+----+"
             "|  1  | 2  | 3  |"
@@ -626,7 +626,7 @@ fn span_with_panel_test() {
     assert_eq!(
         table,
         static_table!(
-            "+-----+----+----+"
+            "+---------------+"
             "|Tabled Releases|"
             "+-----+----+----+"
             "|  0  | 1  | 2  |"
@@ -649,11 +649,11 @@ fn span_with_panel_test() {
     assert_eq!(
         table,
         static_table!(
-            "+-----+----+----+"
+            "+---------------+"
             "|Tabled Releases|"
-            "+-----+----+----+"
+            "+----------+----+"
             "|    0     | 2  |"
-            "+-----+----+----+"
+            "+----------+----+"
             "|    1     | 3  |"
             "+-----+----+----+"
             "|  4  | 5  | 6  |"
@@ -661,3 +661,68 @@ fn span_with_panel_test() {
         )
     );
 }
+
+#[test]
+fn span_merge_left_test() {
+    let data = create_vector::<3, 3>();
+
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(Modify::new(Cell(1, 2)).with(Span::merge_left()))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            " N | column 0 | column 1 | column 2 "
+            "---+----------+----------+----------"
+            " 0 |         0-0         |   0-2    "
+            " 1 |   1-0    |   1-1    |   1-2    "
+            " 2 |   2-0    |   2-1    |   2-2    "
+        )
+    );
+
+    let expected = Table::new(&data)
+        .with(Style::psql())
+        .with(Modify::new(Cell(1, 1)).with(Span::column(2)))
+        .to_string();
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn span_merge_left_in_first_column_test() {
+    let data = create_vector::<3, 3>();
+
+    let expected = Table::new(&data).with(Style::psql()).to_string();
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(Modify::new(Cell(1, 0)).with(Span::merge_left()))
+        .to_string();
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn span_on_frame_has_no_inner_intersection_test() {
+    let data = create_vector::<2, 3>();
+
+    let table = Table::new(&data)
+        .with(Style::modern())
+        .with(Modify::new(Cell(0, 1)).with(Span::column(2)))
+        .with(Modify::new(Cell(2, 1)).with(Span::column(2)))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "┌───┬───────────┬──────────┐"
+            "│ N │ column 0  │ column 2 │"
+            "├───┼─────┼─────┼──────────┤"
+            "│ 0 │ 0-0 │ 0-1 │   0-2    │"
+            "├───┼─────┼─────┼──────────┤"
+            "│ 1 │    1-0    │   1-2    │"
+            "└───┴───────────┴──────────┘"
+        )
+    );
+}
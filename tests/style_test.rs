@@ -5,8 +5,9 @@ use crate::util::{create_vector, static_table};
 use tabled::{
     builder::Builder,
     object::{Rows, Segment},
+    papergrid::{Borders, Grid, Line},
     style::{Border, BorderText},
-    Highlight, Modify, Padding, Style, Table, TableIteratorExt,
+    Highlight, Modify, Padding, Style, Table, TableIteratorExt, TableOption,
 };
 
 mod util;
@@ -106,6 +107,149 @@ fn rounded_style() {
     );
 }
 
+#[test]
+fn rounded_style_header_off() {
+    let data = create_vector::<3, 3>();
+    let table = Table::new(&data)
+        .with(Style::rounded().header_off())
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "╭───┬──────────┬──────────┬──────────╮"
+            "│ N │ column 0 │ column 1 │ column 2 │"
+            "│ 0 │   0-0    │   0-1    │   0-2    │"
+            "│ 1 │   1-0    │   1-1    │   1-2    │"
+            "│ 2 │   2-0    │   2-1    │   2-2    │"
+            "╰───┴──────────┴──────────┴──────────╯"
+        )
+    );
+}
+
+#[test]
+fn sharp_style() {
+    let data = create_vector::<3, 3>();
+    let table = Table::new(&data).with(Style::sharp()).to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "┌─────────────────────────────────┐"
+            "│ N  column 0  column 1  column 2 │"
+            "├─────────────────────────────────┤"
+            "│ 0    0-0       0-1       0-2    │"
+            "│ 1    1-0       1-1       1-2    │"
+            "│ 2    2-0       2-1       2-2    │"
+            "└─────────────────────────────────┘"
+        )
+    );
+}
+
+#[test]
+fn modern_style_header_line() {
+    let style = Style::modern().header_line(Line {
+        horizontal: Some('═'.into()),
+        intersection: Some('╪'.into()),
+        left: Some('╞'.into()),
+        right: Some('╡'.into()),
+    });
+
+    let data = create_vector::<3, 3>();
+    let table = Table::new(&data)
+        .with(style.clone())
+        .with(style)
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "┌───┬──────────┬──────────┬──────────┐"
+            "│ N │ column 0 │ column 1 │ column 2 │"
+            "╞═══╪══════════╪══════════╪══════════╡"
+            "│ 0 │   0-0    │   0-1    │   0-2    │"
+            "├───┼──────────┼──────────┼──────────┤"
+            "│ 1 │   1-0    │   1-1    │   1-2    │"
+            "├───┼──────────┼──────────┼──────────┤"
+            "│ 2 │   2-0    │   2-1    │   2-2    │"
+            "└───┴──────────┴──────────┴──────────┘"
+        )
+    );
+}
+
+#[test]
+fn modern_style_header_line_vertical_and_left_off() {
+    let style = Style::modern()
+        .header_line(Line {
+            horizontal: Some('═'.into()),
+            intersection: Some('╪'.into()),
+            left: Some('╞'.into()),
+            right: Some('╡'.into()),
+        })
+        .vertical_off()
+        .left_off();
+
+    let data = create_vector::<1, 1>();
+    let table = Table::new(&data).with(style).to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "─────────────┐"
+            " N  column 0 │"
+            "═════════════╡"
+            " 0    0-0    │"
+            "─────────────┘"
+        )
+    );
+}
+
+#[test]
+fn rounded_style_frame_off() {
+    let data = create_vector::<3, 3>();
+    let table = Table::new(&data)
+        .with(
+            Style::rounded()
+                .top_off()
+                .bottom_off()
+                .left_off()
+                .right_off(),
+        )
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            " N │ column 0 │ column 1 │ column 2 "
+            "───┼──────────┼──────────┼──────────"
+            " 0 │   0-0    │   0-1    │   0-2    "
+            " 1 │   1-0    │   1-1    │   1-2    "
+            " 2 │   2-0    │   2-1    │   2-2    "
+        )
+    );
+}
+
+#[test]
+fn psql_style_set_frame() {
+    let data = create_vector::<3, 3>();
+    let table = Table::new(&data)
+        .with(Style::psql().set_frame(Border::new('*', '*', '*', '*', '*', '*', '*', '*')))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "**************************************"
+            "* N | column 0 | column 1 | column 2 *"
+            "*---+----------+----------+----------*"
+            "* 0 |   0-0    |   0-1    |   0-2    *"
+            "* 1 |   1-0    |   1-1    |   1-2    *"
+            "* 2 |   2-0    |   2-1    |   2-2    *"
+            "**************************************"
+        )
+    );
+}
+
 #[test]
 fn pseudo_clean_style() {
     let data = create_vector::<3, 3>();
@@ -127,6 +271,45 @@ fn pseudo_clean_style() {
     );
 }
 
+#[test]
+fn modern_style_vertical_change_keeps_horizontal() {
+    let data = create_vector::<2, 2>();
+
+    let table = Table::new(&data)
+        .with(Style::modern().vertical_off())
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "┌───────────────────────┐"
+            "│ N  column 0  column 1 │"
+            "├───────────────────────┤"
+            "│ 0    0-0       0-1    │"
+            "├───────────────────────┤"
+            "│ 1    1-0       1-1    │"
+            "└───────────────────────┘"
+        )
+    );
+
+    let table = Table::new(&data)
+        .with(Style::modern().vertical('#'))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "┌───#──────────#──────────┐"
+            "│ N # column 0 # column 1 │"
+            "├───#──────────#──────────┤"
+            "│ 0 #   0-0    #   0-1    │"
+            "├───#──────────#──────────┤"
+            "│ 1 #   1-0    #   1-1    │"
+            "└───#──────────#──────────┘"
+        )
+    );
+}
+
 #[test]
 fn blank_style() {
     let data = create_vector::<3, 3>();
@@ -1517,3 +1700,57 @@ fn empty_border_text_doesnt_panic_test() {
         )
     );
 }
+
+#[test]
+fn style_from_symbols() {
+    struct SetBorders(Borders);
+
+    impl TableOption for SetBorders {
+        fn change(&mut self, grid: &mut Grid) {
+            grid.clear_theme();
+            grid.set_borders(self.0.clone());
+        }
+    }
+
+    let borders = Borders {
+        top: Some('-'.into()),
+        top_left: Some('.'.into()),
+        top_right: Some('.'.into()),
+        top_intersection: Some('-'.into()),
+        bottom: Some('-'.into()),
+        bottom_left: Some('\''.into()),
+        bottom_right: Some('\''.into()),
+        bottom_intersection: Some('-'.into()),
+        horizontal: Some('~'.into()),
+        horizontal_left: Some(':'.into()),
+        horizontal_right: Some(':'.into()),
+        vertical_left: Some(':'.into()),
+        vertical_intersection: Some(' '.into()),
+        vertical_right: Some(':'.into()),
+        intersection: Some('~'.into()),
+    };
+
+    let style = Style::from_symbols(borders.clone());
+
+    let data = create_vector::<2, 2>();
+    let table = Table::new(&data).with(style.clone()).to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            ".-------------------------."
+            ": N   column 0   column 1 :"
+            ":~~~~~~~~~~~~~~~~~~~~~~~~~:"
+            ": 0     0-0        0-1    :"
+            ":~~~~~~~~~~~~~~~~~~~~~~~~~:"
+            ": 1     1-0        1-1    :"
+            "'-------------------------'"
+        )
+    );
+
+    let expected = Table::new(&data).with(SetBorders(borders)).to_string();
+    assert_eq!(table, expected);
+
+    let table = Table::new(&data).with(style).to_string();
+    assert_eq!(table, expected);
+}
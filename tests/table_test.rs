@@ -2,7 +2,11 @@ use std::{
     collections::{BTreeMap, BTreeSet},
     iter::FromIterator,
 };
-use tabled::{Style, Table, TableIteratorExt, Tabled};
+use tabled::{
+    builder::Builder,
+    object::{Cell, Segment},
+    Alignment, Modify, Span, Style, Table, TableIteratorExt, Tabled, Width,
+};
 
 use crate::util::{create_vector, static_table};
 
@@ -459,7 +463,7 @@ fn table_option_none() {
 fn table_tuple() {
     let t = ("we are in", 2020);
 
-    let table = Table::new(&[t]).to_string();
+    let table = Table::new([t]).to_string();
 
     assert_eq!(
         table,
@@ -477,7 +481,7 @@ fn table_tuple() {
 fn table_single_tuple() {
     let t = (2020,);
 
-    let table = Table::new(&[t]).to_string();
+    let table = Table::new([t]).to_string();
 
     assert_eq!(
         table,
@@ -495,7 +499,7 @@ fn table_single_tuple() {
 fn table_tuple_vec() {
     let map = [(0, "Monday"), (1, "Thursday")];
 
-    let table = Table::new(&map).to_string();
+    let table = Table::new(map).to_string();
 
     assert_eq!(
         table,
@@ -949,3 +953,145 @@ fn table_emojie_utf8_style() {
         ),
     );
 }
+
+#[test]
+fn cap_columns() {
+    let data = create_vector::<2, 4>();
+    let table = Table::new(&data).cap_columns(3, " ").to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "+---+----------+----------------------------+"
+            "| N | column 0 | column 1 column 2 column 3 |"
+            "+---+----------+----------------------------+"
+            "| 0 |   0-0    |        0-1 0-2 0-3         |"
+            "+---+----------+----------------------------+"
+            "| 1 |   1-0    |        1-1 1-2 1-3         |"
+            "+---+----------+----------------------------+"
+        )
+    );
+}
+
+#[test]
+fn cap_columns_doesnt_change_narrow_table() {
+    let data = create_vector::<2, 2>();
+    let table = Table::new(&data);
+
+    assert_eq!(
+        table.clone().cap_columns(3, " ").to_string(),
+        table.to_string()
+    );
+}
+
+#[test]
+fn cell_mut_by_header() {
+    let data = create_vector::<2, 2>();
+    let mut table = Table::new(&data);
+
+    *table.cell_mut(0, "column 1").unwrap() = String::from("edited");
+
+    assert!(table.cell_mut(0, "column 10").is_none());
+    assert!(table.cell_mut(2, "column 1").is_none());
+    assert_eq!(
+        table.to_string(),
+        static_table!(
+            "+---+----------+----------+"
+            "| N | column 0 | column 1 |"
+            "+---+----------+----------+"
+            "| 0 |   0-0    |  edited  |"
+            "+---+----------+----------+"
+            "| 1 |   1-0    |   1-1    |"
+            "+---+----------+----------+"
+        )
+    );
+}
+
+#[test]
+fn cell_mut_without_header() {
+    let data = create_vector::<2, 2>();
+    let mut table = Table::new(&data).without_header();
+
+    assert!(table.cell_mut(0, "column 1").is_none());
+    assert!(table.cell_mut(0, "0-1").is_none());
+    assert_eq!(
+        table.without_header().to_string(),
+        static_table!(
+            "+---+-----+-----+"
+            "| 0 | 0-0 | 0-1 |"
+            "+---+-----+-----+"
+            "| 1 | 1-0 | 1-1 |"
+            "+---+-----+-----+"
+        )
+    );
+}
+
+#[test]
+fn natural_column_widths() {
+    let mut data = create_vector::<3, 3>();
+    data[1][2] = String::from("a long text");
+    let table = Table::new(&data);
+
+    assert_eq!(table.natural_column_widths(), vec![3, 10, 13, 10]);
+
+    let table = table.with(Width::increase(60));
+
+    assert_eq!(table.natural_column_widths(), vec![8, 15, 18, 14]);
+    assert_eq!(table.natural_column_widths().iter().sum::<usize>() + 5, 60);
+}
+
+#[test]
+fn without_header() {
+    let data = create_vector::<3, 2>();
+    let table = Table::new(&data)
+        .with(Style::rounded())
+        .without_header()
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "╭───┬─────┬─────╮"
+            "│ 0 │ 0-0 │ 0-1 │"
+            "│ 1 │ 1-0 │ 1-1 │"
+            "│ 2 │ 2-0 │ 2-1 │"
+            "╰───┴─────┴─────╯"
+        )
+    );
+}
+
+#[test]
+fn without_header_on_empty_table() {
+    let data: Vec<(usize, usize)> = Vec::new();
+    let table = Table::new(&data).without_header();
+
+    assert_eq!(table.shape(), (0, 2));
+    assert_eq!(table.to_string(), "");
+}
+
+#[test]
+fn into_records_roundtrip() {
+    let records = vec![
+        vec!["name".to_owned(), "count".to_owned()],
+        vec!["apple".to_owned(), "1".to_owned()],
+        vec!["pear".to_owned(), "22".to_owned()],
+    ];
+
+    let table = Builder::from(records.clone())
+        .build()
+        .with(Style::modern())
+        .with(Modify::new(Segment::all()).with(Alignment::right()));
+
+    assert_eq!(table.into_records(), records);
+}
+
+#[test]
+fn into_records_ignores_spans() {
+    let table =
+        Table::new(create_vector::<1, 2>()).with(Modify::new(Cell(1, 0)).with(Span::column(2)));
+
+    assert_eq!(
+        table.into_records(),
+        vec![vec!["N", "column 0", "column 1"], vec!["0", "0-0", "0-1"],]
+    );
+}
@@ -1,11 +1,14 @@
 use tabled::{
+    builder::Builder,
     formatting_settings::TrimStrategy,
     object::{Cell, Columns, Object, Rows, Segment},
     width::{Justify, MinWidth, Width},
     width::{PriorityMax, PriorityMin},
-    Alignment, Modify, Panel, Span, Style, Table, Tabled,
+    Alignment, Modify, Panel, Span, Style, Table, TableOption, Tabled,
 };
 
+use tabled::papergrid::{Entity, Grid, Settings};
+
 use crate::util::{create_vector, is_lines_equal, static_table};
 
 mod util;
@@ -53,6 +56,58 @@ fn max_width_with_suffix() {
     );
 }
 
+#[test]
+fn max_width_with_indicators() {
+    let table = Builder::default()
+        .add_record(["Hello World", "Hi"])
+        .add_record(["Hello", "Hello!"])
+        .build()
+        .with(Style::psql())
+        .with(Modify::new(Segment::all()).with(Width::truncate(5).indicators()))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            " Hell› |  Hi   "
+            "-------+-------"
+            " Hello | Hell› "
+        )
+    );
+}
+
+#[test]
+fn max_width_with_indicators_and_offset() {
+    let table = Builder::default()
+        .add_record(["Hello World", "Hi"])
+        .add_record(["Hello", "Hello!"])
+        .build()
+        .with(Style::psql())
+        .with(Modify::new(Segment::all()).with(Width::truncate(5).offset(3).indicators()))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            " ‹o W› |  ‹  "
+            "-------+-----"
+            "  ‹o   | ‹o! "
+        )
+    );
+}
+
+#[test]
+fn max_width_with_offset_without_indicators() {
+    let table = Builder::default()
+        .add_record(["Hello World"])
+        .build()
+        .with(Style::psql())
+        .with(Modify::new(Segment::all()).with(Width::truncate(5).offset(3)))
+        .to_string();
+
+    assert_eq!(table, " lo Wo \n");
+}
+
 #[test]
 fn max_width_doesnt_icrease_width_if_it_is_smaller() {
     let data = create_vector::<3, 3>();
@@ -218,7 +273,7 @@ fn max_width_wrapped_keep_words_color() {
             "| String            |"
             "|-------------------|"
             "| \u{1b}[32m\u{1b}[40mthis is a long \u{1b}[39m\u{1b}[49m   |"
-            "| \u{1b}[32m\u{1b}[40mse\u{1b}[39m\u{1b}[49m\u{1b}[32m\u{1b}[40mntence\u{1b}[39m\u{1b}[49m          |"
+            "| \u{1b}[32m\u{1b}[40msentence\u{1b}[39m\u{1b}[49m          |"
         )
     );
 
@@ -235,7 +290,7 @@ fn max_width_wrapped_keep_words_color() {
             "| String            |"
             "|-------------------|"
             "| \u{1b}[32m\u{1b}[40mthis is a long  \u{1b}[39m\u{1b}[49m  |"
-            "| \u{1b}[32m\u{1b}[40ms\u{1b}[39m\u{1b}[49m\u{1b}[32m\u{1b}[40mentence\u{1b}[39m\u{1b}[49m          |"
+            "| \u{1b}[32m\u{1b}[40msentence\u{1b}[39m\u{1b}[49m          |"
         )
     );
 
@@ -331,12 +386,204 @@ fn max_width_wrapped_keep_words_long_word_color() {
             "| String            |"
             "|-------------------|"
             "| \u{1b}[32m\u{1b}[40mthis is a long \u{1b}[39m\u{1b}[49m   |"
-            "| \u{1b}[32m\u{1b}[40mse\u{1b}[39m\u{1b}[49m\u{1b}[32m\u{1b}[40mntencesentences\u{1b}[39m\u{1b}[49m |"
+            "| \u{1b}[32m\u{1b}[40msentencesentences\u{1b}[39m\u{1b}[49m |"
             "| \u{1b}[32m\u{1b}[40mentence\u{1b}[39m\u{1b}[49m           |"
         )
     );
 }
 
+#[test]
+fn max_width_wrapped_keep_words_long_word_hyphen() {
+    let data = vec!["this is a long sentencesentencesentence"];
+    let table = Table::new(&data)
+        .with(Style::github_markdown())
+        .with(Modify::new(Segment::all()).with(Alignment::left()))
+        .with(Modify::new(Segment::all()).with(Width::wrap(17).keep_words().with_hyphen()))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "| &str              |"
+            "|-------------------|"
+            "| this is a long    |"
+            "| sentencesentence- |"
+            "| sentence          |"
+        )
+    );
+}
+
+#[test]
+fn max_width_wrapped_long_word_hyphen() {
+    let data = vec!["this is a long sentencesentencesentence"];
+    let table = Table::new(&data)
+        .with(Style::github_markdown())
+        .with(Modify::new(Segment::all()).with(Alignment::left()))
+        .with(Modify::new(Segment::all()).with(Width::wrap(12).with_hyphen()))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "| &str         |"
+            "|--------------|"
+            "| this is a l- |"
+            "| ong sentenc- |"
+            "| esentencese- |"
+            "| ntence       |"
+        )
+    );
+}
+
+#[test]
+fn max_width_wrapped_hyphen_is_not_used_on_small_width() {
+    let data = vec!["abc"];
+    let table = Table::new(&data)
+        .with(Style::github_markdown())
+        .with(Modify::new(Segment::all()).with(Width::wrap(1).with_hyphen()))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "| & |"
+            "| s |"
+            "| t |"
+            "| r |"
+            "|---|"
+            "| a |"
+            "| b |"
+            "| c |"
+        )
+    );
+}
+
+#[cfg(feature = "linebreak")]
+#[test]
+fn max_width_wrapped_unicode_breaks_cjk() {
+    let data = vec!["日本語のテキスト"];
+    let table = Table::new(&data)
+        .with(Style::github_markdown())
+        .with(Modify::new(Rows::new(1..)).with(Width::wrap(6).unicode_breaks()))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "|  &str  |"
+            "|--------|"
+            "| 日本語 |"
+            "| のテキ |"
+            "| スト   |"
+        )
+    );
+}
+
+#[cfg(feature = "linebreak")]
+#[test]
+fn max_width_wrapped_unicode_breaks_mixed() {
+    let data = vec!["Rust は 速い language です"];
+    let table = Table::new(&data)
+        .with(Style::github_markdown())
+        .with(Modify::new(Rows::new(1..)).with(Width::wrap(8).unicode_breaks()))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "|   &str   |"
+            "|----------|"
+            "| Rust は  |"
+            "| 速い     |"
+            "| language |"
+            "| です     |"
+        )
+    );
+}
+
+#[cfg(all(feature = "color", feature = "linebreak"))]
+#[test]
+fn max_width_wrapped_unicode_breaks_color() {
+    use owo_colors::OwoColorize;
+
+    let data = vec!["日本語 text".red().to_string()];
+    let table = Table::new(&data)
+        .with(Style::github_markdown())
+        .with(Modify::new(Rows::new(1..)).with(Width::wrap(4).unicode_breaks()))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "| String |"
+            "|--------|"
+            "|  \u{1b}[31m日本\u{1b}[39m  |"
+            "|  \u{1b}[31m語\u{1b}[39m    |"
+            "|  \u{1b}[31mtext\u{1b}[39m  |"
+        )
+    );
+}
+
+#[test]
+fn max_width_wrapped_huge_cell_with_max_height() {
+    struct MaxHeight(usize);
+
+    impl TableOption for MaxHeight {
+        fn change(&mut self, grid: &mut Grid) {
+            grid.set(Entity::Global, Settings::new().max_height(self.0));
+        }
+    }
+
+    let data = vec!["a".repeat(1_000_000)];
+    let mut table = Table::new(&data)
+        .with(Style::github_markdown())
+        .with(MaxHeight(3))
+        .with(Modify::new(Rows::new(1..)).with(Width::wrap(5)));
+
+    // only a part of the content which is visible is wrapped
+    let content = table.cell_mut(0, "String").unwrap();
+    assert_eq!(content.lines().count(), 4);
+    assert!(content.lines().all(|line| line == "aaaaa"));
+
+    assert_eq!(
+        table.to_string(),
+        static_table!(
+            "| String |"
+            "|--------|"
+            "| aaaaa  |"
+            "| aaaaa  |"
+            "| aaaaa… |"
+        )
+    );
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn max_width_wrapped_keep_words_long_word_hyphen_color() {
+    use owo_colors::OwoColorize;
+
+    let data = vec!["this is a long sentencesentencesentence"
+        .on_black()
+        .green()
+        .to_string()];
+    let table = Table::new(&data)
+        .with(Style::github_markdown())
+        .with(Modify::new(Segment::all()).with(Alignment::left()))
+        .with(Modify::new(Segment::all()).with(Width::wrap(17).keep_words().with_hyphen()))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "| String            |"
+            "|-------------------|"
+            "| \u{1b}[32m\u{1b}[40mthis is a long \u{1b}[39m\u{1b}[49m   |"
+            "| \u{1b}[32m\u{1b}[40msentencesentence\u{1b}[39m\u{1b}[49m- |"
+            "| \u{1b}[32m\u{1b}[40msentence\u{1b}[39m\u{1b}[49m          |"
+        )
+    );
+}
+
 #[cfg(feature = "color")]
 #[test]
 fn max_width_wrapped_collored() {
@@ -406,8 +653,8 @@ fn max_width_with_emoji() {
             "| &st... |"
             "|--------|"
             "|   🤠   |"
-            "| 😳�... |"
-            "| 🚴�... |"
+            "| 😳...  |"
+            "|  ...   |"
         )
     );
 }
@@ -650,6 +897,51 @@ fn min_width_color_with_smaller_then_width() {
     );
 }
 
+#[test]
+fn increase_width_of_column() {
+    let data = create_vector::<3, 3>();
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(Modify::new(Columns::single(1)).with(Width::increase(10)))
+        .with(Modify::new(Columns::single(2)).with(Width::increase(2)))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            " N |  column 0  | column 1 | column 2 "
+            "---+------------+----------+----------"
+            " 0 |    0-0     |   0-1    |   0-2    "
+            " 1 |    1-0     |   1-1    |   1-2    "
+            " 2 |    2-0     |   2-1    |   2-2    "
+        )
+    );
+}
+
+#[test]
+fn increase_width_after_truncate() {
+    let data = create_vector::<3, 3>();
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(
+            Modify::new(Columns::single(1))
+                .with(Width::truncate(2))
+                .with(Width::increase(5).fill_with('.')),
+        )
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            " N | co... | column 1 | column 2 "
+            "---+-------+----------+----------"
+            " 0 | 0-... |   0-1    |   0-2    "
+            " 1 | 1-... |   1-1    |   1-2    "
+            " 2 | 2-... |   2-1    |   2-2    "
+        )
+    );
+}
+
 #[test]
 fn total_width_big() {
     let data = create_vector::<3, 3>();
@@ -708,10 +1000,10 @@ fn total_width_big_with_panel() {
         static_table!(
             "|Hello World                                                                   |"
             "|--------------+---------------------+--------------------+--------------------|"
-            "| N            | column 0            | column 1           | column 2           |"
-            "| 0            | 0-0                 | 0-1                | 0-2                |"
-            "| 1            | 1-0                 | 1-1                | 1-2                |"
-            "| 2            | 2-0                 | 2-1                | 2-2                |"
+            "|      N       |      column 0       |      column 1      |      column 2      |"
+            "|      0       |         0-0         |        0-1         |        0-2         |"
+            "|      1       |         1-0         |        1-1         |        1-2         |"
+            "|      2       |         2-0         |        2-1         |        2-2         |"
         )
     );
 }
@@ -816,7 +1108,7 @@ fn total_width_small_with_panel() {
     );
     assert!(is_lines_equal(&table, 5));
 
-    let table = Table::new(&create_vector::<1, 2>())
+    let table = Table::new(create_vector::<1, 2>())
         .with(Panel("Hello World", 0))
         .with(Modify::new(Segment::all()).with(Alignment::center()))
         .with(Style::github_markdown())
@@ -830,7 +1122,7 @@ fn total_width_small_with_panel() {
             "|Hello World       |"
             "|--+-------+-------|"
             "|  | colum | colum |"
-            "|  | 0-0   | 0-1   |"
+            "|  |  0-0  |  0-1  |"
         )
     );
     assert!(is_lines_equal(&table, 20));
@@ -1263,7 +1555,7 @@ fn wrapping_as_total_multiline_color() {
          | n   |             |        |                          |\n\
          |-----+-------------+--------+--------------------------|\n\
          | \u{1b}[31m0.2\u{1b}[39m | \u{1b}[31m\u{1b}[48;2;8;10;30m2021-06-23\u{1b}[39m\u{1b}[49m  | true   | \u{1b}[34m\u{1b}[42m#[header(inline)] \u{1b}[39m\u{1b}[49m       |\n\
-         | \u{1b}[31m.1\u{1b}[39m  |             |        | \u{1b}[34m\u{1b}[42mattrib\u{1b}[39m\u{1b}[49m\u{1b}[34m\u{1b}[42mute\u{1b}[39m\u{1b}[49m                |\n\
+         | \u{1b}[31m.1\u{1b}[39m  |             |        | \u{1b}[34m\u{1b}[42mattribute\u{1b}[39m\u{1b}[49m                |\n\
          | \u{1b}[31m0.2\u{1b}[39m | \u{1b}[32m\u{1b}[48;2;8;100;30m2021-06-19\u{1b}[39m\u{1b}[49m  | false  | \u{1b}[33mAPI changes\u{1b}[39m              |\n\
          | \u{1b}[31m.0\u{1b}[39m  |             |        |                          |\n\
          | \u{1b}[37m0.1\u{1b}[39m | \u{1b}[31m\u{1b}[48;2;8;10;30m2021-06-07\u{1b}[39m\u{1b}[49m  | false  | \u{1b}[31m\u{1b}[40mdisplay_with attribute\u{1b}[39m\u{1b}[49m   |\n\
@@ -1643,6 +1935,51 @@ fn justify_width_max_test() {
     );
 }
 
+#[test]
+fn justify_width_priority_test() {
+    let table = Builder::default()
+        .set_columns(["a", "bbbb", "cccccccc"])
+        .add_record(["1", "2", "3"])
+        .build()
+        .with(Style::github_markdown());
+
+    assert_eq!(
+        table
+            .clone()
+            .with(Justify::max().priority::<PriorityMin>())
+            .to_string(),
+        static_table!(
+            "|    a     |   bbbb   | cccccccc |"
+            "|----------+----------+----------|"
+            "|    1     |    2     |    3     |"
+        )
+    );
+
+    assert_eq!(
+        table
+            .clone()
+            .with(Justify::max().priority::<PriorityMax>())
+            .to_string(),
+        static_table!(
+            "| a | bbbb |      cccccccc       |"
+            "|---+------+---------------------|"
+            "| 1 |  2   |          3          |"
+        )
+    );
+
+    assert_eq!(
+        table
+            .clone()
+            .with(Justify::new(4).priority::<PriorityMax>())
+            .to_string(),
+        static_table!(
+            "| a | bbbb | ccccccc |"
+            "|---+------+---------|"
+            "| 1 |  2   |    3    |"
+        )
+    );
+}
+
 #[test]
 fn max_width_when_cell_has_tabs() {
     let mut data = create_vector::<3, 3>();
@@ -1816,6 +2153,71 @@ fn max_width_truncate_priority_max() {
     );
 }
 
+#[test]
+fn truncate_balanced() {
+    let mut data = create_vector::<3, 3>();
+    data[1][1] = String::from("Hello World With Big Line");
+
+    let balanced = |width: usize| {
+        Table::new(&data)
+            .with(Style::github_markdown())
+            .with(Width::truncate(width).balanced())
+            .to_string()
+    };
+    let priority_max = |width: usize| {
+        Table::new(&data)
+            .with(Style::github_markdown())
+            .with(Width::truncate(width).priority::<PriorityMax>())
+            .to_string()
+    };
+
+    assert_eq!(balanced(35), priority_max(35));
+    assert_eq!(
+        balanced(35),
+        static_table!(
+            "| N | column  | column  | column  |"
+            "|---+---------+---------+---------|"
+            "| 0 |   0-0   |   0-1   |   0-2   |"
+            "| 1 | Hello W |   1-1   |   1-2   |"
+            "| 2 |   2-0   |   2-1   |   2-2   |"
+        )
+    );
+
+    // when the wide column is cut down to the others they're cut one by one from the left
+    assert!(is_lines_equal(&balanced(30), 30));
+    assert_eq!(
+        balanced(30),
+        static_table!(
+            "| N | colum | colum | column |"
+            "|---+-------+-------+--------|"
+            "| 0 |  0-0  |  0-1  |  0-2   |"
+            "| 1 | Hello |  1-1  |  1-2   |"
+            "| 2 |  2-0  |  2-1  |  2-2   |"
+        )
+    );
+    assert_eq!(
+        priority_max(30),
+        static_table!(
+            "| N | column | colum | colum |"
+            "|---+--------+-------+-------|"
+            "| 0 |  0-0   |  0-1  |  0-2  |"
+            "| 1 | Hello  |  1-1  |  1-2  |"
+            "| 2 |  2-0   |  2-1  |  2-2  |"
+        )
+    );
+
+    assert_eq!(
+        balanced(0),
+        static_table!(
+            "|  |  |  |  |"
+            "|--+--+--+--|"
+            "|  |  |  |  |"
+            "|  |  |  |  |"
+            "|  |  |  |  |"
+        )
+    );
+}
+
 #[test]
 fn max_width_truncate_priority_max_with_span() {
     let mut data = create_vector::<3, 3>();
@@ -2190,3 +2592,213 @@ fn min_width_priority_min() {
         ),
     );
 }
+
+#[test]
+fn width_list_test() {
+    let data = create_vector::<2, 3>();
+
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(Width::list([2, 5, 4]))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            " N  | colum | colu | column 2 "
+            "----+-------+------+----------"
+            " 0  |  0-0  | 0-1  |   0-2    "
+            " 1  |  1-0  | 1-1  |   1-2    "
+        )
+    );
+}
+
+#[test]
+fn min_width_list_test() {
+    let data = create_vector::<2, 3>();
+
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(MinWidth::list([3, 5, 10]))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "  N  | column 0 |  column 1  | column 2 "
+            "-----+----------+------------+----------"
+            "  0  |   0-0    |    0-1     |   0-2    "
+            "  1  |   1-0    |    1-1     |   1-2    "
+        )
+    );
+
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(MinWidth::list([3, 5, 10]).fill_with('.'))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            " N.. | column 0 | column 1.. | column 2 "
+            "-----+----------+------------+----------"
+            " 0.. |  0-0..   | 0-1....... |   0-2    "
+            " 1.. |  1-0..   | 1-1....... |   1-2    "
+        )
+    );
+}
+
+#[test]
+fn fit_to_width_shrinks_and_grows_table() {
+    let data = create_vector::<3, 3>();
+
+    let mut table = Table::new(&data);
+    table.fit_to_width(30);
+    let table = table.to_string();
+
+    assert!(table
+        .lines()
+        .all(|line| papergrid::string_width(line) == 30));
+
+    let mut table = Table::new(&data);
+    table.fit_to_width(60);
+    let table = table.to_string();
+
+    assert!(table
+        .lines()
+        .all(|line| papergrid::string_width(line) == 60));
+}
+
+#[test]
+fn max_width_wrapped_keeps_zwj_emoji_intact() {
+    let family = "👨\u{200d}👩\u{200d}👧";
+    let data = [format!("{}{}{}", family, family, family)];
+
+    let table = Table::new(&data)
+        .with(Style::github_markdown())
+        .with(Modify::new(Rows::new(1..)).with(Width::wrap(13)))
+        .to_string();
+
+    assert_eq!(
+        table,
+        format!(
+            concat!(
+                "|    String    |\n",
+                "|--------------|\n",
+                "| {}{} |\n",
+                "| {}       |\n",
+            ),
+            family, family, family
+        )
+    );
+}
+
+#[test]
+fn max_width_wrapped_keeps_wide_zwj_emoji_whole() {
+    let family = "👨\u{200d}👩\u{200d}👧";
+    let data = [format!("{}{}", family, family)];
+
+    let table = Table::new(&data)
+        .with(Style::github_markdown())
+        .with(Modify::new(Rows::new(1..)).with(Width::wrap(3)))
+        .to_string();
+
+    assert_eq!(
+        table,
+        format!(
+            concat!("| String |\n", "|--------|\n", "| {} |\n", "| {} |\n",),
+            family, family
+        )
+    );
+}
+
+#[test]
+fn max_width_wrapped_keeps_grapheme_clusters_intact() {
+    let e = "e\u{301}";
+    let data = [e.repeat(5)];
+
+    let table = Table::new(&data)
+        .with(Style::github_markdown())
+        .with(Modify::new(Rows::new(1..)).with(Width::wrap(2)))
+        .to_string();
+
+    assert_eq!(
+        table,
+        format!(
+            concat!(
+                "| String |\n",
+                "|--------|\n",
+                "|   {e}{e}   |\n",
+                "|   {e}{e}   |\n",
+                "|   {e}    |\n",
+            ),
+            e = e
+        )
+    );
+}
+
+#[test]
+fn max_width_wrapped_keeping_words_keeps_grapheme_clusters_intact() {
+    let e = "e\u{301}";
+    let data = [format!("ab {e}{e}{e}", e = e)];
+
+    let table = Table::new(&data)
+        .with(Style::github_markdown())
+        .with(Modify::new(Rows::new(1..)).with(Width::wrap(4).keep_words()))
+        .to_string();
+
+    assert_eq!(
+        table,
+        format!(
+            concat!(
+                "| String |\n",
+                "|--------|\n",
+                "|  ab    |\n",
+                "|  {e}{e}{e}   |\n",
+            ),
+            e = e
+        )
+    );
+
+    let table = Table::new(&data)
+        .with(Style::github_markdown())
+        .with(Modify::new(Rows::new(1..)).with(Width::wrap(4).keep_words().with_hyphen()))
+        .to_string();
+
+    assert_eq!(
+        table,
+        format!(
+            concat!(
+                "| String |\n",
+                "|--------|\n",
+                "|  ab    |\n",
+                "|  {e}{e}{e}   |\n",
+            ),
+            e = e
+        )
+    );
+}
+
+#[test]
+fn max_width_wrapped_keeps_wide_grapheme_cluster_whole() {
+    let cluster = "\u{65e5}\u{301}";
+    let data = [cluster.repeat(2)];
+
+    let table = Table::new(&data)
+        .with(Style::github_markdown())
+        .with(Modify::new(Rows::new(1..)).with(Width::wrap(1)))
+        .to_string();
+
+    assert_eq!(
+        table,
+        format!(
+            concat!(
+                "| String |\n",
+                "|--------|\n",
+                "|   {c}   |\n",
+                "|   {c}   |\n",
+            ),
+            c = cluster
+        )
+    );
+}